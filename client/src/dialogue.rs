@@ -0,0 +1,137 @@
+//! The panel showing the local character's conversation with an NPC.
+
+use crate::{
+    AppSystems,
+    screens::Screen,
+    spacetime::SpacetimeDB,
+    stdb::{
+        DialogueChoiceTableAccess, DialogueNodeTableAccess, DialogueSessionTableAccess,
+        NpcDefTableAccess, NpcTableAccess, choose_dialogue_option, end_dialogue,
+    },
+    theme::colors::LABEL_TEXT,
+    ui::widgets::button::{ButtonProps, button},
+};
+use bevy::{prelude::*, ui_widgets::observe};
+use spacetimedb_sdk::Table;
+
+const PANEL_WIDTH: f32 = 420.0;
+const SPEECH_TEXT: Color = Color::srgb(0.9, 0.9, 0.9);
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), setup);
+    app.add_systems(
+        Update,
+        sync_dialogue
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Component)]
+struct DialoguePanel;
+
+/// The node and choices the panel currently shows, if any.
+#[derive(Component, Default, PartialEq)]
+struct ShownNode(Option<(u32, Vec<u32>)>);
+
+fn setup(mut commands: Commands) {
+    commands.spawn((
+        DespawnOnExit(Screen::Gameplay),
+        DialoguePanel,
+        ShownNode::default(),
+        Node {
+            display: Display::None,
+            position_type: PositionType::Absolute,
+            left: percent(50),
+            bottom: px(80),
+            width: px(PANEL_WIDTH),
+            margin: UiRect::left(px(-PANEL_WIDTH / 2.0)),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(px(10)),
+            row_gap: px(6),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0., 0., 0., 0.75)),
+    ));
+}
+
+/// Rebuilds the panel whenever the conversation moves to another node, and hides it
+/// when the conversation ends.
+fn sync_dialogue(
+    mut commands: Commands,
+    stdb: SpacetimeDB,
+    panel: Single<(Entity, &mut Node, &mut ShownNode), With<DialoguePanel>>,
+) {
+    let (root, mut node, mut shown) = panel.into_inner();
+
+    // Only the local character's session is visible
+    let session = stdb.db().dialogue_session().iter().next();
+    let current = ShownNode(
+        session
+            .as_ref()
+            .map(|session| (session.node_id, session.choice_ids.clone())),
+    );
+    if *shown == current {
+        return;
+    }
+    *shown = current;
+
+    commands.entity(root).despawn_related::<Children>();
+    let Some(session) = session else {
+        node.display = Display::None;
+        return;
+    };
+    let Some(dialogue_node) = stdb.db().dialogue_node().id().find(&session.node_id) else {
+        node.display = Display::None;
+        return;
+    };
+    node.display = Display::Flex;
+
+    let speaker = stdb
+        .db()
+        .npc()
+        .entity_id()
+        .find(&session.npc_entity_id)
+        .and_then(|npc| stdb.db().npc_def().id().find(&npc.npc_def_id))
+        .map(|npc_def| npc_def.name)
+        .unwrap_or_default();
+
+    commands.spawn((text(speaker, 16.0, LABEL_TEXT), ChildOf(root)));
+    commands.spawn((text(dialogue_node.text, 14.0, SPEECH_TEXT), ChildOf(root)));
+
+    for choice_id in session.choice_ids {
+        let Some(choice) = stdb.db().dialogue_choice().id().find(&choice_id) else {
+            continue;
+        };
+        commands.spawn((
+            button(Spawn(Text::new(choice.text)), ButtonProps::default()),
+            observe(move |_: On<Pointer<Click>>, stdb: SpacetimeDB| {
+                if let Err(err) = stdb.reducers().choose_dialogue_option(choice_id) {
+                    println!("Failed to choose dialogue option: {err:?}");
+                }
+            }),
+            ChildOf(root),
+        ));
+    }
+
+    commands.spawn((
+        button(Spawn(Text::new("Goodbye")), ButtonProps::default()),
+        observe(|_: On<Pointer<Click>>, stdb: SpacetimeDB| {
+            if let Err(err) = stdb.reducers().end_dialogue() {
+                println!("Failed to end dialogue: {err:?}");
+            }
+        }),
+        ChildOf(root),
+    ));
+}
+
+fn text(value: impl Into<String>, font_size: f32, color: Color) -> impl Bundle {
+    (
+        Text::new(value),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextColor(color),
+    )
+}
@@ -0,0 +1,205 @@
+//! The dungeon frame: entering a dungeon from near its entrance, and leaving it again
+//! with the time left until the instance resets.
+
+use crate::{
+    AppSystems,
+    player::PlayerState,
+    screens::Screen,
+    spacetime::SpacetimeDB,
+    stdb::{
+        DungeonDefTableAccess, DungeonInstanceTableAccess, DungeonPortalTableAccess,
+        EntityTableAccess, TransformTableAccess, enter_dungeon, leave_dungeon,
+    },
+    theme::colors::LABEL_TEXT,
+    ui::widgets::button::{ButtonProps, button},
+};
+use bevy::{prelude::*, ui_widgets::observe};
+use common::math;
+use spacetimedb_sdk::{Table, Timestamp};
+
+/// Matches the range the server lets characters use an entrance from.
+const PORTAL_RANGE: f32 = 5.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), setup);
+    app.add_systems(
+        Update,
+        (sync_dungeon_frame, update_reset_timer)
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Component)]
+struct DungeonFrame;
+
+/// What the frame currently shows.
+#[derive(Component, Default, PartialEq, Clone, Copy)]
+enum ShownDungeon {
+    #[default]
+    Nothing,
+    /// The entrance portal the local character is standing near
+    Portal(u32),
+    /// The map of the instance the local character is inside
+    Instance(u32),
+}
+
+/// The countdown until an instance resets, referencing its map.
+#[derive(Component)]
+struct ResetTimer(u32);
+
+fn setup(mut commands: Commands) {
+    commands.spawn((
+        DespawnOnExit(Screen::Gameplay),
+        DungeonFrame,
+        ShownDungeon::default(),
+        Node {
+            display: Display::None,
+            position_type: PositionType::Absolute,
+            top: px(10),
+            left: percent(50),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(px(4)),
+            row_gap: px(4),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0., 0., 0., 0.5)),
+    ));
+}
+
+/// Finds what the frame should show for where the local character is standing.
+fn current_dungeon(stdb: &SpacetimeDB, player: &PlayerState) -> ShownDungeon {
+    let Some(own) = stdb.db().transform().id().find(&player.transform_id) else {
+        return ShownDungeon::Nothing;
+    };
+    if stdb
+        .db()
+        .dungeon_instance()
+        .map_id()
+        .find(&own.map_id)
+        .is_some()
+    {
+        return ShownDungeon::Instance(own.map_id);
+    }
+
+    let from = math::Vec3::from(own.translation);
+    stdb.db()
+        .dungeon_portal()
+        .iter()
+        .find(|portal| {
+            stdb.db()
+                .entity()
+                .id()
+                .find(&portal.entity_id)
+                .and_then(|entity| stdb.db().transform().id().find(&entity.transform_id))
+                .is_some_and(|transform| {
+                    transform.map_id == own.map_id
+                        && from.distance_squared_xz(transform.translation.into())
+                            <= PORTAL_RANGE * PORTAL_RANGE
+                })
+        })
+        .map_or(ShownDungeon::Nothing, |portal| {
+            ShownDungeon::Portal(portal.entity_id)
+        })
+}
+
+/// Rebuilds the frame whenever the local character nears an entrance, or enters or
+/// leaves an instance.
+fn sync_dungeon_frame(
+    mut commands: Commands,
+    stdb: SpacetimeDB,
+    player: Option<Res<PlayerState>>,
+    frame: Single<(Entity, &mut Node, &mut ShownDungeon), With<DungeonFrame>>,
+) {
+    let Some(player) = player else {
+        return;
+    };
+    let (root, mut node, mut shown) = frame.into_inner();
+
+    let current = current_dungeon(&stdb, &player);
+    if *shown == current {
+        return;
+    }
+    *shown = current;
+
+    commands.entity(root).despawn_related::<Children>();
+    let (dungeon_def_id, label) = match current {
+        ShownDungeon::Nothing => {
+            node.display = Display::None;
+            return;
+        }
+        ShownDungeon::Portal(entity_id) => {
+            let Some(portal) = stdb.db().dungeon_portal().entity_id().find(&entity_id) else {
+                node.display = Display::None;
+                return;
+            };
+            (portal.dungeon_def_id, "Enter")
+        }
+        ShownDungeon::Instance(map_id) => {
+            let Some(instance) = stdb.db().dungeon_instance().map_id().find(&map_id) else {
+                node.display = Display::None;
+                return;
+            };
+            (instance.dungeon_def_id, "Leave")
+        }
+    };
+    node.display = Display::Flex;
+
+    let name = stdb
+        .db()
+        .dungeon_def()
+        .id()
+        .find(&dungeon_def_id)
+        .map(|dungeon_def| dungeon_def.name)
+        .unwrap_or_default();
+    commands.spawn((
+        Text::new(name),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(LABEL_TEXT),
+        ChildOf(root),
+    ));
+    if let ShownDungeon::Instance(map_id) = current {
+        commands.spawn((
+            ResetTimer(map_id),
+            Text::default(),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(LABEL_TEXT),
+            ChildOf(root),
+        ));
+    }
+
+    commands.spawn((
+        button(Spawn(Text::new(label)), ButtonProps::default()),
+        observe(move |_: On<Pointer<Click>>, stdb: SpacetimeDB| {
+            let result = match current {
+                ShownDungeon::Portal(entity_id) => stdb.reducers().enter_dungeon(entity_id),
+                _ => stdb.reducers().leave_dungeon(),
+            };
+            if let Err(err) = result {
+                println!("Failed to {label} dungeon: {err:?}");
+            }
+        }),
+        ChildOf(root),
+    ));
+}
+
+fn update_reset_timer(stdb: SpacetimeDB, mut timers: Query<(&ResetTimer, &mut Text)>) {
+    for (timer, mut text) in &mut timers {
+        let Some(instance) = stdb.db().dungeon_instance().map_id().find(&timer.0) else {
+            continue;
+        };
+        let left_secs = (instance.resets_at.to_micros_since_unix_epoch()
+            - Timestamp::now().to_micros_since_unix_epoch())
+        .max(0)
+            / 1_000_000;
+        text.0 = format!("Resets in {}:{:02}", left_secs / 60, left_secs % 60);
+    }
+}
@@ -0,0 +1,92 @@
+//! The cast bar shown while the local character gathers a resource node or teleports.
+
+use crate::{
+    AppSystems,
+    screens::Screen,
+    spacetime::SpacetimeDB,
+    stdb::{GatherCastTableAccess, TeleportCastTableAccess},
+};
+use bevy::prelude::*;
+use spacetimedb_sdk::{Table, Timestamp};
+
+const BAR_WIDTH: f32 = 240.0;
+const BAR_HEIGHT: f32 = 14.0;
+const FILL_COLOR: Color = Color::srgb(0.85, 0.65, 0.2);
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), setup);
+    app.add_systems(
+        Update,
+        update_cast_bar
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Component)]
+struct CastBar;
+
+#[derive(Component)]
+struct CastBarFill;
+
+fn setup(mut commands: Commands) {
+    commands.spawn((
+        DespawnOnExit(Screen::Gameplay),
+        CastBar,
+        Node {
+            display: Display::None,
+            position_type: PositionType::Absolute,
+            left: percent(50),
+            bottom: px(160),
+            width: px(BAR_WIDTH),
+            height: px(BAR_HEIGHT),
+            margin: UiRect::left(px(-BAR_WIDTH / 2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0., 0., 0., 0.75)),
+        children![(
+            CastBarFill,
+            Node {
+                width: percent(0),
+                height: percent(100),
+                ..default()
+            },
+            BackgroundColor(FILL_COLOR),
+        )],
+    ));
+}
+
+/// Shows the bar while a gather or teleport is in progress and fills it towards
+/// completion.
+fn update_cast_bar(
+    stdb: SpacetimeDB,
+    mut bar: Single<&mut Node, (With<CastBar>, Without<CastBarFill>)>,
+    mut fill: Single<&mut Node, With<CastBarFill>>,
+) {
+    // Only the local character's casts are visible
+    let cast = stdb
+        .db()
+        .gather_cast()
+        .iter()
+        .next()
+        .map(|cast| (cast.started_at, cast.completes_at))
+        .or_else(|| {
+            stdb.db()
+                .teleport_cast()
+                .iter()
+                .next()
+                .map(|cast| (cast.started_at, cast.completes_at))
+        });
+    let Some((started_at, completes_at)) = cast else {
+        bar.display = Display::None;
+        return;
+    };
+
+    let started = started_at.to_micros_since_unix_epoch();
+    let total = (completes_at.to_micros_since_unix_epoch() - started).max(1);
+    let elapsed = Timestamp::now().to_micros_since_unix_epoch() - started;
+    let progress = (elapsed as f32 / total as f32).clamp(0.0, 1.0);
+
+    bar.display = Display::Flex;
+    fill.width = percent(progress * 100.0);
+}
@@ -0,0 +1,289 @@
+//! The journal panel listing the local character's quests and their objectives, and
+//! the codex of lore the character has discovered.
+
+use crate::{
+    AppSystems,
+    screens::Screen,
+    spacetime::SpacetimeDB,
+    stdb::{
+        LoreCollectionCompletedTableAccess, LoreCollectionTableAccess, LoreEntryTableAccess,
+        LoreUnlockedTableAccess, QuestDefTableAccess, QuestObjectiveTableAccess, QuestProgress,
+        QuestProgressTableAccess, QuestState,
+    },
+    theme::colors::LABEL_TEXT,
+    ui::widgets::button::{ButtonProps, button},
+};
+use bevy::{prelude::*, ui_widgets::observe};
+use spacetimedb_sdk::Table;
+
+const PANEL_WIDTH: f32 = 320.0;
+const OBJECTIVE_TEXT: Color = Color::srgb(0.85, 0.85, 0.85);
+const COMPLETE_TEXT: Color = Color::srgb(0.36, 0.72, 0.36);
+const UNDISCOVERED_TEXT: Color = Color::srgb(0.5, 0.5, 0.5);
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<JournalTab>();
+    app.add_systems(OnEnter(Screen::Gameplay), setup);
+    app.add_systems(
+        Update,
+        sync_journal
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// The journal panel, hidden until toggled from the gameplay buttons.
+#[derive(Component)]
+pub struct JournalPanel;
+
+/// The page of the journal being shown.
+#[derive(Resource, Default, Clone, Copy, PartialEq)]
+enum JournalTab {
+    #[default]
+    Quests,
+    Codex,
+}
+
+/// A snapshot of what the journal currently shows.
+#[derive(Component, Default, PartialEq)]
+enum JournalContents {
+    #[default]
+    Empty,
+    /// Quest id, state and objective counts
+    Quests(Vec<(u32, bool, Vec<u32>)>),
+    /// Unlocked lore entry ids and completed collection ids
+    Codex(Vec<u32>, Vec<u32>),
+}
+
+/// Shows the journal if it's hidden and hides it otherwise.
+pub fn toggle_journal(_: On<Pointer<Click>>, mut panel: Single<&mut Node, With<JournalPanel>>) {
+    panel.display = match panel.display {
+        Display::None => Display::Flex,
+        _ => Display::None,
+    };
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn((
+        DespawnOnExit(Screen::Gameplay),
+        JournalPanel,
+        JournalContents::default(),
+        Node {
+            display: Display::None,
+            position_type: PositionType::Absolute,
+            right: px(10),
+            bottom: px(10),
+            width: px(PANEL_WIDTH),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(px(8)),
+            row_gap: px(4),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0., 0., 0., 0.7)),
+    ));
+}
+
+/// Rebuilds the journal whenever the shown page or the character's progress changes.
+fn sync_journal(
+    mut commands: Commands,
+    stdb: SpacetimeDB,
+    tab: Res<JournalTab>,
+    panel: Single<(Entity, &mut JournalContents), With<JournalPanel>>,
+) {
+    let (root, mut shown) = panel.into_inner();
+
+    let contents = match *tab {
+        JournalTab::Quests => JournalContents::Quests(
+            quest_journal(&stdb)
+                .iter()
+                .map(|progress| {
+                    (
+                        progress.quest_def_id,
+                        progress.state == QuestState::Complete,
+                        progress.counts.clone(),
+                    )
+                })
+                .collect(),
+        ),
+        JournalTab::Codex => {
+            let mut unlocked: Vec<_> = stdb
+                .db()
+                .lore_unlocked()
+                .iter()
+                .map(|row| row.lore_entry_id)
+                .collect();
+            unlocked.sort();
+            let mut completed: Vec<_> = stdb
+                .db()
+                .lore_collection_completed()
+                .iter()
+                .map(|row| row.collection_id)
+                .collect();
+            completed.sort();
+            JournalContents::Codex(unlocked, completed)
+        }
+    };
+    if *shown == contents {
+        return;
+    }
+
+    commands.entity(root).despawn_related::<Children>();
+    commands.spawn((
+        Node {
+            column_gap: px(6),
+            ..default()
+        },
+        ChildOf(root),
+        children![
+            (
+                button(Spawn(Text::new("Quests")), ButtonProps::default()),
+                observe(|_: On<Pointer<Click>>, mut tab: ResMut<JournalTab>| {
+                    *tab = JournalTab::Quests;
+                }),
+            ),
+            (
+                button(Spawn(Text::new("Codex")), ButtonProps::default()),
+                observe(|_: On<Pointer<Click>>, mut tab: ResMut<JournalTab>| {
+                    *tab = JournalTab::Codex;
+                }),
+            ),
+        ],
+    ));
+
+    match &contents {
+        JournalContents::Quests(_) => spawn_quests(&mut commands, &stdb, root),
+        JournalContents::Codex(unlocked, completed) => {
+            spawn_codex(&mut commands, &stdb, root, unlocked, completed)
+        }
+        JournalContents::Empty => {}
+    }
+    *shown = contents;
+}
+
+/// The character's quests that haven't been turned in yet, oldest first.
+fn quest_journal(stdb: &SpacetimeDB) -> Vec<QuestProgress> {
+    let mut journal: Vec<_> = stdb
+        .db()
+        .quest_progress()
+        .iter()
+        .filter(|progress| progress.state != QuestState::TurnedIn)
+        .collect();
+    journal.sort_by_key(|progress| progress.accepted_at);
+    journal
+}
+
+fn spawn_quests(commands: &mut Commands, stdb: &SpacetimeDB, root: Entity) {
+    let journal = quest_journal(stdb);
+
+    commands.spawn((text("Journal", 18.0, LABEL_TEXT), ChildOf(root)));
+    if journal.is_empty() {
+        commands.spawn((
+            text("You have no quests.", 14.0, OBJECTIVE_TEXT),
+            ChildOf(root),
+        ));
+    }
+
+    for progress in journal {
+        let Some(quest) = stdb.db().quest_def().id().find(&progress.quest_def_id) else {
+            continue;
+        };
+        let title = if progress.state == QuestState::Complete {
+            format!("{} (Complete)", quest.name)
+        } else {
+            quest.name
+        };
+        commands.spawn((text(title, 16.0, LABEL_TEXT), ChildOf(root)));
+
+        let mut objectives: Vec<_> = stdb
+            .db()
+            .quest_objective()
+            .iter()
+            .filter(|objective| objective.quest_def_id == progress.quest_def_id)
+            .collect();
+        objectives.sort_by_key(|objective| objective.id);
+
+        for (objective, count) in objectives.iter().zip(&progress.counts) {
+            let color = if *count >= objective.required {
+                COMPLETE_TEXT
+            } else {
+                OBJECTIVE_TEXT
+            };
+            commands.spawn((
+                text(
+                    format!(
+                        "  {}: {}/{}",
+                        objective.description, count, objective.required
+                    ),
+                    14.0,
+                    color,
+                ),
+                ChildOf(root),
+            ));
+        }
+    }
+}
+
+/// Lists every lore collection with the entries discovered so far. Undiscovered entries
+/// are shown as placeholders so players can tell how much is left to find.
+fn spawn_codex(
+    commands: &mut Commands,
+    stdb: &SpacetimeDB,
+    root: Entity,
+    unlocked: &[u32],
+    completed: &[u32],
+) {
+    commands.spawn((text("Codex", 18.0, LABEL_TEXT), ChildOf(root)));
+
+    let mut collections: Vec<_> = stdb.db().lore_collection().iter().collect();
+    collections.sort_by_key(|collection| collection.id);
+
+    for collection in collections {
+        let mut entries: Vec<_> = stdb
+            .db()
+            .lore_entry()
+            .iter()
+            .filter(|entry| entry.collection_id == collection.id)
+            .collect();
+        entries.sort_by_key(|entry| entry.id);
+
+        let found = entries
+            .iter()
+            .filter(|entry| unlocked.contains(&entry.id))
+            .count();
+        let (title, color) = if completed.contains(&collection.id) {
+            (format!("{} (Complete)", collection.name), COMPLETE_TEXT)
+        } else {
+            (
+                format!("{} ({}/{})", collection.name, found, entries.len()),
+                LABEL_TEXT,
+            )
+        };
+        commands.spawn((text(title, 16.0, color), ChildOf(root)));
+
+        for entry in entries {
+            if unlocked.contains(&entry.id) {
+                commands.spawn((
+                    text(format!("  {}", entry.title), 14.0, LABEL_TEXT),
+                    ChildOf(root),
+                ));
+                commands.spawn((
+                    text(format!("    {}", entry.text), 12.0, OBJECTIVE_TEXT),
+                    ChildOf(root),
+                ));
+            } else {
+                commands.spawn((text("  ???", 14.0, UNDISCOVERED_TEXT), ChildOf(root)));
+            }
+        }
+    }
+}
+
+fn text(value: impl Into<String>, font_size: f32, color: Color) -> impl Bundle {
+    (
+        Text::new(value),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextColor(color),
+    )
+}
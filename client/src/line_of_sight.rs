@@ -0,0 +1,175 @@
+//! Static geometry that blocks line of sight, and the target highlights that depend on it.
+//!
+//! NPCs within targeting range are ringed in green when the local character can see
+//! them and in grey when geometry is in the way, using the same checks the server runs
+//! when validating attacks and casts.
+
+use crate::{
+    AppSystems,
+    player::PlayerState,
+    screens::Screen,
+    spacetime::SpacetimeDB,
+    stdb::{
+        CollisionShapeKind, CollisionShapeTableAccess, EntityTableAccess, NpcTableAccess,
+        TransformTableAccess,
+    },
+};
+use bevy::{platform::collections::HashSet, prelude::*};
+use common::{
+    line_of_sight::{Shape, StaticGeometry},
+    math,
+};
+use spacetimedb_sdk::Table;
+
+/// How tall blockers are drawn. They block sight at every height.
+const OBSTACLE_HEIGHT: f32 = 3.0;
+const OBSTACLE_COLOR: Color = Color::srgb(0.45, 0.42, 0.38);
+/// Matches the longest range abilities can be used from.
+const HIGHLIGHT_RANGE: f32 = 30.0;
+const HIGHLIGHT_RADIUS: f32 = 0.8;
+const IN_SIGHT_COLOR: Color = Color::srgb(0.3, 0.9, 0.4);
+const OUT_OF_SIGHT_COLOR: Color = Color::srgb(0.5, 0.5, 0.5);
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), setup);
+    app.add_systems(
+        Update,
+        (sync_geometry, highlight_targets)
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// The subscribed collision shapes, bucketed for line of sight checks.
+#[derive(Resource, Default)]
+pub struct Geometry {
+    pub geometry: StaticGeometry,
+    rows: u64,
+}
+
+/// The rendered blocker of a single collision shape.
+#[derive(Component)]
+struct Obstacle;
+
+fn setup(mut commands: Commands) {
+    commands.insert_resource(Geometry::default());
+}
+
+fn to_shape(kind: &CollisionShapeKind) -> Shape {
+    match kind {
+        CollisionShapeKind::Circle(circle) => Shape::Circle {
+            center: [circle.x, circle.z],
+            radius: circle.radius,
+        },
+        CollisionShapeKind::Rect(rect) => Shape::Rect {
+            min: [rect.min_x, rect.min_z],
+            max: [rect.max_x, rect.max_z],
+        },
+    }
+}
+
+/// Rebuilds the geometry and its obstacles whenever the collision shapes change.
+fn sync_geometry(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    stdb: SpacetimeDB,
+    geometry: Option<ResMut<Geometry>>,
+    obstacle_query: Query<Entity, With<Obstacle>>,
+) {
+    let Some(mut geometry) = geometry else {
+        return;
+    };
+    // Static geometry only ever changes when a whole subscription comes or goes
+    let rows = stdb.db().collision_shape().count();
+    if rows == geometry.rows {
+        return;
+    }
+    geometry.rows = rows;
+    geometry.geometry.clear();
+    for entity in &obstacle_query {
+        commands.entity(entity).despawn();
+    }
+
+    let material = materials.add(OBSTACLE_COLOR);
+    let mut drawn = HashSet::new();
+    for row in stdb.db().collision_shape().iter() {
+        let shape = to_shape(&row.kind);
+        geometry.geometry.insert_in_chunk(row.chunk_id, shape);
+
+        // Shapes spanning several chunks only need drawing once
+        if !drawn.insert(row.shape_id) {
+            continue;
+        }
+        let (mesh, x, z) = match shape {
+            Shape::Circle { center, radius } => (
+                meshes.add(Cylinder::new(radius, OBSTACLE_HEIGHT)),
+                center[0],
+                center[1],
+            ),
+            Shape::Rect { min, max } => (
+                meshes.add(Cuboid::new(
+                    max[0] - min[0],
+                    OBSTACLE_HEIGHT,
+                    max[1] - min[1],
+                )),
+                (min[0] + max[0]) / 2.0,
+                (min[1] + max[1]) / 2.0,
+            ),
+        };
+        commands.spawn((
+            DespawnOnExit(Screen::Gameplay),
+            Obstacle,
+            Mesh3d(mesh),
+            MeshMaterial3d(material.clone()),
+            Transform::from_xyz(x, OBSTACLE_HEIGHT / 2.0, z),
+        ));
+    }
+}
+
+/// Rings the NPCs in range of the local character by whether they are in sight.
+fn highlight_targets(
+    mut gizmos: Gizmos,
+    stdb: SpacetimeDB,
+    player: Option<Res<PlayerState>>,
+    geometry: Option<Res<Geometry>>,
+) {
+    let (Some(player), Some(geometry)) = (player, geometry) else {
+        return;
+    };
+    let Some(own) = stdb.db().transform().id().find(&player.transform_id) else {
+        return;
+    };
+    let from = math::Vec3::from(own.translation).xz();
+
+    for npc in stdb.db().npc().iter() {
+        let Some(transform) = stdb
+            .db()
+            .entity()
+            .id()
+            .find(&npc.entity_id)
+            .and_then(|entity| stdb.db().transform().id().find(&entity.transform_id))
+        else {
+            continue;
+        };
+        let to = math::Vec3::from(transform.translation).xz();
+        if common::distance_squared(from, to) > HIGHLIGHT_RANGE * HIGHLIGHT_RANGE {
+            continue;
+        }
+
+        let color = if geometry.geometry.has_line_of_sight(from, to) {
+            IN_SIGHT_COLOR
+        } else {
+            OUT_OF_SIGHT_COLOR
+        };
+        gizmos.circle(
+            Isometry3d::new(
+                Vec3::new(to[0], 0.02, to[1]),
+                Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+            ),
+            HIGHLIGHT_RADIUS,
+            color,
+        );
+    }
+}
@@ -8,13 +8,25 @@ mod dev_tools;
 
 mod camera;
 mod cursor;
+mod dialogue;
+mod dungeon;
+mod gathering;
+mod journal;
+mod line_of_sight;
+mod map;
+mod mount;
 mod movement;
+mod party;
+mod pet;
 mod player;
 mod screens;
+mod social;
 mod spacetime;
 mod stdb;
 mod theme;
+mod travel;
 mod ui;
+mod veil;
 
 #[cfg(target_os = "macos")]
 use bevy::window::CompositeAlphaMode;
@@ -59,8 +71,22 @@ impl Plugin for AppPlugin {
             spacetime::plugin,
             screens::plugin,
             player::plugin,
+            map::plugin,
             camera::plugin,
             movement::plugin,
+        ));
+        app.add_plugins((
+            party::plugin,
+            social::plugin,
+            journal::plugin,
+            dialogue::plugin,
+            veil::plugin,
+            gathering::plugin,
+            line_of_sight::plugin,
+            pet::plugin,
+            dungeon::plugin,
+            travel::plugin,
+            mount::plugin,
             ui::plugin,
             cursor::plugin,
         ));
@@ -0,0 +1,75 @@
+//! Keeps the client subscribed to the map the local character is on.
+//!
+//! Transforms, entities, NPCs and collision shapes are only subscribed to for the current
+//! map, so changing maps swaps them all out at once. The local character's own
+//! transform and entity are part of the gameplay subscription and always available.
+
+use crate::{
+    AppSystems,
+    player::PlayerState,
+    screens::Screen,
+    spacetime::{SpacetimeDB, StdbSubscriptions, SubKey},
+    stdb::TransformTableAccess,
+};
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), setup);
+    app.add_systems(OnExit(Screen::Gameplay), teardown);
+    app.add_systems(
+        Update,
+        sync_map_subscription
+            .in_set(AppSystems::ServerUpdate)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// The open world map, matching the server's `map::OVERWORLD_MAP_ID`.
+pub const OVERWORLD_MAP_ID: u32 = 1;
+
+/// The map the client is subscribed to.
+#[derive(Resource, Default, Debug)]
+pub struct CurrentMap(pub Option<u32>);
+
+fn setup(mut commands: Commands) {
+    commands.insert_resource(CurrentMap::default());
+}
+
+fn teardown(mut commands: Commands, mut stdb_subscriptions: ResMut<StdbSubscriptions>) {
+    stdb_subscriptions.remove(SubKey::MapData);
+    commands.remove_resource::<CurrentMap>();
+}
+
+/// Resubscribes whenever the local character shows up on another map.
+fn sync_map_subscription(
+    stdb: SpacetimeDB,
+    player: Option<Res<PlayerState>>,
+    current: Option<ResMut<CurrentMap>>,
+    mut stdb_subscriptions: ResMut<StdbSubscriptions>,
+) {
+    let (Some(player), Some(mut current)) = (player, current) else {
+        return;
+    };
+    let Some(own) = stdb.db().transform().id().find(&player.transform_id) else {
+        return;
+    };
+    if current.0 == Some(own.map_id) {
+        return;
+    }
+    current.0 = Some(own.map_id);
+
+    let map_id = own.map_id;
+    stdb_subscriptions.replace(
+        SubKey::MapData,
+        [stdb.subscription_builder().subscribe(vec![
+            format!("SELECT * FROM transform WHERE map_id = {map_id}"),
+            format!(
+                "SELECT entity.* FROM entity JOIN transform ON entity.transform_id = transform.id WHERE transform.map_id = {map_id}"
+            ),
+            format!("SELECT * FROM collision_shape WHERE map_id = {map_id}"),
+            format!(
+                "SELECT npc.* FROM npc JOIN entity ON npc.entity_id = entity.id JOIN transform ON entity.transform_id = transform.id WHERE transform.map_id = {map_id}"
+            ),
+        ])],
+    );
+}
@@ -0,0 +1,237 @@
+//! Mounts: the frame for calling and dismissing the local character's mounts, and the
+//! mount shown under every rider on the map.
+
+use crate::{
+    AppSystems,
+    player::PlayerState,
+    screens::Screen,
+    spacetime::SpacetimeDB,
+    stdb::{
+        EntityTableAccess, InventoryItemTableAccess, MountDefTableAccess, MountedTableAccess,
+        TransformTableAccess, dismount, mount,
+    },
+    theme::colors::LABEL_TEXT,
+    ui::widgets::button::{ButtonProps, button},
+};
+use bevy::{platform::collections::HashSet, prelude::*, ui_widgets::observe};
+use spacetimedb_sdk::Table;
+
+const MOUNT_COLOR: Color = Color::srgb(0.55, 0.4, 0.25);
+/// Sits the mount under its rider, who stands at the transform's translation.
+const MOUNT_OFFSET: Vec3 = Vec3::new(0.0, 0.4, 0.0);
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), setup);
+    app.add_systems(
+        Update,
+        (sync_mount_frame, sync_mounts, follow_riders)
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Component)]
+struct MountFrame;
+
+/// A snapshot of what the frame currently shows: the mounts the local character can
+/// ride and the one they are riding.
+#[derive(Component, Default, PartialEq)]
+struct MountContents(Vec<u32>, Option<u32>);
+
+/// The mount shown under a riding entity.
+#[derive(Component)]
+struct MountVisual(u32);
+
+#[derive(Resource)]
+struct MountAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(MountAssets {
+        mesh: meshes.add(Cuboid::new(0.8, 0.8, 1.6)),
+        material: materials.add(MOUNT_COLOR),
+    });
+    commands.spawn((
+        DespawnOnExit(Screen::Gameplay),
+        MountFrame,
+        MountContents::default(),
+        Node {
+            display: Display::None,
+            position_type: PositionType::Absolute,
+            left: px(200),
+            bottom: px(10),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(px(4)),
+            row_gap: px(4),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0., 0., 0., 0.5)),
+    ));
+}
+
+/// Rebuilds the frame whenever the local character gains a mount, mounts or dismounts.
+fn sync_mount_frame(
+    mut commands: Commands,
+    stdb: SpacetimeDB,
+    player: Option<Res<PlayerState>>,
+    frame: Single<(Entity, &mut Node, &mut MountContents), With<MountFrame>>,
+) {
+    let Some(player) = player else {
+        return;
+    };
+    let (root, mut node, mut shown) = frame.into_inner();
+
+    // Only the local character's inventory is visible
+    let owned: HashSet<u32> = stdb
+        .db()
+        .inventory_item()
+        .iter()
+        .map(|item| item.item_def_id)
+        .collect();
+    let mut rideable: Vec<u32> = stdb
+        .db()
+        .mount_def()
+        .iter()
+        .filter(|mount_def| {
+            mount_def
+                .item_def_id
+                .is_some_and(|item_def_id| owned.contains(&item_def_id))
+                || mount_def.class_id == Some(player.class_id)
+        })
+        .map(|mount_def| mount_def.id)
+        .collect();
+    rideable.sort();
+    let riding = stdb
+        .db()
+        .mounted()
+        .entity_id()
+        .find(&player.entity_id)
+        .map(|mounted| mounted.mount_def_id);
+    let current = MountContents(rideable, riding);
+    if *shown == current {
+        return;
+    }
+
+    commands.entity(root).despawn_related::<Children>();
+    node.display = if current.0.is_empty() {
+        Display::None
+    } else {
+        Display::Flex
+    };
+    commands.spawn((
+        Text::new("Mounts"),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(LABEL_TEXT),
+        ChildOf(root),
+    ));
+    for mount_def_id in &current.0 {
+        if current.1 == Some(*mount_def_id) {
+            continue;
+        }
+        let Some(mount_def) = stdb.db().mount_def().id().find(mount_def_id) else {
+            continue;
+        };
+        let mount_def_id = mount_def.id;
+        commands.spawn((
+            button(Spawn(Text::new(mount_def.name)), ButtonProps::default()),
+            observe(move |_: On<Pointer<Click>>, stdb: SpacetimeDB| {
+                if let Err(err) = stdb.reducers().mount(mount_def_id) {
+                    println!("Failed to mount: {err:?}");
+                }
+            }),
+            ChildOf(root),
+        ));
+    }
+    if current.1.is_some() {
+        commands.spawn((
+            button(Spawn(Text::new("Dismount")), ButtonProps::default()),
+            observe(|_: On<Pointer<Click>>, stdb: SpacetimeDB| {
+                if let Err(err) = stdb.reducers().dismount() {
+                    println!("Failed to dismount: {err:?}");
+                }
+            }),
+            ChildOf(root),
+        ));
+    }
+
+    *shown = current;
+}
+
+/// Spawns a mount under entities that mounted and removes it from those that got off.
+fn sync_mounts(
+    mut commands: Commands,
+    stdb: SpacetimeDB,
+    assets: Option<Res<MountAssets>>,
+    visuals: Query<(Entity, &MountVisual)>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+
+    let riders: HashSet<u32> = stdb
+        .db()
+        .mounted()
+        .iter()
+        .map(|mounted| mounted.entity_id)
+        .collect();
+    let mut shown = HashSet::new();
+
+    for (entity, visual) in &visuals {
+        if riders.contains(&visual.0) {
+            shown.insert(visual.0);
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for entity_id in riders.difference(&shown) {
+        commands.spawn((
+            DespawnOnExit(Screen::Gameplay),
+            MountVisual(*entity_id),
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Visibility::Hidden,
+            Transform::default(),
+        ));
+    }
+}
+
+/// Keeps every mount under its rider and facing where they face, hiding those whose
+/// rider isn't on the map.
+fn follow_riders(
+    stdb: SpacetimeDB,
+    mut visuals: Query<(&MountVisual, &mut Transform, &mut Visibility)>,
+) {
+    for (visual, mut transform, mut visibility) in &mut visuals {
+        // Only transforms on the current map are subscribed to
+        let Some(rider) = stdb
+            .db()
+            .entity()
+            .id()
+            .find(&visual.0)
+            .and_then(|entity| stdb.db().transform().id().find(&entity.transform_id))
+        else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        transform.translation = Vec3::from(rider.translation) + MOUNT_OFFSET;
+        // Transforms that never moved can still hold an all-zero rotation
+        let rotation = Quat::from(rider.rotation);
+        transform.rotation = if rotation.is_normalized() {
+            rotation
+        } else {
+            Quat::IDENTITY
+        };
+        *visibility = Visibility::Inherited;
+    }
+}
@@ -0,0 +1,166 @@
+//! Unit frames showing the name, health and mana of every member of the local player's party.
+
+use crate::{
+    AppSystems,
+    screens::Screen,
+    spacetime::SpacetimeDB,
+    stdb::{HealthTableAccess, ManaTableAccess, PartyMember, PartyMemberTableAccess},
+    theme::colors::LABEL_TEXT,
+};
+use bevy::prelude::*;
+use spacetimedb_sdk::Table;
+
+const FRAME_WIDTH: f32 = 180.0;
+const HEALTH_COLOR: Color = Color::srgb(0.36, 0.72, 0.36);
+const MANA_COLOR: Color = Color::srgb(0.33, 0.45, 0.86);
+const BAR_BACKGROUND: Color = Color::srgba(1., 1., 1., 0.1);
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), setup);
+    app.add_systems(
+        Update,
+        (sync_party_frames, update_bars)
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// The container all party frames are spawned into.
+#[derive(Component)]
+struct PartyFrames;
+
+#[derive(Component)]
+struct PartyFrame {
+    character_id: u32,
+}
+
+/// The fill of a health bar, referencing the `health` row it displays.
+#[derive(Component)]
+struct HealthBar(u32);
+
+/// The fill of a mana bar, referencing the `mana` row it displays.
+#[derive(Component)]
+struct ManaBar(u32);
+
+fn setup(mut commands: Commands) {
+    commands.spawn((
+        DespawnOnExit(Screen::Gameplay),
+        PartyFrames,
+        Node {
+            position_type: PositionType::Absolute,
+            left: px(10),
+            top: px(10),
+            flex_direction: FlexDirection::Column,
+            row_gap: px(6),
+            ..default()
+        },
+    ));
+}
+
+/// Rebuilds the frames whenever someone joins or leaves the party.
+fn sync_party_frames(
+    mut commands: Commands,
+    stdb: SpacetimeDB,
+    root_query: Query<(Entity, Option<&Children>), With<PartyFrames>>,
+    frame_query: Query<&PartyFrame>,
+) {
+    let Ok((root, children)) = root_query.single() else {
+        return;
+    };
+
+    let mut members: Vec<PartyMember> = stdb.db().party_member().iter().collect();
+    members.sort_by_key(|member| member.character_id);
+
+    let shown: Vec<u32> = children
+        .map(|children| {
+            children
+                .iter()
+                .filter_map(|child| frame_query.get(child).ok())
+                .map(|frame| frame.character_id)
+                .collect()
+        })
+        .unwrap_or_default();
+    let wanted: Vec<u32> = members.iter().map(|member| member.character_id).collect();
+    if shown == wanted {
+        return;
+    }
+
+    commands.entity(root).despawn_related::<Children>();
+    for member in members {
+        commands.spawn((party_frame(member), ChildOf(root)));
+    }
+}
+
+fn update_bars(
+    stdb: SpacetimeDB,
+    mut health_bars: Query<(&HealthBar, &mut Node), Without<ManaBar>>,
+    mut mana_bars: Query<(&ManaBar, &mut Node), Without<HealthBar>>,
+) {
+    for (bar, mut node) in &mut health_bars {
+        let fraction = stdb
+            .db()
+            .health()
+            .id()
+            .find(&bar.0)
+            .map_or(0.0, |row| row.health as f32 / row.max_health.max(1) as f32);
+        node.width = percent(fraction * 100.0);
+    }
+    for (bar, mut node) in &mut mana_bars {
+        let fraction = stdb
+            .db()
+            .mana()
+            .id()
+            .find(&bar.0)
+            .map_or(0.0, |row| row.mana as f32 / row.max_mana.max(1) as f32);
+        node.width = percent(fraction * 100.0);
+    }
+}
+
+fn party_frame(member: PartyMember) -> impl Bundle {
+    (
+        PartyFrame {
+            character_id: member.character_id,
+        },
+        Node {
+            width: px(FRAME_WIDTH),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(px(4)),
+            row_gap: px(2),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0., 0., 0., 0.5)),
+        children![
+            (
+                Text::new(member.name),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(LABEL_TEXT),
+            ),
+            bar(HealthBar(member.health_id), HEALTH_COLOR),
+            bar(ManaBar(member.mana_id), MANA_COLOR),
+        ],
+    )
+}
+
+fn bar(fill: impl Component, color: Color) -> impl Bundle {
+    (
+        Node {
+            width: percent(100),
+            height: px(8),
+            ..default()
+        },
+        BackgroundColor(BAR_BACKGROUND),
+        children![(
+            fill,
+            Node {
+                width: percent(100),
+                height: percent(100),
+                ..default()
+            },
+            BackgroundColor(color),
+        )],
+    )
+}
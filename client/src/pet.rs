@@ -0,0 +1,162 @@
+//! The frame of the local character's pet, with its health and the commands it obeys.
+
+use crate::{
+    AppSystems,
+    player::PlayerState,
+    screens::Screen,
+    spacetime::SpacetimeDB,
+    stdb::{
+        HealthTableAccess, OwnedEntityTableAccess, PetCommand, SummonDefTableAccess, SummonKind,
+        command_pet, dismiss_summon,
+    },
+    theme::colors::LABEL_TEXT,
+    ui::widgets::button::{ButtonProps, button},
+};
+use bevy::{prelude::*, ui_widgets::observe};
+use spacetimedb_sdk::Table;
+
+const FRAME_WIDTH: f32 = 180.0;
+const HEALTH_COLOR: Color = Color::srgb(0.36, 0.72, 0.36);
+const BAR_BACKGROUND: Color = Color::srgba(1., 1., 1., 0.1);
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), setup);
+    app.add_systems(
+        Update,
+        (sync_pet_frame, update_health_bar)
+            .chain()
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Component)]
+struct PetFrame;
+
+/// The pet entity the frame currently shows, if any.
+#[derive(Component, Default, PartialEq)]
+struct ShownPet(Option<u32>);
+
+/// The fill of the pet's health bar, referencing the `health` row it displays.
+#[derive(Component)]
+struct PetHealthBar(u32);
+
+fn setup(mut commands: Commands) {
+    commands.spawn((
+        DespawnOnExit(Screen::Gameplay),
+        PetFrame,
+        ShownPet::default(),
+        Node {
+            display: Display::None,
+            position_type: PositionType::Absolute,
+            left: px(10),
+            bottom: px(10),
+            width: px(FRAME_WIDTH),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(px(4)),
+            row_gap: px(4),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0., 0., 0., 0.5)),
+    ));
+}
+
+/// Rebuilds the frame whenever the local character summons or loses a pet.
+fn sync_pet_frame(
+    mut commands: Commands,
+    stdb: SpacetimeDB,
+    player: Option<Res<PlayerState>>,
+    frame: Single<(Entity, &mut Node, &mut ShownPet), With<PetFrame>>,
+) {
+    let Some(player) = player else {
+        return;
+    };
+    let (root, mut node, mut shown) = frame.into_inner();
+
+    let pet =
+        stdb.db().owned_entity().iter().find(|owned| {
+            owned.owner_entity_id == player.entity_id && owned.kind == SummonKind::Pet
+        });
+    let current = ShownPet(pet.as_ref().map(|pet| pet.entity_id));
+    if *shown == current {
+        return;
+    }
+    *shown = current;
+
+    commands.entity(root).despawn_related::<Children>();
+    let Some(pet) = pet else {
+        node.display = Display::None;
+        return;
+    };
+    node.display = Display::Flex;
+
+    let name = stdb
+        .db()
+        .summon_def()
+        .id()
+        .find(&pet.summon_def_id)
+        .map(|summon_def| summon_def.name)
+        .unwrap_or_default();
+    commands.spawn((
+        Text::new(name),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(LABEL_TEXT),
+        ChildOf(root),
+    ));
+    commands.spawn((
+        Node {
+            width: percent(100),
+            height: px(8),
+            ..default()
+        },
+        BackgroundColor(BAR_BACKGROUND),
+        children![(
+            PetHealthBar(pet.health_id),
+            Node {
+                width: percent(100),
+                height: percent(100),
+                ..default()
+            },
+            BackgroundColor(HEALTH_COLOR),
+        )],
+        ChildOf(root),
+    ));
+
+    for (label, command) in [("Follow", PetCommand::Follow), ("Stay", PetCommand::Stay)] {
+        commands.spawn((
+            button(Spawn(Text::new(label)), ButtonProps::default()),
+            observe(move |_: On<Pointer<Click>>, stdb: SpacetimeDB| {
+                if let Err(err) = stdb.reducers().command_pet(command.clone()) {
+                    println!("Failed to command pet: {err:?}");
+                }
+            }),
+            ChildOf(root),
+        ));
+    }
+
+    let entity_id = pet.entity_id;
+    commands.spawn((
+        button(Spawn(Text::new("Dismiss")), ButtonProps::default()),
+        observe(move |_: On<Pointer<Click>>, stdb: SpacetimeDB| {
+            if let Err(err) = stdb.reducers().dismiss_summon(entity_id) {
+                println!("Failed to dismiss pet: {err:?}");
+            }
+        }),
+        ChildOf(root),
+    ));
+}
+
+fn update_health_bar(stdb: SpacetimeDB, mut bars: Query<(&PetHealthBar, &mut Node)>) {
+    for (bar, mut node) in &mut bars {
+        let fraction = stdb
+            .db()
+            .health()
+            .id()
+            .find(&bar.0)
+            .map_or(0.0, |row| row.health as f32 / row.max_health.max(1) as f32);
+        node.width = percent(fraction * 100.0);
+    }
+}
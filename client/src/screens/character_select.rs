@@ -109,8 +109,71 @@ fn setup(mut commands: Commands, stdb: SpacetimeDB) {
                             SubKey::LocalGameplayData,
                             stdb.subscription_builder().subscribe(vec![
                                 "SELECT * from character_pawn",
-                                "SELECT * FROM entity",
-                                "SELECT * from transform",
+                                // The rest of the map is subscribed to once we know which map
+                                "SELECT entity.* FROM entity JOIN character_pawn ON entity.id = character_pawn.entity_id WHERE character_pawn.identity = :sender",
+                                "SELECT transform.* FROM transform JOIN character_def ON transform.id = character_def.transform_id WHERE character_def.identity = :sender",
+                                "SELECT * FROM map",
+                                "SELECT * FROM health",
+                                "SELECT * FROM mana",
+                                "SELECT * FROM party",
+                                "SELECT * FROM party_member",
+                                "SELECT * FROM party_invite",
+                                "SELECT * FROM guild",
+                                "SELECT * FROM guild_rank",
+                                "SELECT * FROM guild_member",
+                                "SELECT * FROM guild_invite",
+                                "SELECT * FROM guild_message",
+                                "SELECT * FROM friend",
+                                "SELECT * FROM ignored_player",
+                                "SELECT * FROM friend_notification",
+                                "SELECT * FROM npc_def",
+                                "SELECT * FROM quest_def",
+                                "SELECT * FROM quest_objective",
+                                "SELECT * FROM quest_progress",
+                                "SELECT * FROM dialogue_node",
+                                "SELECT * FROM dialogue_choice",
+                                "SELECT * FROM dialogue_session",
+                                "SELECT * FROM veil",
+                                "SELECT * FROM veil_chunk",
+                                "SELECT * FROM veil_exposure",
+                                "SELECT * FROM veil_resource",
+                                "SELECT * FROM lore_collection",
+                                "SELECT * FROM lore_entry",
+                                "SELECT * FROM lore_object",
+                                "SELECT * FROM lore_unlocked",
+                                "SELECT * FROM lore_collection_completed",
+                                "SELECT * FROM currency",
+                                "SELECT * FROM vendor",
+                                "SELECT * FROM vendor_item",
+                                "SELECT * FROM vendor_buyback",
+                                "SELECT * FROM profession",
+                                "SELECT * FROM profession_skill",
+                                "SELECT * FROM resource_node",
+                                "SELECT * FROM gather_cast",
+                                "SELECT * FROM recipe",
+                                "SELECT * FROM recipe_ingredient",
+                                "SELECT * FROM mailbox",
+                                "SELECT * FROM mail",
+                                "SELECT * FROM item_type",
+                                "SELECT * FROM effect_def",
+                                "SELECT * FROM active_effect",
+                                "SELECT * FROM npc_threat",
+                                "SELECT * FROM stealth",
+                                "SELECT * FROM stealth_detection",
+                                "SELECT * FROM summon_def",
+                                "SELECT * FROM owned_entity",
+                                "SELECT * FROM dungeon_def",
+                                "SELECT * FROM dungeon_portal",
+                                "SELECT * FROM dungeon_instance",
+                                "SELECT * FROM dungeon_lockout",
+                                "SELECT * FROM waypoint",
+                                "SELECT * FROM waypoint_discovered",
+                                "SELECT * FROM hearth",
+                                "SELECT * FROM teleport_cast",
+                                "SELECT * FROM teleport_cooldown",
+                                "SELECT * FROM inventory_item",
+                                "SELECT * FROM mount_def",
+                                "SELECT * FROM mounted",
                             ]),
                         );
 
@@ -1,10 +1,11 @@
 //! The screen state for the main gameplay.
 
 use crate::{
+    journal::toggle_journal,
     screens::Screen,
     ui::widgets::button::{ButtonIcon, IconButtonProps, icon_button},
 };
-use bevy::prelude::*;
+use bevy::{prelude::*, ui_widgets::observe};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Gameplay), setup);
@@ -37,10 +38,13 @@ fn setup(mut commands: Commands) {
                 icon: ButtonIcon::Settings,
                 ..default()
             },),
-            icon_button(IconButtonProps {
-                icon: ButtonIcon::Journal,
-                ..default()
-            },),
+            (
+                icon_button(IconButtonProps {
+                    icon: ButtonIcon::Journal,
+                    ..default()
+                }),
+                observe(toggle_journal),
+            ),
         ],
     ));
 }
@@ -0,0 +1,76 @@
+//! Short-lived messages telling the local player when friends come online or go offline.
+
+use crate::{AppSystems, screens::Screen, stdb::FriendNotification, theme::colors::LABEL_TEXT};
+use bevy::prelude::*;
+use bevy_spacetimedb::ReadInsertMessage;
+
+const NOTIFICATION_SECS: f32 = 5.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), setup);
+    app.add_systems(
+        Update,
+        (show_friend_notifications, expire_notifications)
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// The container notifications are stacked in.
+#[derive(Component)]
+struct Notifications;
+
+#[derive(Component)]
+struct Notification(Timer);
+
+fn setup(mut commands: Commands) {
+    commands.spawn((
+        DespawnOnExit(Screen::Gameplay),
+        Notifications,
+        Node {
+            position_type: PositionType::Absolute,
+            right: px(10),
+            top: px(10),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::End,
+            row_gap: px(4),
+            ..default()
+        },
+    ));
+}
+
+fn show_friend_notifications(
+    mut commands: Commands,
+    mut messages: ReadInsertMessage<FriendNotification>,
+    root: Single<Entity, With<Notifications>>,
+) {
+    for message in messages.read() {
+        let status = if message.row.online {
+            "has come online"
+        } else {
+            "has gone offline"
+        };
+        commands.spawn((
+            Notification(Timer::from_seconds(NOTIFICATION_SECS, TimerMode::Once)),
+            Text::new(format!("{} {status}.", message.row.name)),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(LABEL_TEXT),
+            ChildOf(*root),
+        ));
+    }
+}
+
+fn expire_notifications(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut notifications: Query<(Entity, &mut Notification)>,
+) {
+    for (entity, mut notification) in &mut notifications {
+        if notification.0.tick(time.delta()).is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! Conversions from the generated math types to `common`'s and Bevy's.
+//!
+//! The bindings generate their own `Vec3` and `Quat` for the ones the server stores, so
+//! these bridge them to the shared types for the spatial code in `common`.
+
+use crate::stdb;
+use common::math;
+
+impl From<stdb::Vec3> for math::Vec3 {
+    fn from(v: stdb::Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<stdb::Quat> for math::Quat {
+    fn from(q: stdb::Quat) -> Self {
+        Self {
+            x: q.x,
+            y: q.y,
+            z: q.z,
+            w: q.w,
+        }
+    }
+}
+
+impl From<stdb::Vec3> for bevy::math::Vec3 {
+    fn from(v: stdb::Vec3) -> Self {
+        math::Vec3::from(v).into()
+    }
+}
+
+impl From<stdb::Quat> for bevy::math::Quat {
+    fn from(q: stdb::Quat) -> Self {
+        math::Quat::from(q).into()
+    }
+}
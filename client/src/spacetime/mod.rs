@@ -1,9 +1,10 @@
+mod math;
 pub mod reducers;
 pub mod subscription;
 
 use crate::stdb::{
     CharacterDefTableAccess, CharacterPawnTableAccess, DbConnection, EntityMovementTableAccess,
-    EntityTableAccess, RemoteTables, TransformTableAccess,
+    EntityTableAccess, FriendNotificationTableAccess, RemoteTables, TransformTableAccess,
 };
 use bevy::prelude::*;
 use bevy_spacetimedb::{ReadStdbConnectedMessage, StdbConnection, StdbPlugin};
@@ -33,6 +34,7 @@ pub(super) fn plugin(app: &mut App) {
             .add_table(RemoteTables::entity)
             .add_table(RemoteTables::character_pawn)
             .add_table(RemoteTables::entity_movement)
+            .add_table(RemoteTables::friend_notification)
             .with_run_fn(DbConnection::run_threaded),
     );
 
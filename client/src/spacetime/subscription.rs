@@ -8,6 +8,8 @@ use spacetimedb_sdk::SubscriptionHandle as SubscriptionHandleTrait;
 pub enum SubKey {
     OwnedCharacterData,
     LocalGameplayData,
+    /// Everything placed on the map the local character is on, see `map`.
+    MapData,
     GlobalData,
 }
 
@@ -15,6 +15,7 @@ pub struct CharacterDef {
     pub race_id: u32,
     pub health_id: u32,
     pub mana_id: u32,
+    pub xp_id: u32,
 }
 
 impl __sdk::InModule for CharacterDef {
@@ -11,6 +11,9 @@ pub struct CharacterPawn {
     pub identity: __sdk::Identity,
     pub character_id: u32,
     pub entity_id: u32,
+    pub health_id: u32,
+    pub mana_id: u32,
+    pub map_id: u32,
 }
 
 impl __sdk::InModule for CharacterPawn {
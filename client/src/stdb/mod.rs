@@ -6,89 +6,765 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod abandon_quest_reducer;
+pub mod accept_guild_invite_reducer;
+pub mod accept_party_invite_reducer;
+pub mod accept_quest_reducer;
+pub mod accept_trade_reducer;
+pub mod active_effect_table;
+pub mod active_effect_type;
+pub mod add_friend_reducer;
+pub mod auction_expire_reducer;
+pub mod auction_expiry_timer_table;
+pub mod auction_expiry_timer_type;
+pub mod auction_listing_table;
+pub mod auction_listing_type;
+pub mod bid_auction_reducer;
+pub mod buy_from_vendor_reducer;
+pub mod buyback_from_vendor_reducer;
+pub mod buyout_auction_reducer;
+pub mod cancel_auction_reducer;
+pub mod cancel_gather_reducer;
+pub mod cancel_teleport_reducer;
+pub mod cancel_trade_reducer;
 pub mod character_def_table;
 pub mod character_def_type;
 pub mod character_pawn_table;
 pub mod character_pawn_type;
+pub mod choose_dialogue_option_reducer;
 pub mod class_table;
 pub mod class_type;
+pub mod collision_circle_type;
+pub mod collision_rect_type;
+pub mod collision_shape_kind_type;
+pub mod collision_shape_table;
+pub mod collision_shape_type;
+pub mod command_pet_reducer;
+pub mod complete_gather_reducer;
+pub mod complete_teleport_reducer;
+pub mod craft_reducer;
+pub mod create_auction_reducer;
 pub mod create_character_input_type;
 pub mod create_character_reducer;
+pub mod create_guild_reducer;
+pub mod currency_ledger_table;
+pub mod currency_ledger_type;
+pub mod currency_reason_type;
+pub mod currency_table;
+pub mod currency_type;
+pub mod decline_guild_invite_reducer;
+pub mod decline_party_invite_reducer;
 pub mod delete_character_reducer;
+pub mod delete_mail_reducer;
+pub mod demote_guild_member_reducer;
+pub mod dialogue_action_type;
+pub mod dialogue_choice_table;
+pub mod dialogue_choice_type;
+pub mod dialogue_condition_type;
+pub mod dialogue_node_table;
+pub mod dialogue_node_type;
+pub mod dialogue_session_table;
+pub mod dialogue_session_type;
+pub mod disband_guild_reducer;
+pub mod discover_lore_reducer;
+pub mod dismiss_summon_reducer;
+pub mod dismount_reducer;
+pub mod dungeon_cleanup_reducer;
+pub mod dungeon_cleanup_timer_table;
+pub mod dungeon_cleanup_timer_type;
+pub mod dungeon_def_table;
+pub mod dungeon_def_type;
+pub mod dungeon_instance_table;
+pub mod dungeon_instance_type;
+pub mod dungeon_lockout_table;
+pub mod dungeon_lockout_type;
+pub mod dungeon_portal_table;
+pub mod dungeon_portal_type;
+pub mod dungeon_reset_reducer;
+pub mod dungeon_reset_timer_table;
+pub mod dungeon_reset_timer_type;
+pub mod dungeon_spawn_table;
+pub mod dungeon_spawn_type;
+pub mod effect_def_table;
+pub mod effect_def_type;
+pub mod effect_kind_type;
+pub mod effect_tick_reducer;
+pub mod effect_tick_timer_table;
+pub mod effect_tick_timer_type;
+pub mod end_dialogue_reducer;
+pub mod enter_dungeon_reducer;
+pub mod enter_stealth_reducer;
 pub mod enter_world_reducer;
 pub mod entity_movement_table;
 pub mod entity_movement_type;
 pub mod entity_table;
 pub mod entity_type;
+pub mod friend_notification_table;
+pub mod friend_notification_type;
+pub mod friend_table;
+pub mod friend_type;
+pub mod gather_cast_table;
+pub mod gather_cast_type;
+pub mod gather_timer_table;
+pub mod gather_timer_type;
+pub mod gather_veil_resource_reducer;
+pub mod guild_invite_table;
+pub mod guild_invite_type;
+pub mod guild_member_table;
+pub mod guild_member_type;
+pub mod guild_message_table;
+pub mod guild_message_type;
+pub mod guild_rank_table;
+pub mod guild_rank_type;
+pub mod guild_table;
+pub mod guild_type;
 pub mod health_table;
 pub mod health_type;
+pub mod hearth_table;
+pub mod hearth_type;
 pub mod identity_connected_reducer;
 pub mod identity_disconnected_reducer;
+pub mod ignore_player_reducer;
+pub mod ignored_player_table;
+pub mod ignored_player_type;
+pub mod inventory_item_table;
+pub mod inventory_item_type;
+pub mod invite_to_guild_reducer;
+pub mod invite_to_party_reducer;
+pub mod item_def_table;
+pub mod item_def_type;
+pub mod item_grant_type;
+pub mod item_type_table;
+pub mod item_type_type;
+pub mod kick_from_guild_reducer;
+pub mod kick_from_party_reducer;
+pub mod leave_dungeon_reducer;
+pub mod leave_guild_reducer;
+pub mod leave_party_reducer;
+pub mod leave_stealth_reducer;
 pub mod leave_world_reducer;
+pub mod lock_trade_reducer;
+pub mod lore_collection_completed_table;
+pub mod lore_collection_completed_type;
+pub mod lore_collection_table;
+pub mod lore_collection_type;
+pub mod lore_entry_table;
+pub mod lore_entry_type;
+pub mod lore_kind_type;
+pub mod lore_object_table;
+pub mod lore_object_type;
+pub mod lore_unlocked_table;
+pub mod lore_unlocked_type;
+pub mod mail_attachment_type;
+pub mod mail_expire_reducer;
+pub mod mail_expiry_timer_table;
+pub mod mail_expiry_timer_type;
+pub mod mail_item_type;
+pub mod mail_table;
+pub mod mail_type;
+pub mod mailbox_spawn_table;
+pub mod mailbox_spawn_type;
+pub mod mailbox_table;
+pub mod mailbox_type;
 pub mod mana_table;
 pub mod mana_type;
+pub mod map_kind_type;
+pub mod map_table;
+pub mod map_type;
+pub mod mount_def_table;
+pub mod mount_def_type;
+pub mod mount_reducer;
+pub mod mounted_table;
+pub mod mounted_type;
 pub mod move_intent_type;
 pub mod movement_tick_reducer;
 pub mod movement_tick_timer_table;
 pub mod movement_tick_timer_type;
+pub mod npc_def_table;
+pub mod npc_def_type;
+pub mod npc_respawn_reducer;
+pub mod npc_respawn_timer_table;
+pub mod npc_respawn_timer_type;
+pub mod npc_spawn_table;
+pub mod npc_spawn_type;
+pub mod npc_table;
+pub mod npc_threat_table;
+pub mod npc_threat_type;
+pub mod npc_type;
+pub mod objective_kind_type;
+pub mod offer_trade_currency_reducer;
+pub mod offer_trade_item_reducer;
+pub mod owned_entity_table;
+pub mod owned_entity_type;
+pub mod party_invite_table;
+pub mod party_invite_type;
+pub mod party_member_table;
+pub mod party_member_type;
+pub mod party_table;
+pub mod party_type;
+pub mod pet_command_type;
 pub mod player_table;
 pub mod player_type;
+pub mod profession_skill_table;
+pub mod profession_skill_type;
+pub mod profession_table;
+pub mod profession_type;
+pub mod promote_guild_member_reducer;
 pub mod quat_type;
+pub mod quest_condition_type;
+pub mod quest_def_table;
+pub mod quest_def_type;
+pub mod quest_location_check_reducer;
+pub mod quest_location_timer_table;
+pub mod quest_location_timer_type;
+pub mod quest_objective_table;
+pub mod quest_objective_type;
+pub mod quest_progress_table;
+pub mod quest_progress_type;
+pub mod quest_state_type;
 pub mod race_table;
 pub mod race_type;
+pub mod reach_area_type;
+pub mod recipe_ingredient_table;
+pub mod recipe_ingredient_type;
+pub mod recipe_table;
+pub mod recipe_type;
+pub mod remove_friend_reducer;
 pub mod request_move_reducer;
+pub mod request_trade_reducer;
+pub mod resource_node_respawn_reducer;
+pub mod resource_node_respawn_timer_table;
+pub mod resource_node_respawn_timer_type;
+pub mod resource_node_spawn_table;
+pub mod resource_node_spawn_type;
+pub mod resource_node_table;
+pub mod resource_node_type;
+pub mod retract_trade_item_reducer;
+pub mod return_mail_reducer;
+pub mod sell_to_vendor_reducer;
+pub mod send_guild_message_reducer;
+pub mod send_mail_reducer;
+pub mod set_hearth_reducer;
+pub mod start_dialogue_reducer;
+pub mod start_gather_reducer;
+pub mod stat_modifier_type;
+pub mod stat_type;
+pub mod stealth_detection_table;
+pub mod stealth_detection_type;
+pub mod stealth_table;
+pub mod stealth_tick_reducer;
+pub mod stealth_tick_timer_table;
+pub mod stealth_tick_timer_type;
+pub mod stealth_type;
+pub mod summon_def_table;
+pub mod summon_def_type;
+pub mod summon_expire_reducer;
+pub mod summon_expiry_timer_table;
+pub mod summon_expiry_timer_type;
+pub mod summon_kind_type;
+pub mod summon_reducer;
+pub mod summon_tick_reducer;
+pub mod summon_tick_timer_table;
+pub mod summon_tick_timer_type;
+pub mod take_mail_reducer;
+pub mod talk_to_npc_reducer;
+pub mod taunt_reducer;
+pub mod teleport_cast_table;
+pub mod teleport_cast_type;
+pub mod teleport_cooldown_table;
+pub mod teleport_cooldown_type;
+pub mod teleport_destination_type;
+pub mod teleport_reducer;
+pub mod teleport_timer_table;
+pub mod teleport_timer_type;
+pub mod threat_tick_reducer;
+pub mod threat_tick_timer_table;
+pub mod threat_tick_timer_type;
+pub mod trade_offer_table;
+pub mod trade_offer_type;
+pub mod trade_participant_table;
+pub mod trade_participant_type;
+pub mod trade_range_check_reducer;
+pub mod trade_range_timer_table;
+pub mod trade_range_timer_type;
+pub mod trade_session_table;
+pub mod trade_session_type;
+pub mod trade_state_type;
+pub mod transfer_map_reducer;
+pub mod transfer_party_leader_reducer;
 pub mod transform_table;
 pub mod transform_type;
+pub mod turn_in_quest_reducer;
+pub mod unignore_player_reducer;
 pub mod vec_3_type;
+pub mod veil_chunk_table;
+pub mod veil_chunk_type;
+pub mod veil_drift_reducer;
+pub mod veil_drift_timer_table;
+pub mod veil_drift_timer_type;
+pub mod veil_effect_tick_reducer;
+pub mod veil_effect_timer_table;
+pub mod veil_effect_timer_type;
+pub mod veil_exposure_table;
+pub mod veil_exposure_type;
+pub mod veil_resource_table;
+pub mod veil_resource_type;
+pub mod veil_table;
+pub mod veil_type;
+pub mod vendor_buyback_table;
+pub mod vendor_buyback_type;
+pub mod vendor_item_table;
+pub mod vendor_item_type;
+pub mod vendor_restock_reducer;
+pub mod vendor_restock_timer_table;
+pub mod vendor_restock_timer_type;
+pub mod vendor_table;
+pub mod vendor_type;
+pub mod waypoint_discovered_table;
+pub mod waypoint_discovered_type;
+pub mod waypoint_discovery_reducer;
+pub mod waypoint_discovery_timer_table;
+pub mod waypoint_discovery_timer_type;
+pub mod waypoint_table;
+pub mod waypoint_type;
 pub mod xp_progression_table;
 pub mod xp_progression_type;
 pub mod xp_table;
 pub mod xp_type;
 
+pub use abandon_quest_reducer::{
+    abandon_quest, set_flags_for_abandon_quest, AbandonQuestCallbackId,
+};
+pub use accept_guild_invite_reducer::{
+    accept_guild_invite, set_flags_for_accept_guild_invite, AcceptGuildInviteCallbackId,
+};
+pub use accept_party_invite_reducer::{
+    accept_party_invite, set_flags_for_accept_party_invite, AcceptPartyInviteCallbackId,
+};
+pub use accept_quest_reducer::{accept_quest, set_flags_for_accept_quest, AcceptQuestCallbackId};
+pub use accept_trade_reducer::{accept_trade, set_flags_for_accept_trade, AcceptTradeCallbackId};
+pub use active_effect_table::*;
+pub use active_effect_type::ActiveEffect;
+pub use add_friend_reducer::{add_friend, set_flags_for_add_friend, AddFriendCallbackId};
+pub use auction_expire_reducer::{
+    auction_expire, set_flags_for_auction_expire, AuctionExpireCallbackId,
+};
+pub use auction_expiry_timer_table::*;
+pub use auction_expiry_timer_type::AuctionExpiryTimer;
+pub use auction_listing_table::*;
+pub use auction_listing_type::AuctionListing;
+pub use bid_auction_reducer::{bid_auction, set_flags_for_bid_auction, BidAuctionCallbackId};
+pub use buy_from_vendor_reducer::{
+    buy_from_vendor, set_flags_for_buy_from_vendor, BuyFromVendorCallbackId,
+};
+pub use buyback_from_vendor_reducer::{
+    buyback_from_vendor, set_flags_for_buyback_from_vendor, BuybackFromVendorCallbackId,
+};
+pub use buyout_auction_reducer::{
+    buyout_auction, set_flags_for_buyout_auction, BuyoutAuctionCallbackId,
+};
+pub use cancel_auction_reducer::{
+    cancel_auction, set_flags_for_cancel_auction, CancelAuctionCallbackId,
+};
+pub use cancel_gather_reducer::{
+    cancel_gather, set_flags_for_cancel_gather, CancelGatherCallbackId,
+};
+pub use cancel_teleport_reducer::{
+    cancel_teleport, set_flags_for_cancel_teleport, CancelTeleportCallbackId,
+};
+pub use cancel_trade_reducer::{cancel_trade, set_flags_for_cancel_trade, CancelTradeCallbackId};
 pub use character_def_table::*;
 pub use character_def_type::CharacterDef;
 pub use character_pawn_table::*;
 pub use character_pawn_type::CharacterPawn;
+pub use choose_dialogue_option_reducer::{
+    choose_dialogue_option, set_flags_for_choose_dialogue_option, ChooseDialogueOptionCallbackId,
+};
 pub use class_table::*;
 pub use class_type::Class;
+pub use collision_circle_type::CollisionCircle;
+pub use collision_rect_type::CollisionRect;
+pub use collision_shape_kind_type::CollisionShapeKind;
+pub use collision_shape_table::*;
+pub use collision_shape_type::CollisionShape;
+pub use command_pet_reducer::{command_pet, set_flags_for_command_pet, CommandPetCallbackId};
+pub use complete_gather_reducer::{
+    complete_gather, set_flags_for_complete_gather, CompleteGatherCallbackId,
+};
+pub use complete_teleport_reducer::{
+    complete_teleport, set_flags_for_complete_teleport, CompleteTeleportCallbackId,
+};
+pub use craft_reducer::{craft, set_flags_for_craft, CraftCallbackId};
+pub use create_auction_reducer::{
+    create_auction, set_flags_for_create_auction, CreateAuctionCallbackId,
+};
 pub use create_character_input_type::CreateCharacterInput;
 pub use create_character_reducer::{
     create_character, set_flags_for_create_character, CreateCharacterCallbackId,
 };
+pub use create_guild_reducer::{create_guild, set_flags_for_create_guild, CreateGuildCallbackId};
+pub use currency_ledger_table::*;
+pub use currency_ledger_type::CurrencyLedger;
+pub use currency_reason_type::CurrencyReason;
+pub use currency_table::*;
+pub use currency_type::Currency;
+pub use decline_guild_invite_reducer::{
+    decline_guild_invite, set_flags_for_decline_guild_invite, DeclineGuildInviteCallbackId,
+};
+pub use decline_party_invite_reducer::{
+    decline_party_invite, set_flags_for_decline_party_invite, DeclinePartyInviteCallbackId,
+};
 pub use delete_character_reducer::{
     delete_character, set_flags_for_delete_character, DeleteCharacterCallbackId,
 };
+pub use delete_mail_reducer::{delete_mail, set_flags_for_delete_mail, DeleteMailCallbackId};
+pub use demote_guild_member_reducer::{
+    demote_guild_member, set_flags_for_demote_guild_member, DemoteGuildMemberCallbackId,
+};
+pub use dialogue_action_type::DialogueAction;
+pub use dialogue_choice_table::*;
+pub use dialogue_choice_type::DialogueChoice;
+pub use dialogue_condition_type::DialogueCondition;
+pub use dialogue_node_table::*;
+pub use dialogue_node_type::DialogueNode;
+pub use dialogue_session_table::*;
+pub use dialogue_session_type::DialogueSession;
+pub use disband_guild_reducer::{
+    disband_guild, set_flags_for_disband_guild, DisbandGuildCallbackId,
+};
+pub use discover_lore_reducer::{
+    discover_lore, set_flags_for_discover_lore, DiscoverLoreCallbackId,
+};
+pub use dismiss_summon_reducer::{
+    dismiss_summon, set_flags_for_dismiss_summon, DismissSummonCallbackId,
+};
+pub use dismount_reducer::{dismount, set_flags_for_dismount, DismountCallbackId};
+pub use dungeon_cleanup_reducer::{
+    dungeon_cleanup, set_flags_for_dungeon_cleanup, DungeonCleanupCallbackId,
+};
+pub use dungeon_cleanup_timer_table::*;
+pub use dungeon_cleanup_timer_type::DungeonCleanupTimer;
+pub use dungeon_def_table::*;
+pub use dungeon_def_type::DungeonDef;
+pub use dungeon_instance_table::*;
+pub use dungeon_instance_type::DungeonInstance;
+pub use dungeon_lockout_table::*;
+pub use dungeon_lockout_type::DungeonLockout;
+pub use dungeon_portal_table::*;
+pub use dungeon_portal_type::DungeonPortal;
+pub use dungeon_reset_reducer::{
+    dungeon_reset, set_flags_for_dungeon_reset, DungeonResetCallbackId,
+};
+pub use dungeon_reset_timer_table::*;
+pub use dungeon_reset_timer_type::DungeonResetTimer;
+pub use dungeon_spawn_table::*;
+pub use dungeon_spawn_type::DungeonSpawn;
+pub use effect_def_table::*;
+pub use effect_def_type::EffectDef;
+pub use effect_kind_type::EffectKind;
+pub use effect_tick_reducer::{effect_tick, set_flags_for_effect_tick, EffectTickCallbackId};
+pub use effect_tick_timer_table::*;
+pub use effect_tick_timer_type::EffectTickTimer;
+pub use end_dialogue_reducer::{end_dialogue, set_flags_for_end_dialogue, EndDialogueCallbackId};
+pub use enter_dungeon_reducer::{
+    enter_dungeon, set_flags_for_enter_dungeon, EnterDungeonCallbackId,
+};
+pub use enter_stealth_reducer::{
+    enter_stealth, set_flags_for_enter_stealth, EnterStealthCallbackId,
+};
 pub use enter_world_reducer::{enter_world, set_flags_for_enter_world, EnterWorldCallbackId};
 pub use entity_movement_table::*;
 pub use entity_movement_type::EntityMovement;
 pub use entity_table::*;
 pub use entity_type::Entity;
+pub use friend_notification_table::*;
+pub use friend_notification_type::FriendNotification;
+pub use friend_table::*;
+pub use friend_type::Friend;
+pub use gather_cast_table::*;
+pub use gather_cast_type::GatherCast;
+pub use gather_timer_table::*;
+pub use gather_timer_type::GatherTimer;
+pub use gather_veil_resource_reducer::{
+    gather_veil_resource, set_flags_for_gather_veil_resource, GatherVeilResourceCallbackId,
+};
+pub use guild_invite_table::*;
+pub use guild_invite_type::GuildInvite;
+pub use guild_member_table::*;
+pub use guild_member_type::GuildMember;
+pub use guild_message_table::*;
+pub use guild_message_type::GuildMessage;
+pub use guild_rank_table::*;
+pub use guild_rank_type::GuildRank;
+pub use guild_table::*;
+pub use guild_type::Guild;
 pub use health_table::*;
 pub use health_type::Health;
+pub use hearth_table::*;
+pub use hearth_type::Hearth;
 pub use identity_connected_reducer::{
     identity_connected, set_flags_for_identity_connected, IdentityConnectedCallbackId,
 };
 pub use identity_disconnected_reducer::{
     identity_disconnected, set_flags_for_identity_disconnected, IdentityDisconnectedCallbackId,
 };
+pub use ignore_player_reducer::{
+    ignore_player, set_flags_for_ignore_player, IgnorePlayerCallbackId,
+};
+pub use ignored_player_table::*;
+pub use ignored_player_type::IgnoredPlayer;
+pub use inventory_item_table::*;
+pub use inventory_item_type::InventoryItem;
+pub use invite_to_guild_reducer::{
+    invite_to_guild, set_flags_for_invite_to_guild, InviteToGuildCallbackId,
+};
+pub use invite_to_party_reducer::{
+    invite_to_party, set_flags_for_invite_to_party, InviteToPartyCallbackId,
+};
+pub use item_def_table::*;
+pub use item_def_type::ItemDef;
+pub use item_grant_type::ItemGrant;
+pub use item_type_table::*;
+pub use item_type_type::ItemType;
+pub use kick_from_guild_reducer::{
+    kick_from_guild, set_flags_for_kick_from_guild, KickFromGuildCallbackId,
+};
+pub use kick_from_party_reducer::{
+    kick_from_party, set_flags_for_kick_from_party, KickFromPartyCallbackId,
+};
+pub use leave_dungeon_reducer::{
+    leave_dungeon, set_flags_for_leave_dungeon, LeaveDungeonCallbackId,
+};
+pub use leave_guild_reducer::{leave_guild, set_flags_for_leave_guild, LeaveGuildCallbackId};
+pub use leave_party_reducer::{leave_party, set_flags_for_leave_party, LeavePartyCallbackId};
+pub use leave_stealth_reducer::{
+    leave_stealth, set_flags_for_leave_stealth, LeaveStealthCallbackId,
+};
 pub use leave_world_reducer::{leave_world, set_flags_for_leave_world, LeaveWorldCallbackId};
+pub use lock_trade_reducer::{lock_trade, set_flags_for_lock_trade, LockTradeCallbackId};
+pub use lore_collection_completed_table::*;
+pub use lore_collection_completed_type::LoreCollectionCompleted;
+pub use lore_collection_table::*;
+pub use lore_collection_type::LoreCollection;
+pub use lore_entry_table::*;
+pub use lore_entry_type::LoreEntry;
+pub use lore_kind_type::LoreKind;
+pub use lore_object_table::*;
+pub use lore_object_type::LoreObject;
+pub use lore_unlocked_table::*;
+pub use lore_unlocked_type::LoreUnlocked;
+pub use mail_attachment_type::MailAttachment;
+pub use mail_expire_reducer::{mail_expire, set_flags_for_mail_expire, MailExpireCallbackId};
+pub use mail_expiry_timer_table::*;
+pub use mail_expiry_timer_type::MailExpiryTimer;
+pub use mail_item_type::MailItem;
+pub use mail_table::*;
+pub use mail_type::Mail;
+pub use mailbox_spawn_table::*;
+pub use mailbox_spawn_type::MailboxSpawn;
+pub use mailbox_table::*;
+pub use mailbox_type::Mailbox;
 pub use mana_table::*;
 pub use mana_type::Mana;
+pub use map_kind_type::MapKind;
+pub use map_table::*;
+pub use map_type::Map;
+pub use mount_def_table::*;
+pub use mount_def_type::MountDef;
+pub use mount_reducer::{mount, set_flags_for_mount, MountCallbackId};
+pub use mounted_table::*;
+pub use mounted_type::Mounted;
 pub use move_intent_type::MoveIntent;
 pub use movement_tick_reducer::{
     movement_tick, set_flags_for_movement_tick, MovementTickCallbackId,
 };
 pub use movement_tick_timer_table::*;
 pub use movement_tick_timer_type::MovementTickTimer;
+pub use npc_def_table::*;
+pub use npc_def_type::NpcDef;
+pub use npc_respawn_reducer::{npc_respawn, set_flags_for_npc_respawn, NpcRespawnCallbackId};
+pub use npc_respawn_timer_table::*;
+pub use npc_respawn_timer_type::NpcRespawnTimer;
+pub use npc_spawn_table::*;
+pub use npc_spawn_type::NpcSpawn;
+pub use npc_table::*;
+pub use npc_threat_table::*;
+pub use npc_threat_type::NpcThreat;
+pub use npc_type::Npc;
+pub use objective_kind_type::ObjectiveKind;
+pub use offer_trade_currency_reducer::{
+    offer_trade_currency, set_flags_for_offer_trade_currency, OfferTradeCurrencyCallbackId,
+};
+pub use offer_trade_item_reducer::{
+    offer_trade_item, set_flags_for_offer_trade_item, OfferTradeItemCallbackId,
+};
+pub use owned_entity_table::*;
+pub use owned_entity_type::OwnedEntity;
+pub use party_invite_table::*;
+pub use party_invite_type::PartyInvite;
+pub use party_member_table::*;
+pub use party_member_type::PartyMember;
+pub use party_table::*;
+pub use party_type::Party;
+pub use pet_command_type::PetCommand;
 pub use player_table::*;
 pub use player_type::Player;
+pub use profession_skill_table::*;
+pub use profession_skill_type::ProfessionSkill;
+pub use profession_table::*;
+pub use profession_type::Profession;
+pub use promote_guild_member_reducer::{
+    promote_guild_member, set_flags_for_promote_guild_member, PromoteGuildMemberCallbackId,
+};
 pub use quat_type::Quat;
+pub use quest_condition_type::QuestCondition;
+pub use quest_def_table::*;
+pub use quest_def_type::QuestDef;
+pub use quest_location_check_reducer::{
+    quest_location_check, set_flags_for_quest_location_check, QuestLocationCheckCallbackId,
+};
+pub use quest_location_timer_table::*;
+pub use quest_location_timer_type::QuestLocationTimer;
+pub use quest_objective_table::*;
+pub use quest_objective_type::QuestObjective;
+pub use quest_progress_table::*;
+pub use quest_progress_type::QuestProgress;
+pub use quest_state_type::QuestState;
 pub use race_table::*;
 pub use race_type::Race;
+pub use reach_area_type::ReachArea;
+pub use recipe_ingredient_table::*;
+pub use recipe_ingredient_type::RecipeIngredient;
+pub use recipe_table::*;
+pub use recipe_type::Recipe;
+pub use remove_friend_reducer::{
+    remove_friend, set_flags_for_remove_friend, RemoveFriendCallbackId,
+};
 pub use request_move_reducer::{request_move, set_flags_for_request_move, RequestMoveCallbackId};
+pub use request_trade_reducer::{
+    request_trade, set_flags_for_request_trade, RequestTradeCallbackId,
+};
+pub use resource_node_respawn_reducer::{
+    resource_node_respawn, set_flags_for_resource_node_respawn, ResourceNodeRespawnCallbackId,
+};
+pub use resource_node_respawn_timer_table::*;
+pub use resource_node_respawn_timer_type::ResourceNodeRespawnTimer;
+pub use resource_node_spawn_table::*;
+pub use resource_node_spawn_type::ResourceNodeSpawn;
+pub use resource_node_table::*;
+pub use resource_node_type::ResourceNode;
+pub use retract_trade_item_reducer::{
+    retract_trade_item, set_flags_for_retract_trade_item, RetractTradeItemCallbackId,
+};
+pub use return_mail_reducer::{return_mail, set_flags_for_return_mail, ReturnMailCallbackId};
+pub use sell_to_vendor_reducer::{
+    sell_to_vendor, set_flags_for_sell_to_vendor, SellToVendorCallbackId,
+};
+pub use send_guild_message_reducer::{
+    send_guild_message, set_flags_for_send_guild_message, SendGuildMessageCallbackId,
+};
+pub use send_mail_reducer::{send_mail, set_flags_for_send_mail, SendMailCallbackId};
+pub use set_hearth_reducer::{set_flags_for_set_hearth, set_hearth, SetHearthCallbackId};
+pub use start_dialogue_reducer::{
+    set_flags_for_start_dialogue, start_dialogue, StartDialogueCallbackId,
+};
+pub use start_gather_reducer::{set_flags_for_start_gather, start_gather, StartGatherCallbackId};
+pub use stat_modifier_type::StatModifier;
+pub use stat_type::Stat;
+pub use stealth_detection_table::*;
+pub use stealth_detection_type::StealthDetection;
+pub use stealth_table::*;
+pub use stealth_tick_reducer::{set_flags_for_stealth_tick, stealth_tick, StealthTickCallbackId};
+pub use stealth_tick_timer_table::*;
+pub use stealth_tick_timer_type::StealthTickTimer;
+pub use stealth_type::Stealth;
+pub use summon_def_table::*;
+pub use summon_def_type::SummonDef;
+pub use summon_expire_reducer::{
+    set_flags_for_summon_expire, summon_expire, SummonExpireCallbackId,
+};
+pub use summon_expiry_timer_table::*;
+pub use summon_expiry_timer_type::SummonExpiryTimer;
+pub use summon_kind_type::SummonKind;
+pub use summon_reducer::{set_flags_for_summon, summon, SummonCallbackId};
+pub use summon_tick_reducer::{set_flags_for_summon_tick, summon_tick, SummonTickCallbackId};
+pub use summon_tick_timer_table::*;
+pub use summon_tick_timer_type::SummonTickTimer;
+pub use take_mail_reducer::{set_flags_for_take_mail, take_mail, TakeMailCallbackId};
+pub use talk_to_npc_reducer::{set_flags_for_talk_to_npc, talk_to_npc, TalkToNpcCallbackId};
+pub use taunt_reducer::{set_flags_for_taunt, taunt, TauntCallbackId};
+pub use teleport_cast_table::*;
+pub use teleport_cast_type::TeleportCast;
+pub use teleport_cooldown_table::*;
+pub use teleport_cooldown_type::TeleportCooldown;
+pub use teleport_destination_type::TeleportDestination;
+pub use teleport_reducer::{set_flags_for_teleport, teleport, TeleportCallbackId};
+pub use teleport_timer_table::*;
+pub use teleport_timer_type::TeleportTimer;
+pub use threat_tick_reducer::{set_flags_for_threat_tick, threat_tick, ThreatTickCallbackId};
+pub use threat_tick_timer_table::*;
+pub use threat_tick_timer_type::ThreatTickTimer;
+pub use trade_offer_table::*;
+pub use trade_offer_type::TradeOffer;
+pub use trade_participant_table::*;
+pub use trade_participant_type::TradeParticipant;
+pub use trade_range_check_reducer::{
+    set_flags_for_trade_range_check, trade_range_check, TradeRangeCheckCallbackId,
+};
+pub use trade_range_timer_table::*;
+pub use trade_range_timer_type::TradeRangeTimer;
+pub use trade_session_table::*;
+pub use trade_session_type::TradeSession;
+pub use trade_state_type::TradeState;
+pub use transfer_map_reducer::{set_flags_for_transfer_map, transfer_map, TransferMapCallbackId};
+pub use transfer_party_leader_reducer::{
+    set_flags_for_transfer_party_leader, transfer_party_leader, TransferPartyLeaderCallbackId,
+};
 pub use transform_table::*;
 pub use transform_type::Transform;
+pub use turn_in_quest_reducer::{
+    set_flags_for_turn_in_quest, turn_in_quest, TurnInQuestCallbackId,
+};
+pub use unignore_player_reducer::{
+    set_flags_for_unignore_player, unignore_player, UnignorePlayerCallbackId,
+};
 pub use vec_3_type::Vec3;
+pub use veil_chunk_table::*;
+pub use veil_chunk_type::VeilChunk;
+pub use veil_drift_reducer::{set_flags_for_veil_drift, veil_drift, VeilDriftCallbackId};
+pub use veil_drift_timer_table::*;
+pub use veil_drift_timer_type::VeilDriftTimer;
+pub use veil_effect_tick_reducer::{
+    set_flags_for_veil_effect_tick, veil_effect_tick, VeilEffectTickCallbackId,
+};
+pub use veil_effect_timer_table::*;
+pub use veil_effect_timer_type::VeilEffectTimer;
+pub use veil_exposure_table::*;
+pub use veil_exposure_type::VeilExposure;
+pub use veil_resource_table::*;
+pub use veil_resource_type::VeilResource;
+pub use veil_table::*;
+pub use veil_type::Veil;
+pub use vendor_buyback_table::*;
+pub use vendor_buyback_type::VendorBuyback;
+pub use vendor_item_table::*;
+pub use vendor_item_type::VendorItem;
+pub use vendor_restock_reducer::{
+    set_flags_for_vendor_restock, vendor_restock, VendorRestockCallbackId,
+};
+pub use vendor_restock_timer_table::*;
+pub use vendor_restock_timer_type::VendorRestockTimer;
+pub use vendor_table::*;
+pub use vendor_type::Vendor;
+pub use waypoint_discovered_table::*;
+pub use waypoint_discovered_type::WaypointDiscovered;
+pub use waypoint_discovery_reducer::{
+    set_flags_for_waypoint_discovery, waypoint_discovery, WaypointDiscoveryCallbackId,
+};
+pub use waypoint_discovery_timer_table::*;
+pub use waypoint_discovery_timer_type::WaypointDiscoveryTimer;
+pub use waypoint_table::*;
+pub use waypoint_type::Waypoint;
 pub use xp_progression_table::*;
 pub use xp_progression_type::XpProgression;
 pub use xp_table::*;
@@ -102,14 +778,269 @@ pub use xp_type::Xp;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
-    CreateCharacter { input: CreateCharacterInput },
-    DeleteCharacter { character_id: u32 },
-    EnterWorld { character_id: u32 },
+    AbandonQuest {
+        quest_def_id: u32,
+    },
+    AcceptGuildInvite {
+        invite_id: u32,
+    },
+    AcceptPartyInvite {
+        invite_id: u32,
+    },
+    AcceptQuest {
+        quest_def_id: u32,
+        npc_entity_id: u32,
+    },
+    AcceptTrade,
+    AddFriend {
+        character_name: String,
+    },
+    AuctionExpire {
+        timer: AuctionExpiryTimer,
+    },
+    BidAuction {
+        listing_id: u32,
+        amount: u64,
+    },
+    BuyFromVendor {
+        npc_entity_id: u32,
+        vendor_item_id: u32,
+        quantity: u16,
+    },
+    BuybackFromVendor {
+        npc_entity_id: u32,
+        buyback_id: u32,
+    },
+    BuyoutAuction {
+        listing_id: u32,
+    },
+    CancelAuction {
+        listing_id: u32,
+    },
+    CancelGather,
+    CancelTeleport,
+    CancelTrade,
+    ChooseDialogueOption {
+        choice_id: u32,
+    },
+    CommandPet {
+        command: PetCommand,
+    },
+    CompleteGather {
+        timer: GatherTimer,
+    },
+    CompleteTeleport {
+        timer: TeleportTimer,
+    },
+    Craft {
+        recipe_id: u32,
+        count: u16,
+    },
+    CreateAuction {
+        inventory_item_id: u32,
+        quantity: u16,
+        start_price: u64,
+        buyout: Option<u64>,
+        duration_hours: u8,
+    },
+    CreateCharacter {
+        input: CreateCharacterInput,
+    },
+    CreateGuild {
+        name: String,
+    },
+    DeclineGuildInvite {
+        invite_id: u32,
+    },
+    DeclinePartyInvite {
+        invite_id: u32,
+    },
+    DeleteCharacter {
+        character_id: u32,
+    },
+    DeleteMail {
+        mail_id: u32,
+    },
+    DemoteGuildMember {
+        character_id: u32,
+    },
+    DisbandGuild,
+    DiscoverLore {
+        entity_id: u32,
+    },
+    DismissSummon {
+        entity_id: u32,
+    },
+    Dismount,
+    DungeonCleanup {
+        _timer: DungeonCleanupTimer,
+    },
+    DungeonReset {
+        timer: DungeonResetTimer,
+    },
+    EffectTick {
+        _timer: EffectTickTimer,
+    },
+    EndDialogue,
+    EnterDungeon {
+        portal_entity_id: u32,
+    },
+    EnterStealth,
+    EnterWorld {
+        character_id: u32,
+    },
+    GatherVeilResource {
+        entity_id: u32,
+    },
     IdentityConnected,
     IdentityDisconnected,
+    IgnorePlayer {
+        character_name: String,
+    },
+    InviteToGuild {
+        character_name: String,
+    },
+    InviteToParty {
+        character_name: String,
+    },
+    KickFromGuild {
+        character_id: u32,
+    },
+    KickFromParty {
+        character_id: u32,
+    },
+    LeaveDungeon,
+    LeaveGuild,
+    LeaveParty,
+    LeaveStealth,
     LeaveWorld,
-    MovementTick { timer: MovementTickTimer },
-    RequestMove { move_intent: MoveIntent },
+    LockTrade,
+    MailExpire {
+        timer: MailExpiryTimer,
+    },
+    Mount {
+        mount_def_id: u32,
+    },
+    MovementTick {
+        timer: MovementTickTimer,
+    },
+    NpcRespawn {
+        timer: NpcRespawnTimer,
+    },
+    OfferTradeCurrency {
+        amount: u64,
+    },
+    OfferTradeItem {
+        inventory_item_id: u32,
+        quantity: u16,
+    },
+    PromoteGuildMember {
+        character_id: u32,
+    },
+    QuestLocationCheck {
+        _timer: QuestLocationTimer,
+    },
+    RemoveFriend {
+        friend_id: u32,
+    },
+    RequestMove {
+        move_intent: MoveIntent,
+    },
+    RequestTrade {
+        target_entity_id: u32,
+    },
+    ResourceNodeRespawn {
+        timer: ResourceNodeRespawnTimer,
+    },
+    RetractTradeItem {
+        inventory_item_id: u32,
+    },
+    ReturnMail {
+        mailbox_entity_id: u32,
+        mail_id: u32,
+    },
+    SellToVendor {
+        npc_entity_id: u32,
+        inventory_item_id: u32,
+        quantity: u16,
+    },
+    SendGuildMessage {
+        text: String,
+    },
+    SendMail {
+        mailbox_entity_id: u32,
+        recipient_name: String,
+        subject: String,
+        body: String,
+        attachments: Vec<MailAttachment>,
+        currency: u64,
+        cod: u64,
+    },
+    SetHearth {
+        waypoint_id: u32,
+    },
+    StartDialogue {
+        npc_entity_id: u32,
+    },
+    StartGather {
+        node_entity_id: u32,
+    },
+    StealthTick {
+        _timer: StealthTickTimer,
+    },
+    Summon {
+        summon_def_id: u32,
+    },
+    SummonExpire {
+        timer: SummonExpiryTimer,
+    },
+    SummonTick {
+        _timer: SummonTickTimer,
+    },
+    TakeMail {
+        mailbox_entity_id: u32,
+        mail_id: u32,
+    },
+    TalkToNpc {
+        npc_entity_id: u32,
+    },
+    Taunt {
+        npc_entity_id: u32,
+    },
+    Teleport {
+        destination: TeleportDestination,
+    },
+    ThreatTick {
+        _timer: ThreatTickTimer,
+    },
+    TradeRangeCheck {
+        _timer: TradeRangeTimer,
+    },
+    TransferMap {
+        map_id: u32,
+    },
+    TransferPartyLeader {
+        character_id: u32,
+    },
+    TurnInQuest {
+        quest_def_id: u32,
+        npc_entity_id: u32,
+    },
+    UnignorePlayer {
+        ignored_player_id: u32,
+    },
+    VeilDrift {
+        _timer: VeilDriftTimer,
+    },
+    VeilEffectTick {
+        _timer: VeilEffectTimer,
+    },
+    VendorRestock {
+        timer: VendorRestockTimer,
+    },
+    WaypointDiscovery {
+        _timer: WaypointDiscoveryTimer,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -119,14 +1050,97 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::AbandonQuest { .. } => "abandon_quest",
+            Reducer::AcceptGuildInvite { .. } => "accept_guild_invite",
+            Reducer::AcceptPartyInvite { .. } => "accept_party_invite",
+            Reducer::AcceptQuest { .. } => "accept_quest",
+            Reducer::AcceptTrade => "accept_trade",
+            Reducer::AddFriend { .. } => "add_friend",
+            Reducer::AuctionExpire { .. } => "auction_expire",
+            Reducer::BidAuction { .. } => "bid_auction",
+            Reducer::BuyFromVendor { .. } => "buy_from_vendor",
+            Reducer::BuybackFromVendor { .. } => "buyback_from_vendor",
+            Reducer::BuyoutAuction { .. } => "buyout_auction",
+            Reducer::CancelAuction { .. } => "cancel_auction",
+            Reducer::CancelGather => "cancel_gather",
+            Reducer::CancelTeleport => "cancel_teleport",
+            Reducer::CancelTrade => "cancel_trade",
+            Reducer::ChooseDialogueOption { .. } => "choose_dialogue_option",
+            Reducer::CommandPet { .. } => "command_pet",
+            Reducer::CompleteGather { .. } => "complete_gather",
+            Reducer::CompleteTeleport { .. } => "complete_teleport",
+            Reducer::Craft { .. } => "craft",
+            Reducer::CreateAuction { .. } => "create_auction",
             Reducer::CreateCharacter { .. } => "create_character",
+            Reducer::CreateGuild { .. } => "create_guild",
+            Reducer::DeclineGuildInvite { .. } => "decline_guild_invite",
+            Reducer::DeclinePartyInvite { .. } => "decline_party_invite",
             Reducer::DeleteCharacter { .. } => "delete_character",
+            Reducer::DeleteMail { .. } => "delete_mail",
+            Reducer::DemoteGuildMember { .. } => "demote_guild_member",
+            Reducer::DisbandGuild => "disband_guild",
+            Reducer::DiscoverLore { .. } => "discover_lore",
+            Reducer::DismissSummon { .. } => "dismiss_summon",
+            Reducer::Dismount => "dismount",
+            Reducer::DungeonCleanup { .. } => "dungeon_cleanup",
+            Reducer::DungeonReset { .. } => "dungeon_reset",
+            Reducer::EffectTick { .. } => "effect_tick",
+            Reducer::EndDialogue => "end_dialogue",
+            Reducer::EnterDungeon { .. } => "enter_dungeon",
+            Reducer::EnterStealth => "enter_stealth",
             Reducer::EnterWorld { .. } => "enter_world",
+            Reducer::GatherVeilResource { .. } => "gather_veil_resource",
             Reducer::IdentityConnected => "identity_connected",
             Reducer::IdentityDisconnected => "identity_disconnected",
+            Reducer::IgnorePlayer { .. } => "ignore_player",
+            Reducer::InviteToGuild { .. } => "invite_to_guild",
+            Reducer::InviteToParty { .. } => "invite_to_party",
+            Reducer::KickFromGuild { .. } => "kick_from_guild",
+            Reducer::KickFromParty { .. } => "kick_from_party",
+            Reducer::LeaveDungeon => "leave_dungeon",
+            Reducer::LeaveGuild => "leave_guild",
+            Reducer::LeaveParty => "leave_party",
+            Reducer::LeaveStealth => "leave_stealth",
             Reducer::LeaveWorld => "leave_world",
+            Reducer::LockTrade => "lock_trade",
+            Reducer::MailExpire { .. } => "mail_expire",
+            Reducer::Mount { .. } => "mount",
             Reducer::MovementTick { .. } => "movement_tick",
+            Reducer::NpcRespawn { .. } => "npc_respawn",
+            Reducer::OfferTradeCurrency { .. } => "offer_trade_currency",
+            Reducer::OfferTradeItem { .. } => "offer_trade_item",
+            Reducer::PromoteGuildMember { .. } => "promote_guild_member",
+            Reducer::QuestLocationCheck { .. } => "quest_location_check",
+            Reducer::RemoveFriend { .. } => "remove_friend",
             Reducer::RequestMove { .. } => "request_move",
+            Reducer::RequestTrade { .. } => "request_trade",
+            Reducer::ResourceNodeRespawn { .. } => "resource_node_respawn",
+            Reducer::RetractTradeItem { .. } => "retract_trade_item",
+            Reducer::ReturnMail { .. } => "return_mail",
+            Reducer::SellToVendor { .. } => "sell_to_vendor",
+            Reducer::SendGuildMessage { .. } => "send_guild_message",
+            Reducer::SendMail { .. } => "send_mail",
+            Reducer::SetHearth { .. } => "set_hearth",
+            Reducer::StartDialogue { .. } => "start_dialogue",
+            Reducer::StartGather { .. } => "start_gather",
+            Reducer::StealthTick { .. } => "stealth_tick",
+            Reducer::Summon { .. } => "summon",
+            Reducer::SummonExpire { .. } => "summon_expire",
+            Reducer::SummonTick { .. } => "summon_tick",
+            Reducer::TakeMail { .. } => "take_mail",
+            Reducer::TalkToNpc { .. } => "talk_to_npc",
+            Reducer::Taunt { .. } => "taunt",
+            Reducer::Teleport { .. } => "teleport",
+            Reducer::ThreatTick { .. } => "threat_tick",
+            Reducer::TradeRangeCheck { .. } => "trade_range_check",
+            Reducer::TransferMap { .. } => "transfer_map",
+            Reducer::TransferPartyLeader { .. } => "transfer_party_leader",
+            Reducer::TurnInQuest { .. } => "turn_in_quest",
+            Reducer::UnignorePlayer { .. } => "unignore_player",
+            Reducer::VeilDrift { .. } => "veil_drift",
+            Reducer::VeilEffectTick { .. } => "veil_effect_tick",
+            Reducer::VendorRestock { .. } => "vendor_restock",
+            Reducer::WaypointDiscovery { .. } => "waypoint_discovery",
         }
     }
 }
@@ -134,14 +1148,190 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "abandon_quest" => Ok(__sdk::parse_reducer_args::<
+                abandon_quest_reducer::AbandonQuestArgs,
+            >("abandon_quest", &value.args)?
+            .into()),
+            "accept_guild_invite" => Ok(__sdk::parse_reducer_args::<
+                accept_guild_invite_reducer::AcceptGuildInviteArgs,
+            >("accept_guild_invite", &value.args)?
+            .into()),
+            "accept_party_invite" => Ok(__sdk::parse_reducer_args::<
+                accept_party_invite_reducer::AcceptPartyInviteArgs,
+            >("accept_party_invite", &value.args)?
+            .into()),
+            "accept_quest" => Ok(
+                __sdk::parse_reducer_args::<accept_quest_reducer::AcceptQuestArgs>(
+                    "accept_quest",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "accept_trade" => Ok(
+                __sdk::parse_reducer_args::<accept_trade_reducer::AcceptTradeArgs>(
+                    "accept_trade",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "add_friend" => Ok(
+                __sdk::parse_reducer_args::<add_friend_reducer::AddFriendArgs>(
+                    "add_friend",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "auction_expire" => Ok(__sdk::parse_reducer_args::<
+                auction_expire_reducer::AuctionExpireArgs,
+            >("auction_expire", &value.args)?
+            .into()),
+            "bid_auction" => Ok(
+                __sdk::parse_reducer_args::<bid_auction_reducer::BidAuctionArgs>(
+                    "bid_auction",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "buy_from_vendor" => Ok(__sdk::parse_reducer_args::<
+                buy_from_vendor_reducer::BuyFromVendorArgs,
+            >("buy_from_vendor", &value.args)?
+            .into()),
+            "buyback_from_vendor" => Ok(__sdk::parse_reducer_args::<
+                buyback_from_vendor_reducer::BuybackFromVendorArgs,
+            >("buyback_from_vendor", &value.args)?
+            .into()),
+            "buyout_auction" => Ok(__sdk::parse_reducer_args::<
+                buyout_auction_reducer::BuyoutAuctionArgs,
+            >("buyout_auction", &value.args)?
+            .into()),
+            "cancel_auction" => Ok(__sdk::parse_reducer_args::<
+                cancel_auction_reducer::CancelAuctionArgs,
+            >("cancel_auction", &value.args)?
+            .into()),
+            "cancel_gather" => Ok(__sdk::parse_reducer_args::<
+                cancel_gather_reducer::CancelGatherArgs,
+            >("cancel_gather", &value.args)?
+            .into()),
+            "cancel_teleport" => Ok(__sdk::parse_reducer_args::<
+                cancel_teleport_reducer::CancelTeleportArgs,
+            >("cancel_teleport", &value.args)?
+            .into()),
+            "cancel_trade" => Ok(
+                __sdk::parse_reducer_args::<cancel_trade_reducer::CancelTradeArgs>(
+                    "cancel_trade",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "choose_dialogue_option" => Ok(__sdk::parse_reducer_args::<
+                choose_dialogue_option_reducer::ChooseDialogueOptionArgs,
+            >("choose_dialogue_option", &value.args)?
+            .into()),
+            "command_pet" => Ok(
+                __sdk::parse_reducer_args::<command_pet_reducer::CommandPetArgs>(
+                    "command_pet",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "complete_gather" => Ok(__sdk::parse_reducer_args::<
+                complete_gather_reducer::CompleteGatherArgs,
+            >("complete_gather", &value.args)?
+            .into()),
+            "complete_teleport" => Ok(__sdk::parse_reducer_args::<
+                complete_teleport_reducer::CompleteTeleportArgs,
+            >("complete_teleport", &value.args)?
+            .into()),
+            "craft" => Ok(__sdk::parse_reducer_args::<craft_reducer::CraftArgs>(
+                "craft",
+                &value.args,
+            )?
+            .into()),
+            "create_auction" => Ok(__sdk::parse_reducer_args::<
+                create_auction_reducer::CreateAuctionArgs,
+            >("create_auction", &value.args)?
+            .into()),
             "create_character" => Ok(__sdk::parse_reducer_args::<
                 create_character_reducer::CreateCharacterArgs,
             >("create_character", &value.args)?
             .into()),
+            "create_guild" => Ok(
+                __sdk::parse_reducer_args::<create_guild_reducer::CreateGuildArgs>(
+                    "create_guild",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "decline_guild_invite" => Ok(__sdk::parse_reducer_args::<
+                decline_guild_invite_reducer::DeclineGuildInviteArgs,
+            >("decline_guild_invite", &value.args)?
+            .into()),
+            "decline_party_invite" => Ok(__sdk::parse_reducer_args::<
+                decline_party_invite_reducer::DeclinePartyInviteArgs,
+            >("decline_party_invite", &value.args)?
+            .into()),
             "delete_character" => Ok(__sdk::parse_reducer_args::<
                 delete_character_reducer::DeleteCharacterArgs,
             >("delete_character", &value.args)?
             .into()),
+            "delete_mail" => Ok(
+                __sdk::parse_reducer_args::<delete_mail_reducer::DeleteMailArgs>(
+                    "delete_mail",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "demote_guild_member" => Ok(__sdk::parse_reducer_args::<
+                demote_guild_member_reducer::DemoteGuildMemberArgs,
+            >("demote_guild_member", &value.args)?
+            .into()),
+            "disband_guild" => Ok(__sdk::parse_reducer_args::<
+                disband_guild_reducer::DisbandGuildArgs,
+            >("disband_guild", &value.args)?
+            .into()),
+            "discover_lore" => Ok(__sdk::parse_reducer_args::<
+                discover_lore_reducer::DiscoverLoreArgs,
+            >("discover_lore", &value.args)?
+            .into()),
+            "dismiss_summon" => Ok(__sdk::parse_reducer_args::<
+                dismiss_summon_reducer::DismissSummonArgs,
+            >("dismiss_summon", &value.args)?
+            .into()),
+            "dismount" => Ok(__sdk::parse_reducer_args::<dismount_reducer::DismountArgs>(
+                "dismount",
+                &value.args,
+            )?
+            .into()),
+            "dungeon_cleanup" => Ok(__sdk::parse_reducer_args::<
+                dungeon_cleanup_reducer::DungeonCleanupArgs,
+            >("dungeon_cleanup", &value.args)?
+            .into()),
+            "dungeon_reset" => Ok(__sdk::parse_reducer_args::<
+                dungeon_reset_reducer::DungeonResetArgs,
+            >("dungeon_reset", &value.args)?
+            .into()),
+            "effect_tick" => Ok(
+                __sdk::parse_reducer_args::<effect_tick_reducer::EffectTickArgs>(
+                    "effect_tick",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "end_dialogue" => Ok(
+                __sdk::parse_reducer_args::<end_dialogue_reducer::EndDialogueArgs>(
+                    "end_dialogue",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "enter_dungeon" => Ok(__sdk::parse_reducer_args::<
+                enter_dungeon_reducer::EnterDungeonArgs,
+            >("enter_dungeon", &value.args)?
+            .into()),
+            "enter_stealth" => Ok(__sdk::parse_reducer_args::<
+                enter_stealth_reducer::EnterStealthArgs,
+            >("enter_stealth", &value.args)?
+            .into()),
             "enter_world" => Ok(
                 __sdk::parse_reducer_args::<enter_world_reducer::EnterWorldArgs>(
                     "enter_world",
@@ -149,6 +1339,10 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "gather_veil_resource" => Ok(__sdk::parse_reducer_args::<
+                gather_veil_resource_reducer::GatherVeilResourceArgs,
+            >("gather_veil_resource", &value.args)?
+            .into()),
             "identity_connected" => Ok(__sdk::parse_reducer_args::<
                 identity_connected_reducer::IdentityConnectedArgs,
             >("identity_connected", &value.args)?
@@ -157,6 +1351,48 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 identity_disconnected_reducer::IdentityDisconnectedArgs,
             >("identity_disconnected", &value.args)?
             .into()),
+            "ignore_player" => Ok(__sdk::parse_reducer_args::<
+                ignore_player_reducer::IgnorePlayerArgs,
+            >("ignore_player", &value.args)?
+            .into()),
+            "invite_to_guild" => Ok(__sdk::parse_reducer_args::<
+                invite_to_guild_reducer::InviteToGuildArgs,
+            >("invite_to_guild", &value.args)?
+            .into()),
+            "invite_to_party" => Ok(__sdk::parse_reducer_args::<
+                invite_to_party_reducer::InviteToPartyArgs,
+            >("invite_to_party", &value.args)?
+            .into()),
+            "kick_from_guild" => Ok(__sdk::parse_reducer_args::<
+                kick_from_guild_reducer::KickFromGuildArgs,
+            >("kick_from_guild", &value.args)?
+            .into()),
+            "kick_from_party" => Ok(__sdk::parse_reducer_args::<
+                kick_from_party_reducer::KickFromPartyArgs,
+            >("kick_from_party", &value.args)?
+            .into()),
+            "leave_dungeon" => Ok(__sdk::parse_reducer_args::<
+                leave_dungeon_reducer::LeaveDungeonArgs,
+            >("leave_dungeon", &value.args)?
+            .into()),
+            "leave_guild" => Ok(
+                __sdk::parse_reducer_args::<leave_guild_reducer::LeaveGuildArgs>(
+                    "leave_guild",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "leave_party" => Ok(
+                __sdk::parse_reducer_args::<leave_party_reducer::LeavePartyArgs>(
+                    "leave_party",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "leave_stealth" => Ok(__sdk::parse_reducer_args::<
+                leave_stealth_reducer::LeaveStealthArgs,
+            >("leave_stealth", &value.args)?
+            .into()),
             "leave_world" => Ok(
                 __sdk::parse_reducer_args::<leave_world_reducer::LeaveWorldArgs>(
                     "leave_world",
@@ -164,10 +1400,56 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "lock_trade" => Ok(
+                __sdk::parse_reducer_args::<lock_trade_reducer::LockTradeArgs>(
+                    "lock_trade",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "mail_expire" => Ok(
+                __sdk::parse_reducer_args::<mail_expire_reducer::MailExpireArgs>(
+                    "mail_expire",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "mount" => Ok(__sdk::parse_reducer_args::<mount_reducer::MountArgs>(
+                "mount",
+                &value.args,
+            )?
+            .into()),
             "movement_tick" => Ok(__sdk::parse_reducer_args::<
                 movement_tick_reducer::MovementTickArgs,
             >("movement_tick", &value.args)?
             .into()),
+            "npc_respawn" => Ok(
+                __sdk::parse_reducer_args::<npc_respawn_reducer::NpcRespawnArgs>(
+                    "npc_respawn",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "offer_trade_currency" => Ok(__sdk::parse_reducer_args::<
+                offer_trade_currency_reducer::OfferTradeCurrencyArgs,
+            >("offer_trade_currency", &value.args)?
+            .into()),
+            "offer_trade_item" => Ok(__sdk::parse_reducer_args::<
+                offer_trade_item_reducer::OfferTradeItemArgs,
+            >("offer_trade_item", &value.args)?
+            .into()),
+            "promote_guild_member" => Ok(__sdk::parse_reducer_args::<
+                promote_guild_member_reducer::PromoteGuildMemberArgs,
+            >("promote_guild_member", &value.args)?
+            .into()),
+            "quest_location_check" => Ok(__sdk::parse_reducer_args::<
+                quest_location_check_reducer::QuestLocationCheckArgs,
+            >("quest_location_check", &value.args)?
+            .into()),
+            "remove_friend" => Ok(__sdk::parse_reducer_args::<
+                remove_friend_reducer::RemoveFriendArgs,
+            >("remove_friend", &value.args)?
+            .into()),
             "request_move" => Ok(
                 __sdk::parse_reducer_args::<request_move_reducer::RequestMoveArgs>(
                     "request_move",
@@ -175,6 +1457,154 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "request_trade" => Ok(__sdk::parse_reducer_args::<
+                request_trade_reducer::RequestTradeArgs,
+            >("request_trade", &value.args)?
+            .into()),
+            "resource_node_respawn" => Ok(__sdk::parse_reducer_args::<
+                resource_node_respawn_reducer::ResourceNodeRespawnArgs,
+            >("resource_node_respawn", &value.args)?
+            .into()),
+            "retract_trade_item" => Ok(__sdk::parse_reducer_args::<
+                retract_trade_item_reducer::RetractTradeItemArgs,
+            >("retract_trade_item", &value.args)?
+            .into()),
+            "return_mail" => Ok(
+                __sdk::parse_reducer_args::<return_mail_reducer::ReturnMailArgs>(
+                    "return_mail",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "sell_to_vendor" => Ok(__sdk::parse_reducer_args::<
+                sell_to_vendor_reducer::SellToVendorArgs,
+            >("sell_to_vendor", &value.args)?
+            .into()),
+            "send_guild_message" => Ok(__sdk::parse_reducer_args::<
+                send_guild_message_reducer::SendGuildMessageArgs,
+            >("send_guild_message", &value.args)?
+            .into()),
+            "send_mail" => Ok(
+                __sdk::parse_reducer_args::<send_mail_reducer::SendMailArgs>(
+                    "send_mail",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "set_hearth" => Ok(
+                __sdk::parse_reducer_args::<set_hearth_reducer::SetHearthArgs>(
+                    "set_hearth",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "start_dialogue" => Ok(__sdk::parse_reducer_args::<
+                start_dialogue_reducer::StartDialogueArgs,
+            >("start_dialogue", &value.args)?
+            .into()),
+            "start_gather" => Ok(
+                __sdk::parse_reducer_args::<start_gather_reducer::StartGatherArgs>(
+                    "start_gather",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "stealth_tick" => Ok(
+                __sdk::parse_reducer_args::<stealth_tick_reducer::StealthTickArgs>(
+                    "stealth_tick",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "summon" => Ok(__sdk::parse_reducer_args::<summon_reducer::SummonArgs>(
+                "summon",
+                &value.args,
+            )?
+            .into()),
+            "summon_expire" => Ok(__sdk::parse_reducer_args::<
+                summon_expire_reducer::SummonExpireArgs,
+            >("summon_expire", &value.args)?
+            .into()),
+            "summon_tick" => Ok(
+                __sdk::parse_reducer_args::<summon_tick_reducer::SummonTickArgs>(
+                    "summon_tick",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "take_mail" => Ok(
+                __sdk::parse_reducer_args::<take_mail_reducer::TakeMailArgs>(
+                    "take_mail",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "talk_to_npc" => Ok(
+                __sdk::parse_reducer_args::<talk_to_npc_reducer::TalkToNpcArgs>(
+                    "talk_to_npc",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "taunt" => Ok(__sdk::parse_reducer_args::<taunt_reducer::TauntArgs>(
+                "taunt",
+                &value.args,
+            )?
+            .into()),
+            "teleport" => Ok(__sdk::parse_reducer_args::<teleport_reducer::TeleportArgs>(
+                "teleport",
+                &value.args,
+            )?
+            .into()),
+            "threat_tick" => Ok(
+                __sdk::parse_reducer_args::<threat_tick_reducer::ThreatTickArgs>(
+                    "threat_tick",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "trade_range_check" => Ok(__sdk::parse_reducer_args::<
+                trade_range_check_reducer::TradeRangeCheckArgs,
+            >("trade_range_check", &value.args)?
+            .into()),
+            "transfer_map" => Ok(
+                __sdk::parse_reducer_args::<transfer_map_reducer::TransferMapArgs>(
+                    "transfer_map",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "transfer_party_leader" => Ok(__sdk::parse_reducer_args::<
+                transfer_party_leader_reducer::TransferPartyLeaderArgs,
+            >("transfer_party_leader", &value.args)?
+            .into()),
+            "turn_in_quest" => Ok(__sdk::parse_reducer_args::<
+                turn_in_quest_reducer::TurnInQuestArgs,
+            >("turn_in_quest", &value.args)?
+            .into()),
+            "unignore_player" => Ok(__sdk::parse_reducer_args::<
+                unignore_player_reducer::UnignorePlayerArgs,
+            >("unignore_player", &value.args)?
+            .into()),
+            "veil_drift" => Ok(
+                __sdk::parse_reducer_args::<veil_drift_reducer::VeilDriftArgs>(
+                    "veil_drift",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "veil_effect_tick" => Ok(__sdk::parse_reducer_args::<
+                veil_effect_tick_reducer::VeilEffectTickArgs,
+            >("veil_effect_tick", &value.args)?
+            .into()),
+            "vendor_restock" => Ok(__sdk::parse_reducer_args::<
+                vendor_restock_reducer::VendorRestockArgs,
+            >("vendor_restock", &value.args)?
+            .into()),
+            "waypoint_discovery" => Ok(__sdk::parse_reducer_args::<
+                waypoint_discovery_reducer::WaypointDiscoveryArgs,
+            >("waypoint_discovery", &value.args)?
+            .into()),
             unknown => {
                 Err(
                     __sdk::InternalError::unknown_name("reducer", unknown, "ReducerCallInfo")
@@ -189,17 +1619,108 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    active_effect: __sdk::TableUpdate<ActiveEffect>,
+    auction_expiry_timer: __sdk::TableUpdate<AuctionExpiryTimer>,
+    auction_listing: __sdk::TableUpdate<AuctionListing>,
     character_def: __sdk::TableUpdate<CharacterDef>,
     character_pawn: __sdk::TableUpdate<CharacterPawn>,
     class: __sdk::TableUpdate<Class>,
+    collision_shape: __sdk::TableUpdate<CollisionShape>,
+    currency: __sdk::TableUpdate<Currency>,
+    currency_ledger: __sdk::TableUpdate<CurrencyLedger>,
+    dialogue_choice: __sdk::TableUpdate<DialogueChoice>,
+    dialogue_node: __sdk::TableUpdate<DialogueNode>,
+    dialogue_session: __sdk::TableUpdate<DialogueSession>,
+    dungeon_cleanup_timer: __sdk::TableUpdate<DungeonCleanupTimer>,
+    dungeon_def: __sdk::TableUpdate<DungeonDef>,
+    dungeon_instance: __sdk::TableUpdate<DungeonInstance>,
+    dungeon_lockout: __sdk::TableUpdate<DungeonLockout>,
+    dungeon_portal: __sdk::TableUpdate<DungeonPortal>,
+    dungeon_reset_timer: __sdk::TableUpdate<DungeonResetTimer>,
+    dungeon_spawn: __sdk::TableUpdate<DungeonSpawn>,
+    effect_def: __sdk::TableUpdate<EffectDef>,
+    effect_tick_timer: __sdk::TableUpdate<EffectTickTimer>,
     entity: __sdk::TableUpdate<Entity>,
     entity_movement: __sdk::TableUpdate<EntityMovement>,
+    friend: __sdk::TableUpdate<Friend>,
+    friend_notification: __sdk::TableUpdate<FriendNotification>,
+    gather_cast: __sdk::TableUpdate<GatherCast>,
+    gather_timer: __sdk::TableUpdate<GatherTimer>,
+    guild: __sdk::TableUpdate<Guild>,
+    guild_invite: __sdk::TableUpdate<GuildInvite>,
+    guild_member: __sdk::TableUpdate<GuildMember>,
+    guild_message: __sdk::TableUpdate<GuildMessage>,
+    guild_rank: __sdk::TableUpdate<GuildRank>,
     health: __sdk::TableUpdate<Health>,
+    hearth: __sdk::TableUpdate<Hearth>,
+    ignored_player: __sdk::TableUpdate<IgnoredPlayer>,
+    inventory_item: __sdk::TableUpdate<InventoryItem>,
+    item_def: __sdk::TableUpdate<ItemDef>,
+    item_type: __sdk::TableUpdate<ItemType>,
+    lore_collection: __sdk::TableUpdate<LoreCollection>,
+    lore_collection_completed: __sdk::TableUpdate<LoreCollectionCompleted>,
+    lore_entry: __sdk::TableUpdate<LoreEntry>,
+    lore_object: __sdk::TableUpdate<LoreObject>,
+    lore_unlocked: __sdk::TableUpdate<LoreUnlocked>,
+    mail: __sdk::TableUpdate<Mail>,
+    mail_expiry_timer: __sdk::TableUpdate<MailExpiryTimer>,
+    mailbox: __sdk::TableUpdate<Mailbox>,
+    mailbox_spawn: __sdk::TableUpdate<MailboxSpawn>,
     mana: __sdk::TableUpdate<Mana>,
+    map: __sdk::TableUpdate<Map>,
+    mount_def: __sdk::TableUpdate<MountDef>,
+    mounted: __sdk::TableUpdate<Mounted>,
     movement_tick_timer: __sdk::TableUpdate<MovementTickTimer>,
+    npc: __sdk::TableUpdate<Npc>,
+    npc_def: __sdk::TableUpdate<NpcDef>,
+    npc_respawn_timer: __sdk::TableUpdate<NpcRespawnTimer>,
+    npc_spawn: __sdk::TableUpdate<NpcSpawn>,
+    npc_threat: __sdk::TableUpdate<NpcThreat>,
+    owned_entity: __sdk::TableUpdate<OwnedEntity>,
+    party: __sdk::TableUpdate<Party>,
+    party_invite: __sdk::TableUpdate<PartyInvite>,
+    party_member: __sdk::TableUpdate<PartyMember>,
     player: __sdk::TableUpdate<Player>,
+    profession: __sdk::TableUpdate<Profession>,
+    profession_skill: __sdk::TableUpdate<ProfessionSkill>,
+    quest_def: __sdk::TableUpdate<QuestDef>,
+    quest_location_timer: __sdk::TableUpdate<QuestLocationTimer>,
+    quest_objective: __sdk::TableUpdate<QuestObjective>,
+    quest_progress: __sdk::TableUpdate<QuestProgress>,
     race: __sdk::TableUpdate<Race>,
+    recipe: __sdk::TableUpdate<Recipe>,
+    recipe_ingredient: __sdk::TableUpdate<RecipeIngredient>,
+    resource_node: __sdk::TableUpdate<ResourceNode>,
+    resource_node_respawn_timer: __sdk::TableUpdate<ResourceNodeRespawnTimer>,
+    resource_node_spawn: __sdk::TableUpdate<ResourceNodeSpawn>,
+    stealth: __sdk::TableUpdate<Stealth>,
+    stealth_detection: __sdk::TableUpdate<StealthDetection>,
+    stealth_tick_timer: __sdk::TableUpdate<StealthTickTimer>,
+    summon_def: __sdk::TableUpdate<SummonDef>,
+    summon_expiry_timer: __sdk::TableUpdate<SummonExpiryTimer>,
+    summon_tick_timer: __sdk::TableUpdate<SummonTickTimer>,
+    teleport_cast: __sdk::TableUpdate<TeleportCast>,
+    teleport_cooldown: __sdk::TableUpdate<TeleportCooldown>,
+    teleport_timer: __sdk::TableUpdate<TeleportTimer>,
+    threat_tick_timer: __sdk::TableUpdate<ThreatTickTimer>,
+    trade_offer: __sdk::TableUpdate<TradeOffer>,
+    trade_participant: __sdk::TableUpdate<TradeParticipant>,
+    trade_range_timer: __sdk::TableUpdate<TradeRangeTimer>,
+    trade_session: __sdk::TableUpdate<TradeSession>,
     transform: __sdk::TableUpdate<Transform>,
+    veil: __sdk::TableUpdate<Veil>,
+    veil_chunk: __sdk::TableUpdate<VeilChunk>,
+    veil_drift_timer: __sdk::TableUpdate<VeilDriftTimer>,
+    veil_effect_timer: __sdk::TableUpdate<VeilEffectTimer>,
+    veil_exposure: __sdk::TableUpdate<VeilExposure>,
+    veil_resource: __sdk::TableUpdate<VeilResource>,
+    vendor: __sdk::TableUpdate<Vendor>,
+    vendor_buyback: __sdk::TableUpdate<VendorBuyback>,
+    vendor_item: __sdk::TableUpdate<VendorItem>,
+    vendor_restock_timer: __sdk::TableUpdate<VendorRestockTimer>,
+    waypoint: __sdk::TableUpdate<Waypoint>,
+    waypoint_discovered: __sdk::TableUpdate<WaypointDiscovered>,
+    waypoint_discovery_timer: __sdk::TableUpdate<WaypointDiscoveryTimer>,
     xp: __sdk::TableUpdate<Xp>,
     xp_progression: __sdk::TableUpdate<XpProgression>,
 }
@@ -210,6 +1731,15 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "active_effect" => db_update
+                    .active_effect
+                    .append(active_effect_table::parse_table_update(table_update)?),
+                "auction_expiry_timer" => db_update.auction_expiry_timer.append(
+                    auction_expiry_timer_table::parse_table_update(table_update)?,
+                ),
+                "auction_listing" => db_update
+                    .auction_listing
+                    .append(auction_listing_table::parse_table_update(table_update)?),
                 "character_def" => db_update
                     .character_def
                     .append(character_def_table::parse_table_update(table_update)?),
@@ -219,30 +1749,294 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
                 "class" => db_update
                     .class
                     .append(class_table::parse_table_update(table_update)?),
+                "collision_shape" => db_update
+                    .collision_shape
+                    .append(collision_shape_table::parse_table_update(table_update)?),
+                "currency" => db_update
+                    .currency
+                    .append(currency_table::parse_table_update(table_update)?),
+                "currency_ledger" => db_update
+                    .currency_ledger
+                    .append(currency_ledger_table::parse_table_update(table_update)?),
+                "dialogue_choice" => db_update
+                    .dialogue_choice
+                    .append(dialogue_choice_table::parse_table_update(table_update)?),
+                "dialogue_node" => db_update
+                    .dialogue_node
+                    .append(dialogue_node_table::parse_table_update(table_update)?),
+                "dialogue_session" => db_update
+                    .dialogue_session
+                    .append(dialogue_session_table::parse_table_update(table_update)?),
+                "dungeon_cleanup_timer" => db_update.dungeon_cleanup_timer.append(
+                    dungeon_cleanup_timer_table::parse_table_update(table_update)?,
+                ),
+                "dungeon_def" => db_update
+                    .dungeon_def
+                    .append(dungeon_def_table::parse_table_update(table_update)?),
+                "dungeon_instance" => db_update
+                    .dungeon_instance
+                    .append(dungeon_instance_table::parse_table_update(table_update)?),
+                "dungeon_lockout" => db_update
+                    .dungeon_lockout
+                    .append(dungeon_lockout_table::parse_table_update(table_update)?),
+                "dungeon_portal" => db_update
+                    .dungeon_portal
+                    .append(dungeon_portal_table::parse_table_update(table_update)?),
+                "dungeon_reset_timer" => db_update
+                    .dungeon_reset_timer
+                    .append(dungeon_reset_timer_table::parse_table_update(table_update)?),
+                "dungeon_spawn" => db_update
+                    .dungeon_spawn
+                    .append(dungeon_spawn_table::parse_table_update(table_update)?),
+                "effect_def" => db_update
+                    .effect_def
+                    .append(effect_def_table::parse_table_update(table_update)?),
+                "effect_tick_timer" => db_update
+                    .effect_tick_timer
+                    .append(effect_tick_timer_table::parse_table_update(table_update)?),
                 "entity" => db_update
                     .entity
                     .append(entity_table::parse_table_update(table_update)?),
                 "entity_movement" => db_update
                     .entity_movement
                     .append(entity_movement_table::parse_table_update(table_update)?),
+                "friend" => db_update
+                    .friend
+                    .append(friend_table::parse_table_update(table_update)?),
+                "friend_notification" => db_update
+                    .friend_notification
+                    .append(friend_notification_table::parse_table_update(table_update)?),
+                "gather_cast" => db_update
+                    .gather_cast
+                    .append(gather_cast_table::parse_table_update(table_update)?),
+                "gather_timer" => db_update
+                    .gather_timer
+                    .append(gather_timer_table::parse_table_update(table_update)?),
+                "guild" => db_update
+                    .guild
+                    .append(guild_table::parse_table_update(table_update)?),
+                "guild_invite" => db_update
+                    .guild_invite
+                    .append(guild_invite_table::parse_table_update(table_update)?),
+                "guild_member" => db_update
+                    .guild_member
+                    .append(guild_member_table::parse_table_update(table_update)?),
+                "guild_message" => db_update
+                    .guild_message
+                    .append(guild_message_table::parse_table_update(table_update)?),
+                "guild_rank" => db_update
+                    .guild_rank
+                    .append(guild_rank_table::parse_table_update(table_update)?),
                 "health" => db_update
                     .health
                     .append(health_table::parse_table_update(table_update)?),
+                "hearth" => db_update
+                    .hearth
+                    .append(hearth_table::parse_table_update(table_update)?),
+                "ignored_player" => db_update
+                    .ignored_player
+                    .append(ignored_player_table::parse_table_update(table_update)?),
+                "inventory_item" => db_update
+                    .inventory_item
+                    .append(inventory_item_table::parse_table_update(table_update)?),
+                "item_def" => db_update
+                    .item_def
+                    .append(item_def_table::parse_table_update(table_update)?),
+                "item_type" => db_update
+                    .item_type
+                    .append(item_type_table::parse_table_update(table_update)?),
+                "lore_collection" => db_update
+                    .lore_collection
+                    .append(lore_collection_table::parse_table_update(table_update)?),
+                "lore_collection_completed" => db_update.lore_collection_completed.append(
+                    lore_collection_completed_table::parse_table_update(table_update)?,
+                ),
+                "lore_entry" => db_update
+                    .lore_entry
+                    .append(lore_entry_table::parse_table_update(table_update)?),
+                "lore_object" => db_update
+                    .lore_object
+                    .append(lore_object_table::parse_table_update(table_update)?),
+                "lore_unlocked" => db_update
+                    .lore_unlocked
+                    .append(lore_unlocked_table::parse_table_update(table_update)?),
+                "mail" => db_update
+                    .mail
+                    .append(mail_table::parse_table_update(table_update)?),
+                "mail_expiry_timer" => db_update
+                    .mail_expiry_timer
+                    .append(mail_expiry_timer_table::parse_table_update(table_update)?),
+                "mailbox" => db_update
+                    .mailbox
+                    .append(mailbox_table::parse_table_update(table_update)?),
+                "mailbox_spawn" => db_update
+                    .mailbox_spawn
+                    .append(mailbox_spawn_table::parse_table_update(table_update)?),
                 "mana" => db_update
                     .mana
                     .append(mana_table::parse_table_update(table_update)?),
+                "map" => db_update
+                    .map
+                    .append(map_table::parse_table_update(table_update)?),
+                "mount_def" => db_update
+                    .mount_def
+                    .append(mount_def_table::parse_table_update(table_update)?),
+                "mounted" => db_update
+                    .mounted
+                    .append(mounted_table::parse_table_update(table_update)?),
                 "movement_tick_timer" => db_update
                     .movement_tick_timer
                     .append(movement_tick_timer_table::parse_table_update(table_update)?),
+                "npc" => db_update
+                    .npc
+                    .append(npc_table::parse_table_update(table_update)?),
+                "npc_def" => db_update
+                    .npc_def
+                    .append(npc_def_table::parse_table_update(table_update)?),
+                "npc_respawn_timer" => db_update
+                    .npc_respawn_timer
+                    .append(npc_respawn_timer_table::parse_table_update(table_update)?),
+                "npc_spawn" => db_update
+                    .npc_spawn
+                    .append(npc_spawn_table::parse_table_update(table_update)?),
+                "npc_threat" => db_update
+                    .npc_threat
+                    .append(npc_threat_table::parse_table_update(table_update)?),
+                "owned_entity" => db_update
+                    .owned_entity
+                    .append(owned_entity_table::parse_table_update(table_update)?),
+                "party" => db_update
+                    .party
+                    .append(party_table::parse_table_update(table_update)?),
+                "party_invite" => db_update
+                    .party_invite
+                    .append(party_invite_table::parse_table_update(table_update)?),
+                "party_member" => db_update
+                    .party_member
+                    .append(party_member_table::parse_table_update(table_update)?),
                 "player" => db_update
                     .player
                     .append(player_table::parse_table_update(table_update)?),
+                "profession" => db_update
+                    .profession
+                    .append(profession_table::parse_table_update(table_update)?),
+                "profession_skill" => db_update
+                    .profession_skill
+                    .append(profession_skill_table::parse_table_update(table_update)?),
+                "quest_def" => db_update
+                    .quest_def
+                    .append(quest_def_table::parse_table_update(table_update)?),
+                "quest_location_timer" => db_update.quest_location_timer.append(
+                    quest_location_timer_table::parse_table_update(table_update)?,
+                ),
+                "quest_objective" => db_update
+                    .quest_objective
+                    .append(quest_objective_table::parse_table_update(table_update)?),
+                "quest_progress" => db_update
+                    .quest_progress
+                    .append(quest_progress_table::parse_table_update(table_update)?),
                 "race" => db_update
                     .race
                     .append(race_table::parse_table_update(table_update)?),
+                "recipe" => db_update
+                    .recipe
+                    .append(recipe_table::parse_table_update(table_update)?),
+                "recipe_ingredient" => db_update
+                    .recipe_ingredient
+                    .append(recipe_ingredient_table::parse_table_update(table_update)?),
+                "resource_node" => db_update
+                    .resource_node
+                    .append(resource_node_table::parse_table_update(table_update)?),
+                "resource_node_respawn_timer" => db_update.resource_node_respawn_timer.append(
+                    resource_node_respawn_timer_table::parse_table_update(table_update)?,
+                ),
+                "resource_node_spawn" => db_update
+                    .resource_node_spawn
+                    .append(resource_node_spawn_table::parse_table_update(table_update)?),
+                "stealth" => db_update
+                    .stealth
+                    .append(stealth_table::parse_table_update(table_update)?),
+                "stealth_detection" => db_update
+                    .stealth_detection
+                    .append(stealth_detection_table::parse_table_update(table_update)?),
+                "stealth_tick_timer" => db_update
+                    .stealth_tick_timer
+                    .append(stealth_tick_timer_table::parse_table_update(table_update)?),
+                "summon_def" => db_update
+                    .summon_def
+                    .append(summon_def_table::parse_table_update(table_update)?),
+                "summon_expiry_timer" => db_update
+                    .summon_expiry_timer
+                    .append(summon_expiry_timer_table::parse_table_update(table_update)?),
+                "summon_tick_timer" => db_update
+                    .summon_tick_timer
+                    .append(summon_tick_timer_table::parse_table_update(table_update)?),
+                "teleport_cast" => db_update
+                    .teleport_cast
+                    .append(teleport_cast_table::parse_table_update(table_update)?),
+                "teleport_cooldown" => db_update
+                    .teleport_cooldown
+                    .append(teleport_cooldown_table::parse_table_update(table_update)?),
+                "teleport_timer" => db_update
+                    .teleport_timer
+                    .append(teleport_timer_table::parse_table_update(table_update)?),
+                "threat_tick_timer" => db_update
+                    .threat_tick_timer
+                    .append(threat_tick_timer_table::parse_table_update(table_update)?),
+                "trade_offer" => db_update
+                    .trade_offer
+                    .append(trade_offer_table::parse_table_update(table_update)?),
+                "trade_participant" => db_update
+                    .trade_participant
+                    .append(trade_participant_table::parse_table_update(table_update)?),
+                "trade_range_timer" => db_update
+                    .trade_range_timer
+                    .append(trade_range_timer_table::parse_table_update(table_update)?),
+                "trade_session" => db_update
+                    .trade_session
+                    .append(trade_session_table::parse_table_update(table_update)?),
                 "transform" => db_update
                     .transform
                     .append(transform_table::parse_table_update(table_update)?),
+                "veil" => db_update
+                    .veil
+                    .append(veil_table::parse_table_update(table_update)?),
+                "veil_chunk" => db_update
+                    .veil_chunk
+                    .append(veil_chunk_table::parse_table_update(table_update)?),
+                "veil_drift_timer" => db_update
+                    .veil_drift_timer
+                    .append(veil_drift_timer_table::parse_table_update(table_update)?),
+                "veil_effect_timer" => db_update
+                    .veil_effect_timer
+                    .append(veil_effect_timer_table::parse_table_update(table_update)?),
+                "veil_exposure" => db_update
+                    .veil_exposure
+                    .append(veil_exposure_table::parse_table_update(table_update)?),
+                "veil_resource" => db_update
+                    .veil_resource
+                    .append(veil_resource_table::parse_table_update(table_update)?),
+                "vendor" => db_update
+                    .vendor
+                    .append(vendor_table::parse_table_update(table_update)?),
+                "vendor_buyback" => db_update
+                    .vendor_buyback
+                    .append(vendor_buyback_table::parse_table_update(table_update)?),
+                "vendor_item" => db_update
+                    .vendor_item
+                    .append(vendor_item_table::parse_table_update(table_update)?),
+                "vendor_restock_timer" => db_update.vendor_restock_timer.append(
+                    vendor_restock_timer_table::parse_table_update(table_update)?,
+                ),
+                "waypoint" => db_update
+                    .waypoint
+                    .append(waypoint_table::parse_table_update(table_update)?),
+                "waypoint_discovered" => db_update
+                    .waypoint_discovered
+                    .append(waypoint_discovered_table::parse_table_update(table_update)?),
+                "waypoint_discovery_timer" => db_update.waypoint_discovery_timer.append(
+                    waypoint_discovery_timer_table::parse_table_update(table_update)?,
+                ),
                 "xp" => db_update
                     .xp
                     .append(xp_table::parse_table_update(table_update)?),
@@ -275,6 +2069,18 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.active_effect = cache
+            .apply_diff_to_table::<ActiveEffect>("active_effect", &self.active_effect)
+            .with_updates_by_pk(|row| &row.id);
+        diff.auction_expiry_timer = cache
+            .apply_diff_to_table::<AuctionExpiryTimer>(
+                "auction_expiry_timer",
+                &self.auction_expiry_timer,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.auction_listing = cache
+            .apply_diff_to_table::<AuctionListing>("auction_listing", &self.auction_listing)
+            .with_updates_by_pk(|row| &row.id);
         diff.character_def = cache
             .apply_diff_to_table::<CharacterDef>("character_def", &self.character_def)
             .with_updates_by_pk(|row| &row.id);
@@ -284,33 +2090,330 @@ impl __sdk::DbUpdate for DbUpdate {
         diff.class = cache
             .apply_diff_to_table::<Class>("class", &self.class)
             .with_updates_by_pk(|row| &row.id);
+        diff.collision_shape = cache
+            .apply_diff_to_table::<CollisionShape>("collision_shape", &self.collision_shape)
+            .with_updates_by_pk(|row| &row.id);
+        diff.currency = cache
+            .apply_diff_to_table::<Currency>("currency", &self.currency)
+            .with_updates_by_pk(|row| &row.character_id);
+        diff.currency_ledger = cache
+            .apply_diff_to_table::<CurrencyLedger>("currency_ledger", &self.currency_ledger)
+            .with_updates_by_pk(|row| &row.id);
+        diff.dialogue_choice = cache
+            .apply_diff_to_table::<DialogueChoice>("dialogue_choice", &self.dialogue_choice)
+            .with_updates_by_pk(|row| &row.id);
+        diff.dialogue_node = cache
+            .apply_diff_to_table::<DialogueNode>("dialogue_node", &self.dialogue_node)
+            .with_updates_by_pk(|row| &row.id);
+        diff.dialogue_session = cache
+            .apply_diff_to_table::<DialogueSession>("dialogue_session", &self.dialogue_session)
+            .with_updates_by_pk(|row| &row.character_id);
+        diff.dungeon_cleanup_timer = cache
+            .apply_diff_to_table::<DungeonCleanupTimer>(
+                "dungeon_cleanup_timer",
+                &self.dungeon_cleanup_timer,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.dungeon_def = cache
+            .apply_diff_to_table::<DungeonDef>("dungeon_def", &self.dungeon_def)
+            .with_updates_by_pk(|row| &row.id);
+        diff.dungeon_instance = cache
+            .apply_diff_to_table::<DungeonInstance>("dungeon_instance", &self.dungeon_instance)
+            .with_updates_by_pk(|row| &row.id);
+        diff.dungeon_lockout = cache
+            .apply_diff_to_table::<DungeonLockout>("dungeon_lockout", &self.dungeon_lockout)
+            .with_updates_by_pk(|row| &row.id);
+        diff.dungeon_portal = cache
+            .apply_diff_to_table::<DungeonPortal>("dungeon_portal", &self.dungeon_portal)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.dungeon_reset_timer = cache
+            .apply_diff_to_table::<DungeonResetTimer>(
+                "dungeon_reset_timer",
+                &self.dungeon_reset_timer,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.dungeon_spawn = cache
+            .apply_diff_to_table::<DungeonSpawn>("dungeon_spawn", &self.dungeon_spawn)
+            .with_updates_by_pk(|row| &row.id);
+        diff.effect_def = cache
+            .apply_diff_to_table::<EffectDef>("effect_def", &self.effect_def)
+            .with_updates_by_pk(|row| &row.id);
+        diff.effect_tick_timer = cache
+            .apply_diff_to_table::<EffectTickTimer>("effect_tick_timer", &self.effect_tick_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.entity = cache
             .apply_diff_to_table::<Entity>("entity", &self.entity)
             .with_updates_by_pk(|row| &row.id);
         diff.entity_movement = cache
             .apply_diff_to_table::<EntityMovement>("entity_movement", &self.entity_movement)
             .with_updates_by_pk(|row| &row.entity_id);
+        diff.friend = cache
+            .apply_diff_to_table::<Friend>("friend", &self.friend)
+            .with_updates_by_pk(|row| &row.id);
+        diff.friend_notification = cache
+            .apply_diff_to_table::<FriendNotification>(
+                "friend_notification",
+                &self.friend_notification,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.gather_cast = cache
+            .apply_diff_to_table::<GatherCast>("gather_cast", &self.gather_cast)
+            .with_updates_by_pk(|row| &row.character_id);
+        diff.gather_timer = cache
+            .apply_diff_to_table::<GatherTimer>("gather_timer", &self.gather_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.guild = cache
+            .apply_diff_to_table::<Guild>("guild", &self.guild)
+            .with_updates_by_pk(|row| &row.id);
+        diff.guild_invite = cache
+            .apply_diff_to_table::<GuildInvite>("guild_invite", &self.guild_invite)
+            .with_updates_by_pk(|row| &row.id);
+        diff.guild_member = cache
+            .apply_diff_to_table::<GuildMember>("guild_member", &self.guild_member)
+            .with_updates_by_pk(|row| &row.character_id);
+        diff.guild_message = cache
+            .apply_diff_to_table::<GuildMessage>("guild_message", &self.guild_message)
+            .with_updates_by_pk(|row| &row.id);
+        diff.guild_rank = cache
+            .apply_diff_to_table::<GuildRank>("guild_rank", &self.guild_rank)
+            .with_updates_by_pk(|row| &row.id);
         diff.health = cache
             .apply_diff_to_table::<Health>("health", &self.health)
             .with_updates_by_pk(|row| &row.id);
+        diff.hearth = cache
+            .apply_diff_to_table::<Hearth>("hearth", &self.hearth)
+            .with_updates_by_pk(|row| &row.character_id);
+        diff.ignored_player = cache
+            .apply_diff_to_table::<IgnoredPlayer>("ignored_player", &self.ignored_player)
+            .with_updates_by_pk(|row| &row.id);
+        diff.inventory_item = cache
+            .apply_diff_to_table::<InventoryItem>("inventory_item", &self.inventory_item)
+            .with_updates_by_pk(|row| &row.id);
+        diff.item_def = cache
+            .apply_diff_to_table::<ItemDef>("item_def", &self.item_def)
+            .with_updates_by_pk(|row| &row.id);
+        diff.item_type = cache
+            .apply_diff_to_table::<ItemType>("item_type", &self.item_type)
+            .with_updates_by_pk(|row| &row.id);
+        diff.lore_collection = cache
+            .apply_diff_to_table::<LoreCollection>("lore_collection", &self.lore_collection)
+            .with_updates_by_pk(|row| &row.id);
+        diff.lore_collection_completed = cache
+            .apply_diff_to_table::<LoreCollectionCompleted>(
+                "lore_collection_completed",
+                &self.lore_collection_completed,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.lore_entry = cache
+            .apply_diff_to_table::<LoreEntry>("lore_entry", &self.lore_entry)
+            .with_updates_by_pk(|row| &row.id);
+        diff.lore_object = cache
+            .apply_diff_to_table::<LoreObject>("lore_object", &self.lore_object)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.lore_unlocked = cache
+            .apply_diff_to_table::<LoreUnlocked>("lore_unlocked", &self.lore_unlocked)
+            .with_updates_by_pk(|row| &row.id);
+        diff.mail = cache
+            .apply_diff_to_table::<Mail>("mail", &self.mail)
+            .with_updates_by_pk(|row| &row.id);
+        diff.mail_expiry_timer = cache
+            .apply_diff_to_table::<MailExpiryTimer>("mail_expiry_timer", &self.mail_expiry_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.mailbox = cache
+            .apply_diff_to_table::<Mailbox>("mailbox", &self.mailbox)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.mailbox_spawn = cache
+            .apply_diff_to_table::<MailboxSpawn>("mailbox_spawn", &self.mailbox_spawn)
+            .with_updates_by_pk(|row| &row.id);
         diff.mana = cache
             .apply_diff_to_table::<Mana>("mana", &self.mana)
             .with_updates_by_pk(|row| &row.id);
+        diff.map = cache
+            .apply_diff_to_table::<Map>("map", &self.map)
+            .with_updates_by_pk(|row| &row.id);
+        diff.mount_def = cache
+            .apply_diff_to_table::<MountDef>("mount_def", &self.mount_def)
+            .with_updates_by_pk(|row| &row.id);
+        diff.mounted = cache
+            .apply_diff_to_table::<Mounted>("mounted", &self.mounted)
+            .with_updates_by_pk(|row| &row.entity_id);
         diff.movement_tick_timer = cache
             .apply_diff_to_table::<MovementTickTimer>(
                 "movement_tick_timer",
                 &self.movement_tick_timer,
             )
             .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.npc = cache
+            .apply_diff_to_table::<Npc>("npc", &self.npc)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.npc_def = cache
+            .apply_diff_to_table::<NpcDef>("npc_def", &self.npc_def)
+            .with_updates_by_pk(|row| &row.id);
+        diff.npc_respawn_timer = cache
+            .apply_diff_to_table::<NpcRespawnTimer>("npc_respawn_timer", &self.npc_respawn_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.npc_spawn = cache
+            .apply_diff_to_table::<NpcSpawn>("npc_spawn", &self.npc_spawn)
+            .with_updates_by_pk(|row| &row.id);
+        diff.npc_threat = cache
+            .apply_diff_to_table::<NpcThreat>("npc_threat", &self.npc_threat)
+            .with_updates_by_pk(|row| &row.id);
+        diff.owned_entity = cache
+            .apply_diff_to_table::<OwnedEntity>("owned_entity", &self.owned_entity)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.party = cache
+            .apply_diff_to_table::<Party>("party", &self.party)
+            .with_updates_by_pk(|row| &row.id);
+        diff.party_invite = cache
+            .apply_diff_to_table::<PartyInvite>("party_invite", &self.party_invite)
+            .with_updates_by_pk(|row| &row.id);
+        diff.party_member = cache
+            .apply_diff_to_table::<PartyMember>("party_member", &self.party_member)
+            .with_updates_by_pk(|row| &row.character_id);
         diff.player = cache
             .apply_diff_to_table::<Player>("player", &self.player)
             .with_updates_by_pk(|row| &row.identity);
+        diff.profession = cache
+            .apply_diff_to_table::<Profession>("profession", &self.profession)
+            .with_updates_by_pk(|row| &row.id);
+        diff.profession_skill = cache
+            .apply_diff_to_table::<ProfessionSkill>("profession_skill", &self.profession_skill)
+            .with_updates_by_pk(|row| &row.id);
+        diff.quest_def = cache
+            .apply_diff_to_table::<QuestDef>("quest_def", &self.quest_def)
+            .with_updates_by_pk(|row| &row.id);
+        diff.quest_location_timer = cache
+            .apply_diff_to_table::<QuestLocationTimer>(
+                "quest_location_timer",
+                &self.quest_location_timer,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.quest_objective = cache
+            .apply_diff_to_table::<QuestObjective>("quest_objective", &self.quest_objective)
+            .with_updates_by_pk(|row| &row.id);
+        diff.quest_progress = cache
+            .apply_diff_to_table::<QuestProgress>("quest_progress", &self.quest_progress)
+            .with_updates_by_pk(|row| &row.id);
         diff.race = cache
             .apply_diff_to_table::<Race>("race", &self.race)
             .with_updates_by_pk(|row| &row.id);
+        diff.recipe = cache
+            .apply_diff_to_table::<Recipe>("recipe", &self.recipe)
+            .with_updates_by_pk(|row| &row.id);
+        diff.recipe_ingredient = cache
+            .apply_diff_to_table::<RecipeIngredient>("recipe_ingredient", &self.recipe_ingredient)
+            .with_updates_by_pk(|row| &row.id);
+        diff.resource_node = cache
+            .apply_diff_to_table::<ResourceNode>("resource_node", &self.resource_node)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.resource_node_respawn_timer = cache
+            .apply_diff_to_table::<ResourceNodeRespawnTimer>(
+                "resource_node_respawn_timer",
+                &self.resource_node_respawn_timer,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.resource_node_spawn = cache
+            .apply_diff_to_table::<ResourceNodeSpawn>(
+                "resource_node_spawn",
+                &self.resource_node_spawn,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.stealth = cache
+            .apply_diff_to_table::<Stealth>("stealth", &self.stealth)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.stealth_detection = cache
+            .apply_diff_to_table::<StealthDetection>("stealth_detection", &self.stealth_detection)
+            .with_updates_by_pk(|row| &row.id);
+        diff.stealth_tick_timer = cache
+            .apply_diff_to_table::<StealthTickTimer>("stealth_tick_timer", &self.stealth_tick_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.summon_def = cache
+            .apply_diff_to_table::<SummonDef>("summon_def", &self.summon_def)
+            .with_updates_by_pk(|row| &row.id);
+        diff.summon_expiry_timer = cache
+            .apply_diff_to_table::<SummonExpiryTimer>(
+                "summon_expiry_timer",
+                &self.summon_expiry_timer,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.summon_tick_timer = cache
+            .apply_diff_to_table::<SummonTickTimer>("summon_tick_timer", &self.summon_tick_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.teleport_cast = cache
+            .apply_diff_to_table::<TeleportCast>("teleport_cast", &self.teleport_cast)
+            .with_updates_by_pk(|row| &row.character_id);
+        diff.teleport_cooldown = cache
+            .apply_diff_to_table::<TeleportCooldown>("teleport_cooldown", &self.teleport_cooldown)
+            .with_updates_by_pk(|row| &row.character_id);
+        diff.teleport_timer = cache
+            .apply_diff_to_table::<TeleportTimer>("teleport_timer", &self.teleport_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.threat_tick_timer = cache
+            .apply_diff_to_table::<ThreatTickTimer>("threat_tick_timer", &self.threat_tick_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.trade_offer = cache
+            .apply_diff_to_table::<TradeOffer>("trade_offer", &self.trade_offer)
+            .with_updates_by_pk(|row| &row.id);
+        diff.trade_participant = cache
+            .apply_diff_to_table::<TradeParticipant>("trade_participant", &self.trade_participant)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.trade_range_timer = cache
+            .apply_diff_to_table::<TradeRangeTimer>("trade_range_timer", &self.trade_range_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.trade_session = cache
+            .apply_diff_to_table::<TradeSession>("trade_session", &self.trade_session)
+            .with_updates_by_pk(|row| &row.id);
         diff.transform = cache
             .apply_diff_to_table::<Transform>("transform", &self.transform)
             .with_updates_by_pk(|row| &row.id);
+        diff.veil = cache
+            .apply_diff_to_table::<Veil>("veil", &self.veil)
+            .with_updates_by_pk(|row| &row.id);
+        diff.veil_chunk = cache
+            .apply_diff_to_table::<VeilChunk>("veil_chunk", &self.veil_chunk)
+            .with_updates_by_pk(|row| &row.chunk_id);
+        diff.veil_drift_timer = cache
+            .apply_diff_to_table::<VeilDriftTimer>("veil_drift_timer", &self.veil_drift_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.veil_effect_timer = cache
+            .apply_diff_to_table::<VeilEffectTimer>("veil_effect_timer", &self.veil_effect_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.veil_exposure = cache
+            .apply_diff_to_table::<VeilExposure>("veil_exposure", &self.veil_exposure)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.veil_resource = cache
+            .apply_diff_to_table::<VeilResource>("veil_resource", &self.veil_resource)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.vendor = cache
+            .apply_diff_to_table::<Vendor>("vendor", &self.vendor)
+            .with_updates_by_pk(|row| &row.id);
+        diff.vendor_buyback = cache
+            .apply_diff_to_table::<VendorBuyback>("vendor_buyback", &self.vendor_buyback)
+            .with_updates_by_pk(|row| &row.id);
+        diff.vendor_item = cache
+            .apply_diff_to_table::<VendorItem>("vendor_item", &self.vendor_item)
+            .with_updates_by_pk(|row| &row.id);
+        diff.vendor_restock_timer = cache
+            .apply_diff_to_table::<VendorRestockTimer>(
+                "vendor_restock_timer",
+                &self.vendor_restock_timer,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.waypoint = cache
+            .apply_diff_to_table::<Waypoint>("waypoint", &self.waypoint)
+            .with_updates_by_pk(|row| &row.id);
+        diff.waypoint_discovered = cache
+            .apply_diff_to_table::<WaypointDiscovered>(
+                "waypoint_discovered",
+                &self.waypoint_discovered,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.waypoint_discovery_timer = cache
+            .apply_diff_to_table::<WaypointDiscoveryTimer>(
+                "waypoint_discovery_timer",
+                &self.waypoint_discovery_timer,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.xp = cache
             .apply_diff_to_table::<Xp>("xp", &self.xp)
             .with_updates_by_pk(|row| &row.id);
@@ -326,17 +2429,108 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    active_effect: __sdk::TableAppliedDiff<'r, ActiveEffect>,
+    auction_expiry_timer: __sdk::TableAppliedDiff<'r, AuctionExpiryTimer>,
+    auction_listing: __sdk::TableAppliedDiff<'r, AuctionListing>,
     character_def: __sdk::TableAppliedDiff<'r, CharacterDef>,
     character_pawn: __sdk::TableAppliedDiff<'r, CharacterPawn>,
     class: __sdk::TableAppliedDiff<'r, Class>,
+    collision_shape: __sdk::TableAppliedDiff<'r, CollisionShape>,
+    currency: __sdk::TableAppliedDiff<'r, Currency>,
+    currency_ledger: __sdk::TableAppliedDiff<'r, CurrencyLedger>,
+    dialogue_choice: __sdk::TableAppliedDiff<'r, DialogueChoice>,
+    dialogue_node: __sdk::TableAppliedDiff<'r, DialogueNode>,
+    dialogue_session: __sdk::TableAppliedDiff<'r, DialogueSession>,
+    dungeon_cleanup_timer: __sdk::TableAppliedDiff<'r, DungeonCleanupTimer>,
+    dungeon_def: __sdk::TableAppliedDiff<'r, DungeonDef>,
+    dungeon_instance: __sdk::TableAppliedDiff<'r, DungeonInstance>,
+    dungeon_lockout: __sdk::TableAppliedDiff<'r, DungeonLockout>,
+    dungeon_portal: __sdk::TableAppliedDiff<'r, DungeonPortal>,
+    dungeon_reset_timer: __sdk::TableAppliedDiff<'r, DungeonResetTimer>,
+    dungeon_spawn: __sdk::TableAppliedDiff<'r, DungeonSpawn>,
+    effect_def: __sdk::TableAppliedDiff<'r, EffectDef>,
+    effect_tick_timer: __sdk::TableAppliedDiff<'r, EffectTickTimer>,
     entity: __sdk::TableAppliedDiff<'r, Entity>,
     entity_movement: __sdk::TableAppliedDiff<'r, EntityMovement>,
+    friend: __sdk::TableAppliedDiff<'r, Friend>,
+    friend_notification: __sdk::TableAppliedDiff<'r, FriendNotification>,
+    gather_cast: __sdk::TableAppliedDiff<'r, GatherCast>,
+    gather_timer: __sdk::TableAppliedDiff<'r, GatherTimer>,
+    guild: __sdk::TableAppliedDiff<'r, Guild>,
+    guild_invite: __sdk::TableAppliedDiff<'r, GuildInvite>,
+    guild_member: __sdk::TableAppliedDiff<'r, GuildMember>,
+    guild_message: __sdk::TableAppliedDiff<'r, GuildMessage>,
+    guild_rank: __sdk::TableAppliedDiff<'r, GuildRank>,
     health: __sdk::TableAppliedDiff<'r, Health>,
+    hearth: __sdk::TableAppliedDiff<'r, Hearth>,
+    ignored_player: __sdk::TableAppliedDiff<'r, IgnoredPlayer>,
+    inventory_item: __sdk::TableAppliedDiff<'r, InventoryItem>,
+    item_def: __sdk::TableAppliedDiff<'r, ItemDef>,
+    item_type: __sdk::TableAppliedDiff<'r, ItemType>,
+    lore_collection: __sdk::TableAppliedDiff<'r, LoreCollection>,
+    lore_collection_completed: __sdk::TableAppliedDiff<'r, LoreCollectionCompleted>,
+    lore_entry: __sdk::TableAppliedDiff<'r, LoreEntry>,
+    lore_object: __sdk::TableAppliedDiff<'r, LoreObject>,
+    lore_unlocked: __sdk::TableAppliedDiff<'r, LoreUnlocked>,
+    mail: __sdk::TableAppliedDiff<'r, Mail>,
+    mail_expiry_timer: __sdk::TableAppliedDiff<'r, MailExpiryTimer>,
+    mailbox: __sdk::TableAppliedDiff<'r, Mailbox>,
+    mailbox_spawn: __sdk::TableAppliedDiff<'r, MailboxSpawn>,
     mana: __sdk::TableAppliedDiff<'r, Mana>,
+    map: __sdk::TableAppliedDiff<'r, Map>,
+    mount_def: __sdk::TableAppliedDiff<'r, MountDef>,
+    mounted: __sdk::TableAppliedDiff<'r, Mounted>,
     movement_tick_timer: __sdk::TableAppliedDiff<'r, MovementTickTimer>,
+    npc: __sdk::TableAppliedDiff<'r, Npc>,
+    npc_def: __sdk::TableAppliedDiff<'r, NpcDef>,
+    npc_respawn_timer: __sdk::TableAppliedDiff<'r, NpcRespawnTimer>,
+    npc_spawn: __sdk::TableAppliedDiff<'r, NpcSpawn>,
+    npc_threat: __sdk::TableAppliedDiff<'r, NpcThreat>,
+    owned_entity: __sdk::TableAppliedDiff<'r, OwnedEntity>,
+    party: __sdk::TableAppliedDiff<'r, Party>,
+    party_invite: __sdk::TableAppliedDiff<'r, PartyInvite>,
+    party_member: __sdk::TableAppliedDiff<'r, PartyMember>,
     player: __sdk::TableAppliedDiff<'r, Player>,
+    profession: __sdk::TableAppliedDiff<'r, Profession>,
+    profession_skill: __sdk::TableAppliedDiff<'r, ProfessionSkill>,
+    quest_def: __sdk::TableAppliedDiff<'r, QuestDef>,
+    quest_location_timer: __sdk::TableAppliedDiff<'r, QuestLocationTimer>,
+    quest_objective: __sdk::TableAppliedDiff<'r, QuestObjective>,
+    quest_progress: __sdk::TableAppliedDiff<'r, QuestProgress>,
     race: __sdk::TableAppliedDiff<'r, Race>,
+    recipe: __sdk::TableAppliedDiff<'r, Recipe>,
+    recipe_ingredient: __sdk::TableAppliedDiff<'r, RecipeIngredient>,
+    resource_node: __sdk::TableAppliedDiff<'r, ResourceNode>,
+    resource_node_respawn_timer: __sdk::TableAppliedDiff<'r, ResourceNodeRespawnTimer>,
+    resource_node_spawn: __sdk::TableAppliedDiff<'r, ResourceNodeSpawn>,
+    stealth: __sdk::TableAppliedDiff<'r, Stealth>,
+    stealth_detection: __sdk::TableAppliedDiff<'r, StealthDetection>,
+    stealth_tick_timer: __sdk::TableAppliedDiff<'r, StealthTickTimer>,
+    summon_def: __sdk::TableAppliedDiff<'r, SummonDef>,
+    summon_expiry_timer: __sdk::TableAppliedDiff<'r, SummonExpiryTimer>,
+    summon_tick_timer: __sdk::TableAppliedDiff<'r, SummonTickTimer>,
+    teleport_cast: __sdk::TableAppliedDiff<'r, TeleportCast>,
+    teleport_cooldown: __sdk::TableAppliedDiff<'r, TeleportCooldown>,
+    teleport_timer: __sdk::TableAppliedDiff<'r, TeleportTimer>,
+    threat_tick_timer: __sdk::TableAppliedDiff<'r, ThreatTickTimer>,
+    trade_offer: __sdk::TableAppliedDiff<'r, TradeOffer>,
+    trade_participant: __sdk::TableAppliedDiff<'r, TradeParticipant>,
+    trade_range_timer: __sdk::TableAppliedDiff<'r, TradeRangeTimer>,
+    trade_session: __sdk::TableAppliedDiff<'r, TradeSession>,
     transform: __sdk::TableAppliedDiff<'r, Transform>,
+    veil: __sdk::TableAppliedDiff<'r, Veil>,
+    veil_chunk: __sdk::TableAppliedDiff<'r, VeilChunk>,
+    veil_drift_timer: __sdk::TableAppliedDiff<'r, VeilDriftTimer>,
+    veil_effect_timer: __sdk::TableAppliedDiff<'r, VeilEffectTimer>,
+    veil_exposure: __sdk::TableAppliedDiff<'r, VeilExposure>,
+    veil_resource: __sdk::TableAppliedDiff<'r, VeilResource>,
+    vendor: __sdk::TableAppliedDiff<'r, Vendor>,
+    vendor_buyback: __sdk::TableAppliedDiff<'r, VendorBuyback>,
+    vendor_item: __sdk::TableAppliedDiff<'r, VendorItem>,
+    vendor_restock_timer: __sdk::TableAppliedDiff<'r, VendorRestockTimer>,
+    waypoint: __sdk::TableAppliedDiff<'r, Waypoint>,
+    waypoint_discovered: __sdk::TableAppliedDiff<'r, WaypointDiscovered>,
+    waypoint_discovery_timer: __sdk::TableAppliedDiff<'r, WaypointDiscoveryTimer>,
     xp: __sdk::TableAppliedDiff<'r, Xp>,
     xp_progression: __sdk::TableAppliedDiff<'r, XpProgression>,
 }
@@ -351,6 +2545,21 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<ActiveEffect>(
+            "active_effect",
+            &self.active_effect,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<AuctionExpiryTimer>(
+            "auction_expiry_timer",
+            &self.auction_expiry_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<AuctionListing>(
+            "auction_listing",
+            &self.auction_listing,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<CharacterDef>(
             "character_def",
             &self.character_def,
@@ -362,22 +2571,330 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
             event,
         );
         callbacks.invoke_table_row_callbacks::<Class>("class", &self.class, event);
+        callbacks.invoke_table_row_callbacks::<CollisionShape>(
+            "collision_shape",
+            &self.collision_shape,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Currency>("currency", &self.currency, event);
+        callbacks.invoke_table_row_callbacks::<CurrencyLedger>(
+            "currency_ledger",
+            &self.currency_ledger,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DialogueChoice>(
+            "dialogue_choice",
+            &self.dialogue_choice,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DialogueNode>(
+            "dialogue_node",
+            &self.dialogue_node,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DialogueSession>(
+            "dialogue_session",
+            &self.dialogue_session,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DungeonCleanupTimer>(
+            "dungeon_cleanup_timer",
+            &self.dungeon_cleanup_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DungeonDef>("dungeon_def", &self.dungeon_def, event);
+        callbacks.invoke_table_row_callbacks::<DungeonInstance>(
+            "dungeon_instance",
+            &self.dungeon_instance,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DungeonLockout>(
+            "dungeon_lockout",
+            &self.dungeon_lockout,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DungeonPortal>(
+            "dungeon_portal",
+            &self.dungeon_portal,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DungeonResetTimer>(
+            "dungeon_reset_timer",
+            &self.dungeon_reset_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DungeonSpawn>(
+            "dungeon_spawn",
+            &self.dungeon_spawn,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<EffectDef>("effect_def", &self.effect_def, event);
+        callbacks.invoke_table_row_callbacks::<EffectTickTimer>(
+            "effect_tick_timer",
+            &self.effect_tick_timer,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Entity>("entity", &self.entity, event);
         callbacks.invoke_table_row_callbacks::<EntityMovement>(
             "entity_movement",
             &self.entity_movement,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<Friend>("friend", &self.friend, event);
+        callbacks.invoke_table_row_callbacks::<FriendNotification>(
+            "friend_notification",
+            &self.friend_notification,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<GatherCast>("gather_cast", &self.gather_cast, event);
+        callbacks.invoke_table_row_callbacks::<GatherTimer>(
+            "gather_timer",
+            &self.gather_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Guild>("guild", &self.guild, event);
+        callbacks.invoke_table_row_callbacks::<GuildInvite>(
+            "guild_invite",
+            &self.guild_invite,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<GuildMember>(
+            "guild_member",
+            &self.guild_member,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<GuildMessage>(
+            "guild_message",
+            &self.guild_message,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<GuildRank>("guild_rank", &self.guild_rank, event);
         callbacks.invoke_table_row_callbacks::<Health>("health", &self.health, event);
+        callbacks.invoke_table_row_callbacks::<Hearth>("hearth", &self.hearth, event);
+        callbacks.invoke_table_row_callbacks::<IgnoredPlayer>(
+            "ignored_player",
+            &self.ignored_player,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<InventoryItem>(
+            "inventory_item",
+            &self.inventory_item,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ItemDef>("item_def", &self.item_def, event);
+        callbacks.invoke_table_row_callbacks::<ItemType>("item_type", &self.item_type, event);
+        callbacks.invoke_table_row_callbacks::<LoreCollection>(
+            "lore_collection",
+            &self.lore_collection,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LoreCollectionCompleted>(
+            "lore_collection_completed",
+            &self.lore_collection_completed,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LoreEntry>("lore_entry", &self.lore_entry, event);
+        callbacks.invoke_table_row_callbacks::<LoreObject>("lore_object", &self.lore_object, event);
+        callbacks.invoke_table_row_callbacks::<LoreUnlocked>(
+            "lore_unlocked",
+            &self.lore_unlocked,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Mail>("mail", &self.mail, event);
+        callbacks.invoke_table_row_callbacks::<MailExpiryTimer>(
+            "mail_expiry_timer",
+            &self.mail_expiry_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Mailbox>("mailbox", &self.mailbox, event);
+        callbacks.invoke_table_row_callbacks::<MailboxSpawn>(
+            "mailbox_spawn",
+            &self.mailbox_spawn,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Mana>("mana", &self.mana, event);
+        callbacks.invoke_table_row_callbacks::<Map>("map", &self.map, event);
+        callbacks.invoke_table_row_callbacks::<MountDef>("mount_def", &self.mount_def, event);
+        callbacks.invoke_table_row_callbacks::<Mounted>("mounted", &self.mounted, event);
         callbacks.invoke_table_row_callbacks::<MovementTickTimer>(
             "movement_tick_timer",
             &self.movement_tick_timer,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<Npc>("npc", &self.npc, event);
+        callbacks.invoke_table_row_callbacks::<NpcDef>("npc_def", &self.npc_def, event);
+        callbacks.invoke_table_row_callbacks::<NpcRespawnTimer>(
+            "npc_respawn_timer",
+            &self.npc_respawn_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<NpcSpawn>("npc_spawn", &self.npc_spawn, event);
+        callbacks.invoke_table_row_callbacks::<NpcThreat>("npc_threat", &self.npc_threat, event);
+        callbacks.invoke_table_row_callbacks::<OwnedEntity>(
+            "owned_entity",
+            &self.owned_entity,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Party>("party", &self.party, event);
+        callbacks.invoke_table_row_callbacks::<PartyInvite>(
+            "party_invite",
+            &self.party_invite,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PartyMember>(
+            "party_member",
+            &self.party_member,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Player>("player", &self.player, event);
+        callbacks.invoke_table_row_callbacks::<Profession>("profession", &self.profession, event);
+        callbacks.invoke_table_row_callbacks::<ProfessionSkill>(
+            "profession_skill",
+            &self.profession_skill,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<QuestDef>("quest_def", &self.quest_def, event);
+        callbacks.invoke_table_row_callbacks::<QuestLocationTimer>(
+            "quest_location_timer",
+            &self.quest_location_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<QuestObjective>(
+            "quest_objective",
+            &self.quest_objective,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<QuestProgress>(
+            "quest_progress",
+            &self.quest_progress,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Race>("race", &self.race, event);
+        callbacks.invoke_table_row_callbacks::<Recipe>("recipe", &self.recipe, event);
+        callbacks.invoke_table_row_callbacks::<RecipeIngredient>(
+            "recipe_ingredient",
+            &self.recipe_ingredient,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ResourceNode>(
+            "resource_node",
+            &self.resource_node,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ResourceNodeRespawnTimer>(
+            "resource_node_respawn_timer",
+            &self.resource_node_respawn_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ResourceNodeSpawn>(
+            "resource_node_spawn",
+            &self.resource_node_spawn,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Stealth>("stealth", &self.stealth, event);
+        callbacks.invoke_table_row_callbacks::<StealthDetection>(
+            "stealth_detection",
+            &self.stealth_detection,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<StealthTickTimer>(
+            "stealth_tick_timer",
+            &self.stealth_tick_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<SummonDef>("summon_def", &self.summon_def, event);
+        callbacks.invoke_table_row_callbacks::<SummonExpiryTimer>(
+            "summon_expiry_timer",
+            &self.summon_expiry_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<SummonTickTimer>(
+            "summon_tick_timer",
+            &self.summon_tick_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<TeleportCast>(
+            "teleport_cast",
+            &self.teleport_cast,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<TeleportCooldown>(
+            "teleport_cooldown",
+            &self.teleport_cooldown,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<TeleportTimer>(
+            "teleport_timer",
+            &self.teleport_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ThreatTickTimer>(
+            "threat_tick_timer",
+            &self.threat_tick_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<TradeOffer>("trade_offer", &self.trade_offer, event);
+        callbacks.invoke_table_row_callbacks::<TradeParticipant>(
+            "trade_participant",
+            &self.trade_participant,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<TradeRangeTimer>(
+            "trade_range_timer",
+            &self.trade_range_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<TradeSession>(
+            "trade_session",
+            &self.trade_session,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Transform>("transform", &self.transform, event);
+        callbacks.invoke_table_row_callbacks::<Veil>("veil", &self.veil, event);
+        callbacks.invoke_table_row_callbacks::<VeilChunk>("veil_chunk", &self.veil_chunk, event);
+        callbacks.invoke_table_row_callbacks::<VeilDriftTimer>(
+            "veil_drift_timer",
+            &self.veil_drift_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<VeilEffectTimer>(
+            "veil_effect_timer",
+            &self.veil_effect_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<VeilExposure>(
+            "veil_exposure",
+            &self.veil_exposure,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<VeilResource>(
+            "veil_resource",
+            &self.veil_resource,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Vendor>("vendor", &self.vendor, event);
+        callbacks.invoke_table_row_callbacks::<VendorBuyback>(
+            "vendor_buyback",
+            &self.vendor_buyback,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<VendorItem>("vendor_item", &self.vendor_item, event);
+        callbacks.invoke_table_row_callbacks::<VendorRestockTimer>(
+            "vendor_restock_timer",
+            &self.vendor_restock_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Waypoint>("waypoint", &self.waypoint, event);
+        callbacks.invoke_table_row_callbacks::<WaypointDiscovered>(
+            "waypoint_discovered",
+            &self.waypoint_discovered,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<WaypointDiscoveryTimer>(
+            "waypoint_discovery_timer",
+            &self.waypoint_discovery_timer,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Xp>("xp", &self.xp, event);
         callbacks.invoke_table_row_callbacks::<XpProgression>(
             "xp_progression",
@@ -974,17 +3491,108 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        active_effect_table::register_table(client_cache);
+        auction_expiry_timer_table::register_table(client_cache);
+        auction_listing_table::register_table(client_cache);
         character_def_table::register_table(client_cache);
         character_pawn_table::register_table(client_cache);
         class_table::register_table(client_cache);
+        collision_shape_table::register_table(client_cache);
+        currency_table::register_table(client_cache);
+        currency_ledger_table::register_table(client_cache);
+        dialogue_choice_table::register_table(client_cache);
+        dialogue_node_table::register_table(client_cache);
+        dialogue_session_table::register_table(client_cache);
+        dungeon_cleanup_timer_table::register_table(client_cache);
+        dungeon_def_table::register_table(client_cache);
+        dungeon_instance_table::register_table(client_cache);
+        dungeon_lockout_table::register_table(client_cache);
+        dungeon_portal_table::register_table(client_cache);
+        dungeon_reset_timer_table::register_table(client_cache);
+        dungeon_spawn_table::register_table(client_cache);
+        effect_def_table::register_table(client_cache);
+        effect_tick_timer_table::register_table(client_cache);
         entity_table::register_table(client_cache);
         entity_movement_table::register_table(client_cache);
+        friend_table::register_table(client_cache);
+        friend_notification_table::register_table(client_cache);
+        gather_cast_table::register_table(client_cache);
+        gather_timer_table::register_table(client_cache);
+        guild_table::register_table(client_cache);
+        guild_invite_table::register_table(client_cache);
+        guild_member_table::register_table(client_cache);
+        guild_message_table::register_table(client_cache);
+        guild_rank_table::register_table(client_cache);
         health_table::register_table(client_cache);
+        hearth_table::register_table(client_cache);
+        ignored_player_table::register_table(client_cache);
+        inventory_item_table::register_table(client_cache);
+        item_def_table::register_table(client_cache);
+        item_type_table::register_table(client_cache);
+        lore_collection_table::register_table(client_cache);
+        lore_collection_completed_table::register_table(client_cache);
+        lore_entry_table::register_table(client_cache);
+        lore_object_table::register_table(client_cache);
+        lore_unlocked_table::register_table(client_cache);
+        mail_table::register_table(client_cache);
+        mail_expiry_timer_table::register_table(client_cache);
+        mailbox_table::register_table(client_cache);
+        mailbox_spawn_table::register_table(client_cache);
         mana_table::register_table(client_cache);
+        map_table::register_table(client_cache);
+        mount_def_table::register_table(client_cache);
+        mounted_table::register_table(client_cache);
         movement_tick_timer_table::register_table(client_cache);
+        npc_table::register_table(client_cache);
+        npc_def_table::register_table(client_cache);
+        npc_respawn_timer_table::register_table(client_cache);
+        npc_spawn_table::register_table(client_cache);
+        npc_threat_table::register_table(client_cache);
+        owned_entity_table::register_table(client_cache);
+        party_table::register_table(client_cache);
+        party_invite_table::register_table(client_cache);
+        party_member_table::register_table(client_cache);
         player_table::register_table(client_cache);
+        profession_table::register_table(client_cache);
+        profession_skill_table::register_table(client_cache);
+        quest_def_table::register_table(client_cache);
+        quest_location_timer_table::register_table(client_cache);
+        quest_objective_table::register_table(client_cache);
+        quest_progress_table::register_table(client_cache);
         race_table::register_table(client_cache);
+        recipe_table::register_table(client_cache);
+        recipe_ingredient_table::register_table(client_cache);
+        resource_node_table::register_table(client_cache);
+        resource_node_respawn_timer_table::register_table(client_cache);
+        resource_node_spawn_table::register_table(client_cache);
+        stealth_table::register_table(client_cache);
+        stealth_detection_table::register_table(client_cache);
+        stealth_tick_timer_table::register_table(client_cache);
+        summon_def_table::register_table(client_cache);
+        summon_expiry_timer_table::register_table(client_cache);
+        summon_tick_timer_table::register_table(client_cache);
+        teleport_cast_table::register_table(client_cache);
+        teleport_cooldown_table::register_table(client_cache);
+        teleport_timer_table::register_table(client_cache);
+        threat_tick_timer_table::register_table(client_cache);
+        trade_offer_table::register_table(client_cache);
+        trade_participant_table::register_table(client_cache);
+        trade_range_timer_table::register_table(client_cache);
+        trade_session_table::register_table(client_cache);
         transform_table::register_table(client_cache);
+        veil_table::register_table(client_cache);
+        veil_chunk_table::register_table(client_cache);
+        veil_drift_timer_table::register_table(client_cache);
+        veil_effect_timer_table::register_table(client_cache);
+        veil_exposure_table::register_table(client_cache);
+        veil_resource_table::register_table(client_cache);
+        vendor_table::register_table(client_cache);
+        vendor_buyback_table::register_table(client_cache);
+        vendor_item_table::register_table(client_cache);
+        vendor_restock_timer_table::register_table(client_cache);
+        waypoint_table::register_table(client_cache);
+        waypoint_discovered_table::register_table(client_cache);
+        waypoint_discovery_timer_table::register_table(client_cache);
         xp_table::register_table(client_cache);
         xp_progression_table::register_table(client_cache);
     }
@@ -14,7 +14,9 @@ pub struct Transform {
     pub translation: Vec3,
     pub rotation: Quat,
     pub scale: Vec3,
+    pub map_id: u32,
     pub chunk_id: u32,
+    pub stealthed: bool,
 }
 
 impl __sdk::InModule for Transform {
@@ -0,0 +1,149 @@
+//! The travel frame: teleporting to the local character's hearth or the waypoints they
+//! have discovered, and binding the hearth at a waypoint they stand next to.
+
+use crate::{
+    AppSystems,
+    player::PlayerState,
+    screens::Screen,
+    spacetime::SpacetimeDB,
+    stdb::{
+        TeleportDestination, TransformTableAccess, WaypointDiscoveredTableAccess,
+        WaypointTableAccess, set_hearth, teleport,
+    },
+    theme::colors::LABEL_TEXT,
+    ui::widgets::button::{ButtonProps, button},
+};
+use bevy::{prelude::*, ui_widgets::observe};
+use common::math;
+use spacetimedb_sdk::Table;
+
+/// Matches the range the server lets characters bind their hearth from.
+const BIND_RANGE: f32 = 5.0;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), setup);
+    app.add_systems(
+        Update,
+        sync_travel_frame
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+#[derive(Component)]
+struct TravelFrame;
+
+/// A snapshot of what the frame currently shows: the discovered waypoint ids and the
+/// waypoint the local character stands next to.
+#[derive(Component, Default, PartialEq)]
+struct TravelContents(Vec<u32>, Option<u32>);
+
+fn setup(mut commands: Commands) {
+    commands.spawn((
+        DespawnOnExit(Screen::Gameplay),
+        TravelFrame,
+        TravelContents::default(),
+        Node {
+            position_type: PositionType::Absolute,
+            left: px(10),
+            top: px(10),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(px(4)),
+            row_gap: px(4),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0., 0., 0., 0.5)),
+    ));
+}
+
+/// Rebuilds the frame whenever a waypoint is discovered or the local character walks up
+/// to or away from one.
+fn sync_travel_frame(
+    mut commands: Commands,
+    stdb: SpacetimeDB,
+    player: Option<Res<PlayerState>>,
+    frame: Single<(Entity, &mut TravelContents), With<TravelFrame>>,
+) {
+    let Some(player) = player else {
+        return;
+    };
+    let (root, mut shown) = frame.into_inner();
+
+    let mut discovered: Vec<u32> = stdb
+        .db()
+        .waypoint_discovered()
+        .iter()
+        .filter(|discovered| discovered.character_id == player.character_id)
+        .map(|discovered| discovered.waypoint_id)
+        .collect();
+    discovered.sort();
+    let nearby = stdb
+        .db()
+        .transform()
+        .id()
+        .find(&player.transform_id)
+        .and_then(|own| {
+            stdb.db().waypoint().iter().find(|waypoint| {
+                waypoint.map_id == own.map_id
+                    && math::Vec3::from(own.translation)
+                        .distance_squared_xz(waypoint.translation.into())
+                        <= BIND_RANGE * BIND_RANGE
+            })
+        })
+        .map(|waypoint| waypoint.id);
+    let current = TravelContents(discovered, nearby);
+    if *shown == current {
+        return;
+    }
+
+    commands.entity(root).despawn_related::<Children>();
+    commands.spawn((
+        Text::new("Travel"),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(LABEL_TEXT),
+        ChildOf(root),
+    ));
+    commands.spawn((
+        button(Spawn(Text::new("Hearth")), ButtonProps::default()),
+        observe(|_: On<Pointer<Click>>, stdb: SpacetimeDB| {
+            if let Err(err) = stdb.reducers().teleport(TeleportDestination::Hearth) {
+                println!("Failed to teleport: {err:?}");
+            }
+        }),
+        ChildOf(root),
+    ));
+    for waypoint_id in &current.0 {
+        let Some(waypoint) = stdb.db().waypoint().id().find(waypoint_id) else {
+            continue;
+        };
+        let waypoint_id = waypoint.id;
+        commands.spawn((
+            button(Spawn(Text::new(waypoint.name)), ButtonProps::default()),
+            observe(move |_: On<Pointer<Click>>, stdb: SpacetimeDB| {
+                if let Err(err) = stdb
+                    .reducers()
+                    .teleport(TeleportDestination::Waypoint(waypoint_id))
+                {
+                    println!("Failed to teleport: {err:?}");
+                }
+            }),
+            ChildOf(root),
+        ));
+    }
+    if let Some(waypoint_id) = current.1 {
+        commands.spawn((
+            button(Spawn(Text::new("Bind Hearth Here")), ButtonProps::default()),
+            observe(move |_: On<Pointer<Click>>, stdb: SpacetimeDB| {
+                if let Err(err) = stdb.reducers().set_hearth(waypoint_id) {
+                    println!("Failed to bind hearth: {err:?}");
+                }
+            }),
+            ChildOf(root),
+        ));
+    }
+
+    *shown = current;
+}
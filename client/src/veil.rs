@@ -0,0 +1,101 @@
+//! Renders the Veil as fog over the chunks it currently covers.
+
+use crate::{
+    AppSystems,
+    map::{CurrentMap, OVERWORLD_MAP_ID},
+    screens::Screen,
+    spacetime::SpacetimeDB,
+    stdb::VeilChunkTableAccess,
+};
+use bevy::{platform::collections::HashSet, prelude::*};
+use common::chunk::{self, CHUNK_SIZE};
+use spacetimedb_sdk::Table;
+
+const FOG_COLOR: Color = Color::srgba(0.55, 0.45, 0.75, 0.35);
+/// Lifts the fog slightly off the ground to avoid z-fighting.
+const FOG_HEIGHT: f32 = 0.05;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Gameplay), setup);
+    app.add_systems(
+        Update,
+        sync_fog
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// A fog tile covering a single chunk.
+#[derive(Component)]
+struct VeilFog(u32);
+
+#[derive(Resource)]
+struct FogAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(FogAssets {
+        mesh: meshes.add(Plane3d::default().mesh().size(CHUNK_SIZE, CHUNK_SIZE)),
+        material: materials.add(StandardMaterial {
+            base_color: FOG_COLOR,
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        }),
+    });
+}
+
+/// Spawns fog over chunks the Veil drifted onto and clears it from the chunks it left.
+/// The Veil only drifts over the overworld, so other maps have no fog at all.
+fn sync_fog(
+    mut commands: Commands,
+    stdb: SpacetimeDB,
+    assets: Option<Res<FogAssets>>,
+    current_map: Option<Res<CurrentMap>>,
+    fog_query: Query<(Entity, &VeilFog)>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+
+    let on_overworld = current_map.is_some_and(|map| map.0 == Some(OVERWORLD_MAP_ID));
+    let covered: HashSet<u32> = if on_overworld {
+        stdb.db()
+            .veil_chunk()
+            .iter()
+            .map(|row| row.chunk_id)
+            .collect()
+    } else {
+        HashSet::new()
+    };
+    let mut shown = HashSet::new();
+
+    for (entity, fog) in &fog_query {
+        if covered.contains(&fog.0) {
+            shown.insert(fog.0);
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for chunk_id in covered.difference(&shown) {
+        let (x, z) = chunk::decode(*chunk_id);
+        commands.spawn((
+            DespawnOnExit(Screen::Gameplay),
+            VeilFog(*chunk_id),
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::from_xyz(
+                (x as f32 + 0.5) * CHUNK_SIZE,
+                FOG_HEIGHT,
+                (z as f32 + 0.5) * CHUNK_SIZE,
+            ),
+        ));
+    }
+}
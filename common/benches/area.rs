@@ -0,0 +1,106 @@
+//! Compares looking up an area through a chunk ID index, the way the server narrows
+//! transforms with its btree index, against scanning every position.
+//!
+//! Run with `cargo bench -p common`.
+
+use std::collections::BTreeMap;
+use std::f32::consts::FRAC_PI_4;
+
+use common::{area::Area, chunk};
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+
+/// Half the side of the square the positions are spread over, in meters.
+const WORLD_HALF_SIZE: f32 = 1_000.0;
+const POSITIONS: usize = 20_000;
+
+/// Deterministic positions, so runs are comparable.
+fn positions() -> Vec<[f32; 2]> {
+    let mut state: u32 = 0x9E37_79B9;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as f32 / u32::MAX as f32 * 2.0 * WORLD_HALF_SIZE - WORLD_HALF_SIZE
+    };
+    (0..POSITIONS).map(|_| [next(), next()]).collect()
+}
+
+/// Positions keyed by chunk ID, like the `chunk_id` btree index on `transform`.
+fn index(positions: &[[f32; 2]]) -> BTreeMap<(u32, usize), [f32; 2]> {
+    positions
+        .iter()
+        .enumerate()
+        .map(|(id, position)| ((chunk::encode(position[0], position[1]), id), *position))
+        .collect()
+}
+
+fn scan(positions: &[[f32; 2]], area: &Area) -> usize {
+    positions
+        .iter()
+        .filter(|position| area.contains(**position))
+        .count()
+}
+
+fn indexed(index: &BTreeMap<(u32, usize), [f32; 2]>, area: &Area) -> usize {
+    area.chunk_id_ranges()
+        .into_iter()
+        .map(|range| {
+            index
+                .range((*range.start(), 0)..=(*range.end(), usize::MAX))
+                .filter(|(_, position)| area.contains(**position))
+                .count()
+        })
+        .sum()
+}
+
+fn areas() -> [(&'static str, Area); 3] {
+    [
+        (
+            "circle",
+            Area::Circle {
+                center: [12.0, -7.0],
+                radius: 15.0,
+            },
+        ),
+        (
+            "cone",
+            Area::Cone {
+                origin: [12.0, -7.0],
+                direction: [1.0, 1.0],
+                radius: 20.0,
+                half_angle: FRAC_PI_4,
+            },
+        ),
+        (
+            "rect",
+            Area::Rect {
+                origin: [12.0, -7.0],
+                direction: [0.0, 1.0],
+                length: 30.0,
+                half_width: 3.0,
+            },
+        ),
+    ]
+}
+
+fn area_queries(c: &mut Criterion) {
+    let positions = positions();
+    let index = index(&positions);
+    let mut group = c.benchmark_group("area");
+
+    for (name, area) in areas() {
+        // Both ways have to agree before their speed means anything
+        assert_eq!(scan(&positions, &area), indexed(&index, &area));
+
+        group.bench_with_input(BenchmarkId::new("scan", name), &area, |b, area| {
+            b.iter(|| scan(black_box(&positions), black_box(area)))
+        });
+        group.bench_with_input(BenchmarkId::new("chunk_index", name), &area, |b, area| {
+            b.iter(|| indexed(black_box(&index), black_box(area)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, area_queries);
+criterion_main!(benches);
@@ -0,0 +1,213 @@
+//! Areas of effect on the XZ plane.
+//!
+//! An area first narrows down the chunks it can touch, so callers holding entities in
+//! an index keyed by chunk ID only look at those chunks, and then checks positions
+//! exactly with [`Area::contains`].
+
+use std::ops::RangeInclusive;
+
+use glam::Vec2;
+
+use crate::chunk::{self, CHUNK_SIZE, ChunkBox, MAX_CHUNK, MIN_CHUNK};
+
+/// A shape on the XZ plane, in world units. Directions don't need to be normalized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Area {
+    Circle {
+        center: [f32; 2],
+        radius: f32,
+    },
+    /// A slice of a circle around `origin`, spreading `half_angle` radians to each side
+    /// of `direction`
+    Cone {
+        origin: [f32; 2],
+        direction: [f32; 2],
+        radius: f32,
+        half_angle: f32,
+    },
+    /// A rectangle reaching `length` forward from `origin` along `direction`, and
+    /// `half_width` to each side of it
+    Rect {
+        origin: [f32; 2],
+        direction: [f32; 2],
+        length: f32,
+        half_width: f32,
+    },
+}
+
+impl Area {
+    /// Whether `point` is inside the area, edges included.
+    pub fn contains(&self, point: [f32; 2]) -> bool {
+        let point = Vec2::from_array(point);
+        match *self {
+            Area::Circle { center, radius } => {
+                point.distance_squared(Vec2::from_array(center)) <= radius * radius
+            }
+            Area::Cone {
+                origin,
+                direction,
+                radius,
+                half_angle,
+            } => {
+                let offset = point - Vec2::from_array(origin);
+                if offset.length_squared() > radius * radius {
+                    return false;
+                }
+                // The tip of the cone points every way
+                if offset == Vec2::ZERO {
+                    return true;
+                }
+                let direction = Vec2::from_array(direction).normalize_or_zero();
+                offset.normalize().dot(direction) >= half_angle.cos()
+            }
+            Area::Rect {
+                origin,
+                direction,
+                length,
+                half_width,
+            } => {
+                let direction = Vec2::from_array(direction).normalize_or_zero();
+                let offset = point - Vec2::from_array(origin);
+                let forward = offset.dot(direction);
+                let side = offset.perp_dot(direction);
+                (0.0..=length).contains(&forward) && side.abs() <= half_width
+            }
+        }
+    }
+
+    /// A circle around the whole area.
+    fn bounding_circle(&self) -> (Vec2, f32) {
+        match *self {
+            Area::Circle { center, radius } => (Vec2::from_array(center), radius),
+            Area::Cone { origin, radius, .. } => (Vec2::from_array(origin), radius),
+            Area::Rect {
+                origin,
+                direction,
+                length,
+                half_width,
+            } => {
+                let direction = Vec2::from_array(direction).normalize_or_zero();
+                let center = Vec2::from_array(origin) + direction * (length / 2.0);
+                (center, Vec2::new(length / 2.0, half_width).length())
+            }
+        }
+    }
+
+    /// The chunk the area is centered on.
+    pub fn center_chunk_id(&self) -> u32 {
+        let (center, _) = self.bounding_circle();
+        let (x, z) = chunk_coords(center);
+        chunk::encode_chunk(x, z)
+    }
+
+    /// How many chunks away from its center chunk the area can reach. Every chunk the
+    /// area touches is `chunk::within_radius` of [`Area::center_chunk_id`] by it.
+    pub fn chunk_radius(&self) -> i32 {
+        let (_, radius) = self.bounding_circle();
+        (radius.max(0.0) / CHUNK_SIZE).ceil() as i32
+    }
+
+    /// The chunk IDs the area can touch, as one range per row of chunks. Chunk IDs keep
+    /// X in their lower bits, so each row is contiguous in a btree index on chunk ID
+    /// and can be looked up with a single range scan.
+    pub fn chunk_id_ranges(&self) -> Vec<RangeInclusive<u32>> {
+        ChunkBox::around(self.center_chunk_id(), self.chunk_radius())
+            .row_ranges()
+            .collect()
+    }
+}
+
+fn chunk_coords(point: Vec2) -> (i32, i32) {
+    (
+        ((point.x / CHUNK_SIZE).floor() as i32).clamp(MIN_CHUNK, MAX_CHUNK),
+        ((point.y / CHUNK_SIZE).floor() as i32).clamp(MIN_CHUNK, MAX_CHUNK),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_4;
+
+    #[test]
+    fn circle_contains() {
+        let area = Area::Circle {
+            center: [10.0, 10.0],
+            radius: 5.0,
+        };
+        assert!(area.contains([10.0, 10.0]));
+        assert!(area.contains([15.0, 10.0])); // Edge
+        assert!(!area.contains([15.1, 10.0]));
+    }
+
+    #[test]
+    fn cone_contains() {
+        // 90 degrees wide, facing +X
+        let area = Area::Cone {
+            origin: [0.0, 0.0],
+            direction: [2.0, 0.0],
+            radius: 10.0,
+            half_angle: FRAC_PI_4,
+        };
+        assert!(area.contains([0.0, 0.0]));
+        assert!(area.contains([5.0, 0.0]));
+        assert!(area.contains([5.0, 4.9]));
+        assert!(!area.contains([5.0, 5.1]));
+        assert!(!area.contains([-5.0, 0.0]));
+        assert!(!area.contains([10.1, 0.0]));
+    }
+
+    #[test]
+    fn rect_contains() {
+        // 20 m long and 4 m wide, facing +Z
+        let area = Area::Rect {
+            origin: [0.0, 0.0],
+            direction: [0.0, 1.0],
+            length: 20.0,
+            half_width: 2.0,
+        };
+        assert!(area.contains([0.0, 0.0]));
+        assert!(area.contains([2.0, 20.0]));
+        assert!(!area.contains([0.0, -0.1]));
+        assert!(!area.contains([0.0, 20.1]));
+        assert!(!area.contains([2.1, 10.0]));
+    }
+
+    #[test]
+    fn chunk_ranges_cover_the_area() {
+        let area = Area::Circle {
+            center: [25.0, -5.0],
+            radius: 30.0,
+        };
+        let ranges = area.chunk_id_ranges();
+        let radius = area.chunk_radius();
+        assert_eq!(radius, 2);
+        assert_eq!(ranges.len(), 5);
+
+        // Every point in the area falls in one of the ranges
+        for step in 0..=60 {
+            let x = -5.0 + step as f32;
+            for step in 0..=60 {
+                let z = -35.0 + step as f32;
+                if !area.contains([x, z]) {
+                    continue;
+                }
+                let id = chunk::encode(x, z);
+                assert!(ranges.iter().any(|range| range.contains(&id)));
+                assert!(chunk::within_radius(area.center_chunk_id(), id, radius));
+            }
+        }
+    }
+
+    #[test]
+    fn chunk_ranges_clamp_at_world_edge() {
+        let edge = MIN_CHUNK as f32 * CHUNK_SIZE;
+        let area = Area::Circle {
+            center: [edge, edge],
+            radius: 50.0,
+        };
+        let ranges = area.chunk_id_ranges();
+        assert_eq!(ranges.len(), 4);
+        assert_eq!(*ranges[0].start(), chunk::encode(edge, edge));
+    }
+}
@@ -1,5 +1,7 @@
 use glam::Vec2;
 
+use crate::math::Vec3;
+
 #[derive(Debug)]
 pub struct MovementResult2D {
     pub new_position: [f32; 2],
@@ -65,6 +67,38 @@ pub fn calculate_step_2d(
     }
 }
 
+/// The outcome of [`calculate_step`].
+#[derive(Debug)]
+pub struct MovementResult {
+    pub new_position: Vec3,
+    pub step: Vec3,
+    pub movement_finished: bool,
+}
+
+/// [`calculate_step_2d`] along the ground, keeping the height of `current_position`.
+pub fn calculate_step(
+    current_position: Vec3,
+    target_position: Vec3,
+    acceptance_radius: f32,
+    movement_speed: f32,
+    delta_time_seconds: f32,
+) -> MovementResult {
+    let result = calculate_step_2d(
+        current_position.xz(),
+        target_position.xz(),
+        acceptance_radius,
+        movement_speed,
+        delta_time_seconds,
+    );
+    let [x, z] = result.new_position;
+    let [step_x, step_z] = result.step;
+    MovementResult {
+        new_position: Vec3::new(x, current_position.y, z),
+        step: Vec3::new(step_x, 0.0, step_z),
+        movement_finished: result.movement_finished,
+    }
+}
+
 // 3D version if needed
 // // Define the outcome of a movement step
 // #[derive(Debug)]
@@ -1,12 +1,18 @@
 //! Utilities for encoding 2D chunk coordinates into a single `u32` ID.
 //!
-//! - Chunk size: 20.0 meters per side
+//! - Chunk size: 20.0 meters per side by default, see [`ChunkGrid`]
 //! - World size: ~655 km x 655 km (at 20 m per chunk)
 //! - Coordinate range: [-32768, 32767] chunks per axis
 //! - Storage format: lower 16 bits = X, upper 16 bits = Z
+//!
+//! The packing doesn't depend on the chunk size, so neighbors, rings and boxes of chunk
+//! IDs work the same on every grid. Only converting between world units and chunks
+//! goes through a [`ChunkGrid`]. The free functions use [`ChunkGrid::DEFAULT`].
+
+use std::ops::RangeInclusive;
 
 /// Chunk size in world units (meters).
-const CHUNK_SIZE: f32 = 20.0;
+pub const CHUNK_SIZE: f32 = 20.0;
 
 /// Number of bits per axis (16 + 16 = 32 bits total).
 const HALF_BITS: i32 = 16;
@@ -15,32 +21,174 @@ const HALF_BITS: i32 = 16;
 /// Example: chunk_x = -1 → shifted_x = 32767 (valid u16).
 const OFFSET_CHUNKS: i32 = 1 << (HALF_BITS - 1); // 32768
 
-/// Encode world coordinates `(x, z)` (in meters) into a packed chunk ID.
-///
-/// Each axis is divided into 20 m "chunks".
-/// The chunk coordinate is floored so negatives are consistent:
-///   - Example: `x = -0.1` → `chunk_x = -1`
-///
-/// Range checks (debug only) ensure that the packed ID does not overflow
-/// the 16-bit per-axis storage.
+/// The lowest chunk coordinate on either axis.
+pub const MIN_CHUNK: i32 = -OFFSET_CHUNKS;
+/// The highest chunk coordinate on either axis.
+pub const MAX_CHUNK: i32 = OFFSET_CHUNKS - 1;
+
+/// Chunk coordinates that don't fit in a chunk ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRange {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+}
+
+impl std::fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "chunk ({}, {}) is outside [{MIN_CHUNK}, {MAX_CHUNK}]",
+            self.chunk_x, self.chunk_z
+        )
+    }
+}
+
+impl std::error::Error for OutOfRange {}
+
+/// An axis aligned rectangle on the XZ plane, in world units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub min: [f32; 2],
+    pub max: [f32; 2],
+}
+
+impl Bounds {
+    /// Whether `point` is inside the rectangle, edges included.
+    pub fn contains(&self, point: [f32; 2]) -> bool {
+        (self.min[0]..=self.max[0]).contains(&point[0])
+            && (self.min[1]..=self.max[1]).contains(&point[1])
+    }
+}
+
+/// A square grid of chunks laid over the XZ plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChunkGrid {
+    cell_size: f32,
+}
+
+impl Default for ChunkGrid {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl ChunkGrid {
+    /// The grid the world is bucketed by, with [`CHUNK_SIZE`] chunks.
+    pub const DEFAULT: ChunkGrid = ChunkGrid {
+        cell_size: CHUNK_SIZE,
+    };
+
+    /// A grid of `cell_size` by `cell_size` chunks.
+    ///
+    /// # Panics
+    ///
+    /// If `cell_size` isn't a positive, finite number.
+    pub fn new(cell_size: f32) -> Self {
+        assert!(
+            cell_size.is_finite() && cell_size > 0.0,
+            "cell size {cell_size} must be positive and finite"
+        );
+        Self { cell_size }
+    }
+
+    /// The length of a chunk's side, in world units.
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// The chunk coordinates `(x, z)` fall in, which may be outside the range a chunk
+    /// ID can hold. Coordinates are floored so negatives are consistent:
+    ///   - Example: `x = -0.1` → `chunk_x = -1`
+    #[inline]
+    pub fn chunk_coords(&self, x: f32, z: f32) -> (i32, i32) {
+        // Float to int casts saturate, so far away points stay far away
+        (
+            (x / self.cell_size).floor() as i32,
+            (z / self.cell_size).floor() as i32,
+        )
+    }
+
+    /// Encode world coordinates `(x, z)` into a chunk ID, or fail if they are outside
+    /// the world.
+    #[inline]
+    pub fn try_encode(&self, x: f32, z: f32) -> Result<u32, OutOfRange> {
+        let (chunk_x, chunk_z) = self.chunk_coords(x, z);
+        try_encode_chunk(chunk_x, chunk_z)
+    }
+
+    /// Encode world coordinates `(x, z)` into a chunk ID.
+    ///
+    /// Coordinates outside the world panic in debug builds and are clamped to the
+    /// nearest edge chunk in release builds.
+    #[inline]
+    pub fn encode(&self, x: f32, z: f32) -> u32 {
+        let (chunk_x, chunk_z) = self.chunk_coords(x, z);
+        encode_chunk(chunk_x, chunk_z)
+    }
+
+    /// The rectangle a chunk covers. Its max edges belong to the next chunk over.
+    pub fn bounds(&self, chunk_id: u32) -> Bounds {
+        let (x, z) = decode(chunk_id);
+        Bounds {
+            min: [x as f32 * self.cell_size, z as f32 * self.cell_size],
+            max: [
+                (x + 1) as f32 * self.cell_size,
+                (z + 1) as f32 * self.cell_size,
+            ],
+        }
+    }
+
+    /// Every chunk the rectangle overlaps, clipped to the world.
+    pub fn chunk_box(&self, bounds: Bounds) -> ChunkBox {
+        let (min_x, min_z) = self.chunk_coords(bounds.min[0], bounds.min[1]);
+        let (max_x, max_z) = self.chunk_coords(bounds.max[0], bounds.max[1]);
+        ChunkBox::new(min_x, min_z, max_x, max_z)
+    }
+}
+
+/// Encode world coordinates `(x, z)` (in meters) into a packed chunk ID on the
+/// default grid, see [`ChunkGrid::encode`].
 #[inline]
 pub fn encode(x: f32, z: f32) -> u32 {
-    // Convert from world units into chunk indices (signed).
-    let chunk_x = (x / CHUNK_SIZE).floor() as i32;
-    let chunk_z = (z / CHUNK_SIZE).floor() as i32;
+    ChunkGrid::DEFAULT.encode(x, z)
+}
+
+/// Encode signed chunk coordinates `(chunk_x, chunk_z)` into a packed chunk ID, or
+/// fail if they don't fit.
+#[inline]
+pub fn try_encode_chunk(chunk_x: i32, chunk_z: i32) -> Result<u32, OutOfRange> {
+    if !(MIN_CHUNK..=MAX_CHUNK).contains(&chunk_x) || !(MIN_CHUNK..=MAX_CHUNK).contains(&chunk_z) {
+        return Err(OutOfRange { chunk_x, chunk_z });
+    }
+    Ok(pack(chunk_x, chunk_z))
+}
 
+/// Encode signed chunk coordinates `(chunk_x, chunk_z)` into a packed chunk ID.
+///
+/// Coordinates outside the world panic in debug builds and are clamped to the nearest
+/// edge chunk in release builds, rather than wrapping around to the other side.
+#[inline]
+pub fn encode_chunk(chunk_x: i32, chunk_z: i32) -> u32 {
     // Sanity check: ensure we are inside the valid range.
     debug_assert!(
-        (-OFFSET_CHUNKS..OFFSET_CHUNKS).contains(&chunk_x),
+        (MIN_CHUNK..=MAX_CHUNK).contains(&chunk_x),
         "chunk_x {} out of range",
         chunk_x
     );
     debug_assert!(
-        (-OFFSET_CHUNKS..OFFSET_CHUNKS).contains(&chunk_z),
+        (MIN_CHUNK..=MAX_CHUNK).contains(&chunk_z),
         "chunk_z {} out of range",
         chunk_z
     );
 
+    pack(
+        chunk_x.clamp(MIN_CHUNK, MAX_CHUNK),
+        chunk_z.clamp(MIN_CHUNK, MAX_CHUNK),
+    )
+}
+
+#[inline]
+fn pack(chunk_x: i32, chunk_z: i32) -> u32 {
     // Shift into unsigned space [0..65535].
     let shifted_x = (chunk_x + OFFSET_CHUNKS) as u32;
     let shifted_z = (chunk_z + OFFSET_CHUNKS) as u32;
@@ -66,6 +214,100 @@ pub fn within_radius(center_id: u32, other_id: u32, radius: i32) -> bool {
     (ox - cx).abs() <= radius && (oz - cz).abs() <= radius
 }
 
+/// The eight chunks around `chunk_id`, skipping any past the edge of the world.
+pub fn neighbors(chunk_id: u32) -> impl Iterator<Item = u32> {
+    ring(chunk_id, 1)
+}
+
+/// The chunks exactly `radius` chunks from `chunk_id` in Chebyshev distance, going
+/// round the square from its min corner and skipping any past the edge of
+/// the world. A radius of 0 is the chunk itself.
+pub fn ring(chunk_id: u32, radius: i32) -> impl Iterator<Item = u32> {
+    let (cx, cz) = decode(chunk_id);
+    // Past this every chunk of the ring is outside the world
+    let radius = radius.clamp(0, 2 * OFFSET_CHUNKS);
+    let side = 2 * radius;
+    // Walk each side of the square, stopping short of the corner the next side starts
+    // from. A zero radius has no sides, so yield the center once instead.
+    let steps = if radius == 0 { 1 } else { 4 * side };
+    (0..steps).filter_map(move |step| {
+        let (dx, dz) = match (step / side.max(1), step % side.max(1)) {
+            (0, i) => (i, 0),
+            (1, i) => (side, i),
+            (2, i) => (side - i, side),
+            (_, i) => (0, side - i),
+        };
+        try_encode_chunk(cx - radius + dx, cz - radius + dz).ok()
+    })
+}
+
+/// An inclusive box of chunk coordinates. A box whose min is past its max is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkBox {
+    pub min_x: i32,
+    pub min_z: i32,
+    pub max_x: i32,
+    pub max_z: i32,
+}
+
+impl ChunkBox {
+    /// The box between two corners, clipped to the world.
+    pub fn new(min_x: i32, min_z: i32, max_x: i32, max_z: i32) -> Self {
+        Self {
+            min_x: min_x.max(MIN_CHUNK),
+            min_z: min_z.max(MIN_CHUNK),
+            max_x: max_x.min(MAX_CHUNK),
+            max_z: max_z.min(MAX_CHUNK),
+        }
+    }
+
+    /// Every chunk within `radius` chunks of `chunk_id`, clipped to the world.
+    pub fn around(chunk_id: u32, radius: i32) -> Self {
+        let (x, z) = decode(chunk_id);
+        Self::new(
+            x.saturating_sub(radius),
+            z.saturating_sub(radius),
+            x.saturating_add(radius),
+            z.saturating_add(radius),
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min_x > self.max_x || self.min_z > self.max_z
+    }
+
+    pub fn contains(&self, chunk_id: u32) -> bool {
+        let (x, z) = decode(chunk_id);
+        (self.min_x..=self.max_x).contains(&x) && (self.min_z..=self.max_z).contains(&z)
+    }
+
+    /// The rectangle the box covers on `grid`.
+    pub fn bounds(&self, grid: &ChunkGrid) -> Bounds {
+        let min = grid.bounds(pack(self.min_x, self.min_z));
+        let max = grid.bounds(pack(self.max_x, self.max_z));
+        Bounds {
+            min: min.min,
+            max: max.max,
+        }
+    }
+
+    /// Every chunk ID in the box, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = u32> + use<> {
+        let (min_x, max_x) = (self.min_x, self.max_x);
+        (self.min_z..=self.max_z).flat_map(move |z| (min_x..=max_x).map(move |x| pack(x, z)))
+    }
+
+    /// The chunk IDs in the box as one range per row of chunks. Chunk IDs keep X in
+    /// their lower bits, so each row is contiguous in a btree index on chunk ID and can
+    /// be looked up with a single range scan.
+    pub fn row_ranges(&self) -> impl Iterator<Item = RangeInclusive<u32>> + use<> {
+        let (min_x, max_x) = (self.min_x, self.max_x);
+        (self.min_z..=self.max_z)
+            .filter(move |_| min_x <= max_x)
+            .map(move |z| pack(min_x, z)..=pack(max_x, z))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,9 +349,122 @@ mod tests {
     }
 
     #[test]
+    #[cfg(debug_assertions)]
     #[should_panic]
     fn encode_out_of_range_panics() {
-        // Should trigger debug_assert in debug builds (release skips)
+        // Should trigger debug_assert in debug builds (release clamps)
         let _ = encode((OFFSET_CHUNKS as f32) * CHUNK_SIZE, 0.0);
     }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn encode_out_of_range_clamps() {
+        let id = encode((OFFSET_CHUNKS as f32) * CHUNK_SIZE, -1.0e30);
+        assert_eq!(decode(id), (MAX_CHUNK, MIN_CHUNK));
+    }
+
+    #[test]
+    fn try_encode_out_of_range() {
+        let grid = ChunkGrid::DEFAULT;
+        assert!(grid.try_encode(0.0, 0.0).is_ok());
+        assert_eq!(
+            grid.try_encode((OFFSET_CHUNKS as f32) * CHUNK_SIZE, 0.0),
+            Err(OutOfRange {
+                chunk_x: OFFSET_CHUNKS,
+                chunk_z: 0
+            })
+        );
+        assert!(grid.try_encode(f32::NEG_INFINITY, 0.0).is_err());
+    }
+
+    #[test]
+    fn grid_cell_size() {
+        let grid = ChunkGrid::new(5.0);
+        assert_eq!(decode(grid.encode(12.0, -0.1)), (2, -1));
+        assert_eq!(
+            grid.bounds(grid.encode(12.0, -0.1)),
+            Bounds {
+                min: [10.0, -5.0],
+                max: [15.0, 0.0]
+            }
+        );
+        assert_eq!(ChunkGrid::default().encode(12.0, -0.1), encode(12.0, -0.1));
+    }
+
+    #[test]
+    fn bounds_round_trip() {
+        let grid = ChunkGrid::DEFAULT;
+        let id = encode(-35.0, 47.0);
+        let bounds = grid.bounds(id);
+        assert!(bounds.contains([-35.0, 47.0]));
+
+        let chunks = grid.chunk_box(Bounds {
+            min: [-35.0, 41.0],
+            max: [5.0, 47.0],
+        });
+        assert_eq!(chunks, ChunkBox::new(-2, 2, 0, 2));
+        assert_eq!(
+            chunks.bounds(&grid),
+            Bounds {
+                min: [-40.0, 40.0],
+                max: [20.0, 60.0]
+            }
+        );
+    }
+
+    #[test]
+    fn neighbors_surround_chunk() {
+        let center = encode_chunk(3, -4);
+        let ids: Vec<u32> = neighbors(center).collect();
+        assert_eq!(ids.len(), 8);
+        assert!(!ids.contains(&center));
+        for id in ids {
+            assert!(within_radius(center, id, 1));
+        }
+    }
+
+    #[test]
+    fn ring_is_box_outline() {
+        let center = encode_chunk(0, 0);
+        assert_eq!(ring(center, 0).collect::<Vec<_>>(), vec![center]);
+
+        let mut ids: Vec<u32> = ring(center, 2).collect();
+        assert_eq!(ids.len(), 16);
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 16);
+
+        let outline: Vec<u32> = ChunkBox::around(center, 2)
+            .iter()
+            .filter(|id| !within_radius(center, *id, 1))
+            .collect();
+        assert_eq!(outline.len(), 16);
+        assert!(outline.iter().all(|id| ids.contains(id)));
+    }
+
+    #[test]
+    fn ring_and_box_clip_at_world_edge() {
+        let corner = encode_chunk(MIN_CHUNK, MIN_CHUNK);
+        assert_eq!(neighbors(corner).count(), 3);
+        assert_eq!(ring(corner, 2).count(), 5);
+
+        let chunks = ChunkBox::around(corner, 1);
+        assert_eq!(chunks.iter().count(), 4);
+        assert!(chunks.contains(corner));
+        assert!(ChunkBox::around(corner, i32::MAX).contains(corner));
+    }
+
+    #[test]
+    fn box_rows_are_contiguous() {
+        let chunks = ChunkBox::new(-1, -1, 2, 1);
+        let ranges: Vec<_> = chunks.row_ranges().collect();
+        assert_eq!(ranges.len(), 3);
+        let from_ranges: Vec<u32> = ranges.into_iter().flatten().collect();
+        assert_eq!(from_ranges, chunks.iter().collect::<Vec<_>>());
+
+        let empty = ChunkBox::new(1, 0, 0, 0);
+        assert!(empty.is_empty());
+        assert_eq!(empty.iter().count(), 0);
+        assert_eq!(empty.row_ranges().count(), 0);
+    }
 }
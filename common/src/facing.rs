@@ -0,0 +1,99 @@
+//! Which way entities face on the XZ plane.
+//!
+//! Facing is a yaw in radians around the Y axis. A yaw of zero faces -Z, the forward
+//! direction of an unrotated transform, and positive yaw turns counterclockwise when
+//! looking down from above.
+
+use std::f32::consts::{PI, TAU};
+
+use glam::Vec2;
+
+/// The direction a yaw faces, as `[x, z]`.
+pub fn forward(yaw: f32) -> [f32; 2] {
+    let (sin, cos) = yaw.sin_cos();
+    [-sin, -cos]
+}
+
+/// The yaw facing along `direction`, or `None` if it has no length.
+pub fn yaw_towards(direction: [f32; 2]) -> Option<f32> {
+    let [x, z] = direction;
+    if x == 0.0 && z == 0.0 {
+        return None;
+    }
+    Some((-x).atan2(-z))
+}
+
+/// The signed shortest turn from yaw `from` to yaw `to`, in `(-PI, PI]`.
+pub fn angle_between(from: f32, to: f32) -> f32 {
+    let angle = (to - from).rem_euclid(TAU);
+    if angle > PI { angle - TAU } else { angle }
+}
+
+/// Turns from `current` towards `target` by at most `max_turn` radians, the short way
+/// around.
+pub fn turn_towards(current: f32, target: f32, max_turn: f32) -> f32 {
+    let angle = angle_between(current, target);
+    current + angle.clamp(-max_turn.max(0.0), max_turn.max(0.0))
+}
+
+/// Whether something at `origin` facing `yaw` sees `target` within `half_angle` radians
+/// to either side. A target right at the origin is always in front.
+pub fn in_facing_cone(origin: [f32; 2], yaw: f32, target: [f32; 2], half_angle: f32) -> bool {
+    let offset = Vec2::from_array(target) - Vec2::from_array(origin);
+    if offset == Vec2::ZERO {
+        return true;
+    }
+    offset.normalize().dot(Vec2::from_array(forward(yaw))) >= half_angle.cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-5, "{a} != {b}");
+    }
+
+    #[test]
+    fn forward_and_yaw_round_trip() {
+        assert_eq!(yaw_towards([0.0, -1.0]), Some(0.0));
+        assert_close(yaw_towards([-1.0, 0.0]).unwrap(), FRAC_PI_2);
+        assert_eq!(yaw_towards([0.0, 0.0]), None);
+
+        for step in -8..=8 {
+            let yaw = step as f32 * FRAC_PI_4 / 2.0;
+            // PI and -PI face the same way
+            assert_close(angle_between(yaw_towards(forward(yaw)).unwrap(), yaw), 0.0);
+        }
+    }
+
+    #[test]
+    fn angle_between_takes_the_short_way() {
+        assert_close(angle_between(0.0, FRAC_PI_2), FRAC_PI_2);
+        assert_close(angle_between(FRAC_PI_2, 0.0), -FRAC_PI_2);
+        // Across the seam at PI
+        assert_close(angle_between(PI - 0.1, -PI + 0.1), 0.2);
+        assert_close(angle_between(0.0, TAU), 0.0);
+    }
+
+    #[test]
+    fn turn_is_limited() {
+        assert_close(turn_towards(0.0, FRAC_PI_2, 0.5), 0.5);
+        assert_close(turn_towards(0.0, -FRAC_PI_2, 0.5), -0.5);
+        // Doesn't overshoot
+        assert_close(turn_towards(0.0, 0.2, 0.5), 0.2);
+        assert_close(turn_towards(PI - 0.1, -PI + 0.1, 0.5), PI + 0.1);
+    }
+
+    #[test]
+    fn facing_cone() {
+        // Facing -Z, 90 degrees wide
+        let yaw = 0.0;
+        assert!(in_facing_cone([0.0, 0.0], yaw, [0.0, -5.0], FRAC_PI_4));
+        assert!(in_facing_cone([0.0, 0.0], yaw, [4.9, -5.0], FRAC_PI_4));
+        assert!(!in_facing_cone([0.0, 0.0], yaw, [5.1, -5.0], FRAC_PI_4));
+        assert!(!in_facing_cone([0.0, 0.0], yaw, [0.0, 5.0], FRAC_PI_4));
+        assert!(in_facing_cone([1.0, 1.0], yaw, [1.0, 1.0], FRAC_PI_4));
+    }
+}
@@ -1,7 +1,11 @@
 use glam::Vec2;
 
+pub mod area;
 pub mod calculate_step;
 pub mod chunk;
+pub mod facing;
+pub mod line_of_sight;
+pub mod math;
 
 pub fn distance_squared(a: [f32; 2], b: [f32; 2]) -> f32 {
     let a = Vec2::from_array(a);
@@ -0,0 +1,367 @@
+//! Line of sight against static world geometry.
+//!
+//! Walls, pillars and other blockers are collision shapes on the XZ plane. They have
+//! no height, so anything they cover blocks sight at every height. Shapes are bucketed
+//! by the chunk grid from [`crate::chunk`], and a ray only tests the shapes in the
+//! chunks it passes through.
+//!
+//! The server stores the buckets in an indexed table and looks them up chunk by chunk
+//! with [`chunks_along_ray`] and [`Shape::ray_hit`]. The client keeps them in memory in
+//! a [`StaticGeometry`].
+
+use std::collections::HashMap;
+
+use glam::Vec2;
+
+use crate::chunk::{self, Bounds, CHUNK_SIZE, ChunkGrid};
+
+/// A static blocker on the XZ plane, in world units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    Circle {
+        center: [f32; 2],
+        radius: f32,
+    },
+    /// An axis aligned rectangle
+    Rect {
+        min: [f32; 2],
+        max: [f32; 2],
+    },
+}
+
+impl Shape {
+    /// The corners of the axis aligned box around the shape.
+    pub fn bounds(&self) -> Bounds {
+        match *self {
+            Shape::Circle { center, radius } => Bounds {
+                min: [center[0] - radius, center[1] - radius],
+                max: [center[0] + radius, center[1] + radius],
+            },
+            Shape::Rect { min, max } => Bounds { min, max },
+        }
+    }
+
+    /// Every chunk the shape overlaps, which are the buckets it belongs in.
+    pub fn chunk_ids(&self) -> Vec<u32> {
+        ChunkGrid::DEFAULT.chunk_box(self.bounds()).iter().collect()
+    }
+
+    /// How far along the segment from `from` to `to` it first touches the shape, as a
+    /// fraction between `0.0` and `1.0`. A segment starting inside the shape hits it
+    /// at `0.0`.
+    pub fn ray_hit(&self, from: [f32; 2], to: [f32; 2]) -> Option<f32> {
+        let origin = Vec2::from_array(from);
+        let delta = Vec2::from_array(to) - origin;
+
+        match *self {
+            Shape::Circle { center, radius } => {
+                let offset = origin - Vec2::from_array(center);
+                let c = offset.length_squared() - radius * radius;
+                if c <= 0.0 {
+                    return Some(0.0);
+                }
+                let a = delta.length_squared();
+                if a == 0.0 {
+                    return None;
+                }
+                let b = offset.dot(delta);
+                let discriminant = b * b - a * c;
+                if discriminant < 0.0 {
+                    return None;
+                }
+                let t = (-b - discriminant.sqrt()) / a;
+                (0.0..=1.0).contains(&t).then_some(t)
+            }
+            Shape::Rect { min, max } => {
+                // Slab test, clipping the segment against each axis in turn
+                let mut enter = 0.0_f32;
+                let mut exit = 1.0_f32;
+                for axis in 0..2 {
+                    let (o, d) = (origin[axis], delta[axis]);
+                    if d == 0.0 {
+                        if o < min[axis] || o > max[axis] {
+                            return None;
+                        }
+                        continue;
+                    }
+                    let t1 = (min[axis] - o) / d;
+                    let t2 = (max[axis] - o) / d;
+                    enter = enter.max(t1.min(t2));
+                    exit = exit.min(t1.max(t2));
+                    if enter > exit {
+                        return None;
+                    }
+                }
+                Some(enter)
+            }
+        }
+    }
+}
+
+fn chunk_coords(point: [f32; 2]) -> (i32, i32) {
+    ChunkGrid::DEFAULT.chunk_coords(point[0], point[1])
+}
+
+/// Every chunk the segment from `from` to `to` passes through, in order, starting with
+/// the chunk of `from` and ending with the chunk of `to`.
+///
+/// Walks the grid one chunk at a time, always crossing whichever chunk edge the
+/// segment reaches first. A segment passing exactly through a chunk corner also visits
+/// one of the chunks beside the corner.
+pub fn chunks_along_ray(from: [f32; 2], to: [f32; 2]) -> Vec<u32> {
+    let (mut x, mut z) = chunk_coords(from);
+    let (end_x, end_z) = chunk_coords(to);
+    let delta = Vec2::from_array(to) - Vec2::from_array(from);
+
+    // Per axis: which way to step, the fraction of the segment until the next chunk
+    // edge, and the fraction it takes to cross a whole chunk
+    let axis = |cell: i32, origin: f32, d: f32| -> (i32, f32, f32) {
+        if d > 0.0 {
+            (
+                1,
+                ((cell + 1) as f32 * CHUNK_SIZE - origin) / d,
+                CHUNK_SIZE / d,
+            )
+        } else if d < 0.0 {
+            (-1, (cell as f32 * CHUNK_SIZE - origin) / d, -CHUNK_SIZE / d)
+        } else {
+            (0, f32::INFINITY, f32::INFINITY)
+        }
+    };
+    let (step_x, mut next_x, cross_x) = axis(x, from[0], delta.x);
+    let (step_z, mut next_z, cross_z) = axis(z, from[1], delta.y);
+
+    let steps = (end_x - x).abs() + (end_z - z).abs();
+    let mut ids = Vec::with_capacity(steps as usize + 1);
+    ids.push(chunk::encode_chunk(x, z));
+    for _ in 0..steps {
+        // Rounding can make the walk favour an axis that is already done
+        if (next_x < next_z && x != end_x) || z == end_z {
+            x += step_x;
+            next_x += cross_x;
+        } else {
+            z += step_z;
+            next_z += cross_z;
+        }
+        ids.push(chunk::encode_chunk(x, z));
+    }
+    ids
+}
+
+/// Static collision shapes bucketed by chunk, for answering line of sight queries.
+#[derive(Debug, Default, Clone)]
+pub struct StaticGeometry {
+    chunks: HashMap<u32, Vec<Shape>>,
+}
+
+impl StaticGeometry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a shape to the bucket of every chunk it overlaps.
+    pub fn insert(&mut self, shape: Shape) {
+        for chunk_id in shape.chunk_ids() {
+            self.insert_in_chunk(chunk_id, shape);
+        }
+    }
+
+    /// Adds a shape to a single chunk's bucket, for shapes that are already bucketed.
+    pub fn insert_in_chunk(&mut self, chunk_id: u32, shape: Shape) {
+        self.chunks.entry(chunk_id).or_default().push(shape);
+    }
+
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+    }
+
+    /// The shapes bucketed in a chunk.
+    pub fn shapes_in_chunk(&self, chunk_id: u32) -> &[Shape] {
+        self.chunks.get(&chunk_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// How far along the segment from `from` to `to` it first hits a shape, as a
+    /// fraction between `0.0` and `1.0`.
+    pub fn raycast(&self, from: [f32; 2], to: [f32; 2]) -> Option<f32> {
+        let mut nearest: Option<f32> = None;
+        for chunk_id in chunks_along_ray(from, to) {
+            for shape in self.shapes_in_chunk(chunk_id) {
+                if let Some(t) = shape.ray_hit(from, to) {
+                    nearest = Some(nearest.map_or(t, |nearest| nearest.min(t)));
+                }
+            }
+        }
+        nearest
+    }
+
+    /// Whether nothing blocks the segment from `from` to `to`.
+    pub fn has_line_of_sight(&self, from: [f32; 2], to: [f32; 2]) -> bool {
+        chunks_along_ray(from, to).into_iter().all(|chunk_id| {
+            self.shapes_in_chunk(chunk_id)
+                .iter()
+                .all(|shape| shape.ray_hit(from, to).is_none())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pillar(x: f32, z: f32) -> Shape {
+        Shape::Circle {
+            center: [x, z],
+            radius: 1.0,
+        }
+    }
+
+    #[test]
+    fn open_ground_has_line_of_sight() {
+        let geometry = StaticGeometry::new();
+        assert!(geometry.has_line_of_sight([0.0, 0.0], [55.0, -30.0]));
+        assert_eq!(geometry.raycast([0.0, 0.0], [55.0, -30.0]), None);
+    }
+
+    #[test]
+    fn pillar_blocks_sight() {
+        let mut geometry = StaticGeometry::new();
+        geometry.insert(pillar(5.0, 0.0));
+        assert!(!geometry.has_line_of_sight([0.0, 0.0], [10.0, 0.0]));
+        // The near edge of the pillar is 4 m into a 10 m ray
+        let t = geometry.raycast([0.0, 0.0], [10.0, 0.0]).unwrap();
+        assert!((t - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn ray_beside_pillar_is_clear() {
+        let mut geometry = StaticGeometry::new();
+        geometry.insert(pillar(5.0, 0.0));
+        assert!(geometry.has_line_of_sight([0.0, 1.5], [10.0, 1.5]));
+    }
+
+    #[test]
+    fn ray_stopping_short_is_clear() {
+        let mut geometry = StaticGeometry::new();
+        geometry.insert(pillar(5.0, 0.0));
+        assert!(geometry.has_line_of_sight([0.0, 0.0], [3.9, 0.0]));
+    }
+
+    #[test]
+    fn wall_blocks_sight() {
+        let mut geometry = StaticGeometry::new();
+        geometry.insert(Shape::Rect {
+            min: [-1.0, 10.0],
+            max: [1.0, 30.0],
+        });
+        assert!(!geometry.has_line_of_sight([-10.0, 20.0], [10.0, 20.0]));
+        assert!(geometry.has_line_of_sight([-10.0, 31.0], [10.0, 31.0]));
+        // Rays parallel to a wall edge
+        assert!(!geometry.has_line_of_sight([0.0, 0.0], [0.0, 40.0]));
+        assert!(geometry.has_line_of_sight([2.0, 0.0], [2.0, 40.0]));
+    }
+
+    #[test]
+    fn starting_inside_a_shape_is_blocked() {
+        let mut geometry = StaticGeometry::new();
+        geometry.insert(pillar(5.0, 0.0));
+        assert_eq!(geometry.raycast([5.0, 0.5], [20.0, 0.0]), Some(0.0));
+        assert!(!geometry.has_line_of_sight([5.0, 0.0], [5.0, 0.0]));
+    }
+
+    #[test]
+    fn shape_spanning_chunks_is_found_from_each() {
+        // A wall straddling the chunk edge at x = 20 is bucketed on both sides
+        let wall = Shape::Rect {
+            min: [19.0, 5.0],
+            max: [21.0, 6.0],
+        };
+        assert_eq!(
+            wall.chunk_ids(),
+            vec![chunk::encode(0.0, 0.0), chunk::encode(20.0, 0.0)]
+        );
+
+        let mut geometry = StaticGeometry::new();
+        geometry.insert(wall);
+        assert!(!geometry.has_line_of_sight([19.5, 0.0], [19.5, 10.0]));
+        assert!(!geometry.has_line_of_sight([20.5, 0.0], [20.5, 10.0]));
+    }
+
+    #[test]
+    fn negative_coordinates() {
+        let mut geometry = StaticGeometry::new();
+        geometry.insert(pillar(-25.0, -25.0));
+        assert!(!geometry.has_line_of_sight([-5.0, -5.0], [-45.0, -45.0]));
+        assert!(geometry.has_line_of_sight([-5.0, -5.0], [-45.0, -5.0]));
+    }
+
+    #[test]
+    fn shapes_outside_the_walked_chunks_are_skipped() {
+        // Bucketed by hand into a chunk the ray never enters
+        let mut geometry = StaticGeometry::new();
+        geometry.insert_in_chunk(chunk::encode(100.0, 100.0), pillar(5.0, 0.0));
+        assert!(geometry.has_line_of_sight([0.0, 0.0], [10.0, 0.0]));
+    }
+
+    #[test]
+    fn ray_walk_covers_start_and_end() {
+        let ids = chunks_along_ray([1.0, 1.0], [1.0, 1.0]);
+        assert_eq!(ids, vec![chunk::encode(1.0, 1.0)]);
+
+        let ids = chunks_along_ray([5.0, 5.0], [65.0, 5.0]);
+        assert_eq!(
+            ids,
+            vec![
+                chunk::encode(5.0, 5.0),
+                chunk::encode(25.0, 5.0),
+                chunk::encode(45.0, 5.0),
+                chunk::encode(65.0, 5.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn ray_walk_steps_to_neighbours() {
+        // Every step moves to an edge neighbour, for rays in every direction
+        let targets = [
+            [73.0, 41.0],
+            [-73.0, 41.0],
+            [73.0, -41.0],
+            [-73.0, -41.0],
+            [3.0, -97.0],
+            [40.0, 40.0],
+        ];
+        for to in targets {
+            let from = [2.0, 2.0];
+            let ids = chunks_along_ray(from, to);
+            assert_eq!(ids.first(), Some(&chunk::encode(from[0], from[1])));
+            assert_eq!(ids.last(), Some(&chunk::encode(to[0], to[1])));
+            for pair in ids.windows(2) {
+                let (ax, az) = chunk::decode(pair[0]);
+                let (bx, bz) = chunk::decode(pair[1]);
+                assert_eq!((ax - bx).abs() + (az - bz).abs(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn diagonal_ray_finds_pillar_in_crossed_chunk() {
+        let mut geometry = StaticGeometry::new();
+        geometry.insert(pillar(30.0, 30.0));
+        assert!(!geometry.has_line_of_sight([0.0, 0.0], [50.0, 50.0]));
+        assert!(geometry.has_line_of_sight([0.0, 10.0], [50.0, 60.0]));
+    }
+
+    #[test]
+    fn results_are_repeatable() {
+        let mut geometry = StaticGeometry::new();
+        geometry.insert(pillar(12.3, -7.7));
+        geometry.insert(Shape::Rect {
+            min: [31.0, -40.0],
+            max: [33.0, 2.0],
+        });
+        let first = geometry.raycast([-3.1, 4.2], [58.9, -21.4]);
+        for _ in 0..100 {
+            assert_eq!(geometry.raycast([-3.1, 4.2], [58.9, -21.4]), first);
+        }
+    }
+}
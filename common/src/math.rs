@@ -0,0 +1,321 @@
+//! Math types shared by the server, the client and the rest of `common`.
+//!
+//! [`Vec3`] and [`Quat`] are plain `f32` structs so they can be stored in SpacetimeDB
+//! tables, which they can be with the `spacetime` feature enabled. They convert
+//! losslessly to and from their `glam` counterparts, which are also Bevy's, for
+//! anything beyond the basics here.
+//!
+//! The world's ground is the XZ plane. The `_xz` methods ignore height, and
+//! [`Vec3::xz`] hands a position to the planar code in `area`, `facing` and
+//! `line_of_sight`.
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A 3-dimensional vector.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "spacetime", derive(spacetimedb_sats::SpacetimeType))]
+#[cfg_attr(feature = "spacetime", sats(crate = spacetimedb_sats))]
+pub struct Vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Vec3 {
+    pub const ZERO: Self = Self::new(0., 0., 0.);
+
+    /// Creates a new vector.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates a new vector from an array.
+    #[inline]
+    #[must_use]
+    pub const fn from_array(a: [f32; 3]) -> Self {
+        Self::new(a[0], a[1], a[2])
+    }
+
+    /// Converts `self` to `[x, y, z]`
+    #[inline]
+    #[must_use]
+    pub const fn to_array(&self) -> [f32; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// The position on the ground, as `[x, z]`.
+    #[inline]
+    #[must_use]
+    pub const fn xz(&self) -> [f32; 2] {
+        [self.x, self.z]
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn length_squared(self) -> f32 {
+        glam::Vec3::from(self).length_squared()
+    }
+
+    /// The squared distance to `other` along the ground, ignoring height.
+    #[inline]
+    #[must_use]
+    pub fn distance_squared_xz(self, other: Self) -> f32 {
+        glam::Vec2::from_array(self.xz()).distance_squared(glam::Vec2::from_array(other.xz()))
+    }
+}
+
+impl From<glam::Vec3> for Vec3 {
+    #[inline]
+    fn from(v: glam::Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3> for glam::Vec3 {
+    #[inline]
+    fn from(v: Vec3) -> Self {
+        glam::Vec3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<[f32; 3]> for Vec3 {
+    #[inline]
+    fn from(a: [f32; 3]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<Vec3> for [f32; 3] {
+    #[inline]
+    fn from(v: Vec3) -> Self {
+        v.to_array()
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Vec3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Vec3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<f32> for Vec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl MulAssign<f32> for Vec3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<f32> for Vec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f32) -> Self {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// A rotation. Only unit quaternions are valid rotations, which `Default` respects by
+/// being the identity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "spacetime", derive(spacetimedb_sats::SpacetimeType))]
+#[cfg_attr(feature = "spacetime", sats(crate = spacetimedb_sats))]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Default for Quat {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Quat {
+    /// No rotation at all.
+    pub const IDENTITY: Self = Self {
+        x: 0.,
+        y: 0.,
+        z: 0.,
+        w: 1.,
+    };
+
+    /// A rotation of `yaw` radians around the Y axis. See `facing` for which way a yaw
+    /// faces.
+    #[inline]
+    #[must_use]
+    pub fn from_yaw(yaw: f32) -> Self {
+        glam::Quat::from_rotation_y(yaw).into()
+    }
+
+    /// The rotation around the Y axis, ignoring any pitch and roll.
+    #[must_use]
+    pub fn yaw(&self) -> f32 {
+        (2. * (self.w * self.y + self.x * self.z))
+            .atan2(1. - 2. * (self.x * self.x + self.y * self.y))
+    }
+
+    /// Spherically interpolates between `self` and `end`, the short way around. `t` of
+    /// zero is `self` and one is `end`.
+    #[inline]
+    #[must_use]
+    pub fn slerp(self, end: Self, t: f32) -> Self {
+        glam::Quat::from(self)
+            .slerp(glam::Quat::from(end), t)
+            .into()
+    }
+
+    /// Converts `self` to `[x, y, z, w]`
+    #[inline]
+    #[must_use]
+    pub fn to_array(&self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+}
+
+impl From<glam::Quat> for Quat {
+    #[inline]
+    fn from(q: glam::Quat) -> Self {
+        Self {
+            x: q.x,
+            y: q.y,
+            z: q.z,
+            w: q.w,
+        }
+    }
+}
+
+impl From<Quat> for glam::Quat {
+    #[inline]
+    fn from(q: Quat) -> Self {
+        glam::Quat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+/// Composes rotations, `self` applied after `rhs`.
+impl Mul for Quat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        (glam::Quat::from(self) * glam::Quat::from(rhs)).into()
+    }
+}
+
+/// Rotates a vector.
+impl Mul<Vec3> for Quat {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        (glam::Quat::from(self) * glam::Vec3::from(rhs)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::facing;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn assert_close(a: Vec3, b: Vec3) {
+        assert!((a - b).length_squared() < 1e-10, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn glam_round_trip_is_lossless() {
+        let v = Vec3::new(1.5, -0.1, 1e-7);
+        assert_eq!(Vec3::from(glam::Vec3::from(v)), v);
+        let q = Quat::from_yaw(0.3);
+        assert_eq!(Quat::from(glam::Quat::from(q)), q);
+    }
+
+    #[test]
+    fn operators() {
+        let a = Vec3::new(1., 2., 3.);
+        let b = Vec3::new(4., 5., 6.);
+        assert_eq!(a + b, Vec3::new(5., 7., 9.));
+        assert_eq!(b - a, Vec3::new(3., 3., 3.));
+        assert_eq!(a * 2., Vec3::new(2., 4., 6.));
+        assert_eq!(b / 2., Vec3::new(2., 2.5, 3.));
+        assert_eq!(-a, Vec3::new(-1., -2., -3.));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        c *= 0.5;
+        assert_eq!(c, b * 0.5);
+    }
+
+    #[test]
+    fn distance_ignores_height() {
+        let a = Vec3::new(0., 10., 0.);
+        let b = Vec3::new(3., -5., 4.);
+        assert_eq!(a.distance_squared_xz(b), 25.);
+    }
+
+    #[test]
+    fn default_rotation_is_identity() {
+        let v = Vec3::new(1., 2., 3.);
+        assert_eq!(Quat::default() * v, v);
+        assert_eq!(Quat::default().yaw(), 0.);
+    }
+
+    #[test]
+    fn yaw_faces_along_facing_forward() {
+        let yaw = 0.7;
+        let q = Quat::from_yaw(yaw);
+        assert!((q.yaw() - yaw).abs() < 1e-6);
+
+        let [x, z] = facing::forward(yaw);
+        assert_close(q * Vec3::new(0., 0., -1.), Vec3::new(x, 0., z));
+    }
+
+    #[test]
+    fn slerp_halfway() {
+        let q = Quat::IDENTITY.slerp(Quat::from_yaw(FRAC_PI_2), 0.5);
+        assert!((q.yaw() - FRAC_PI_2 / 2.).abs() < 1e-6);
+        let composed = Quat::from_yaw(FRAC_PI_2) * Quat::from_yaw(FRAC_PI_2);
+        assert_close(composed * Vec3::new(1., 0., 0.), Vec3::new(-1., 0., 0.));
+    }
+}
@@ -0,0 +1,31 @@
+//! Finding the entities inside an area of effect.
+//!
+//! Candidates are narrowed down with range scans over the `(map_id, chunk_id)` index
+//! on `transform`, one per row of chunks the area can touch, before their positions are
+//! checked exactly. See `common/benches/area.rs` for how this compares to scanning
+//! every transform.
+
+use crate::{entity, transform};
+use common::area::Area;
+use spacetimedb::ReducerContext;
+
+/// Every entity on `map_id` whose position is inside `area`.
+pub(crate) fn entities_in_area(ctx: &ReducerContext, map_id: u32, area: &Area) -> Vec<u32> {
+    let mut entity_ids = Vec::new();
+    for range in area.chunk_id_ranges() {
+        for transform in ctx.db.transform().map_chunk().filter((map_id, range)) {
+            if !area.contains(transform.translation.xz()) {
+                continue;
+            }
+            // Transforms of characters that aren't in the world have no entity
+            entity_ids.extend(
+                ctx.db
+                    .entity()
+                    .transform_id()
+                    .filter(transform.id)
+                    .map(|entity| entity.id),
+            );
+        }
+    }
+    entity_ids
+}
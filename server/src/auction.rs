@@ -0,0 +1,400 @@
+//! The auction house, a market between players.
+//!
+//! Listed items leave the seller's inventory and the highest bid leaves the bidder's
+//! balance, both held in escrow on the listing until it sells or expires. Everything
+//! that comes out of escrow is delivered by mail, so the auction house never needs the
+//! other party to be online or to have room in their inventory.
+//!
+//! Listings copy the type and level of their item into indexed columns. Clients browse
+//! by subscribing to `auction_listing` filtered on those columns rather than pulling in
+//! every listing.
+
+use crate::{
+    character::character_pawn,
+    currency::{self, CurrencyReason},
+    inventory::{self, item_def},
+    mail::{self, MailItem},
+};
+use spacetimedb::{ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp, reducer, table};
+
+/// The share of the sale price the auction house keeps.
+const AUCTION_CUT_PERCENT: u64 = 5;
+/// How many listings a character can have up at once.
+const MAX_LISTINGS: usize = 50;
+/// The durations a listing can run for.
+const LISTING_HOURS: [u8; 3] = [12, 24, 48];
+/// The name auction house mail is sent under.
+const AUCTION_HOUSE: &str = "Auction House";
+
+#[table(
+    name = auction_listing,
+    public,
+    index(name = type_and_level, btree(columns = [item_type_id, item_level]))
+)]
+pub struct AuctionListing {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub seller_character_id: u32,
+
+    #[index(btree)]
+    pub item_def_id: u32,
+
+    /// Copied from the item definition so listings can be searched by them
+    pub item_type_id: u32,
+    #[index(btree)]
+    pub item_level: u8,
+
+    pub quantity: u16,
+
+    /// The lowest first bid accepted
+    pub start_price: u64,
+    pub buyout: Option<u64>,
+
+    /// The highest bid so far, held in escrow
+    pub bid: u64,
+    pub bidder_character_id: Option<u32>,
+
+    pub listed_at: Timestamp,
+    pub expires_at: Timestamp,
+}
+
+#[table(name = auction_expiry_timer, scheduled(auction_expire))]
+struct AuctionExpiryTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+
+    #[index(btree)]
+    listing_id: u32,
+}
+
+fn sender_character_id(ctx: &ReducerContext, reducer: &str) -> Result<u32, String> {
+    match ctx.db.character_pawn().identity().find(ctx.sender) {
+        Some(pawn) => Ok(pawn.character_id),
+        None => {
+            let err = format!("{reducer}: unable to find character pawn for sender.");
+            log::warn!("{err}");
+            Err(err)
+        }
+    }
+}
+
+fn delete_listing(ctx: &ReducerContext, listing_id: u32) {
+    ctx.db.auction_listing().id().delete(listing_id);
+    ctx.db
+        .auction_expiry_timer()
+        .listing_id()
+        .delete(listing_id);
+}
+
+/// Mails the listed items to the winner and the sale price, less the auction house's
+/// cut, to the seller.
+fn complete_sale(
+    ctx: &ReducerContext,
+    listing: &AuctionListing,
+    buyer_character_id: u32,
+    price: u64,
+) {
+    let item_name = ctx
+        .db
+        .item_def()
+        .id()
+        .find(listing.item_def_id)
+        .map(|item_def| item_def.name)
+        .unwrap_or_default();
+
+    mail::send_system_mail(
+        ctx,
+        buyer_character_id,
+        AUCTION_HOUSE,
+        format!("Auction won: {item_name}"),
+        vec![MailItem {
+            item_def_id: listing.item_def_id,
+            quantity: listing.quantity,
+        }],
+        0,
+    );
+
+    // Computed wide so a huge price can't overflow
+    let cut = (price as u128 * AUCTION_CUT_PERCENT as u128 / 100) as u64;
+    mail::send_system_mail(
+        ctx,
+        listing.seller_character_id,
+        AUCTION_HOUSE,
+        format!("Auction sold: {item_name}"),
+        Vec::new(),
+        price - cut,
+    );
+}
+
+/// Returns the current highest bid to its bidder.
+fn refund_bid(ctx: &ReducerContext, listing: &AuctionListing) {
+    let Some(bidder_character_id) = listing.bidder_character_id else {
+        return;
+    };
+    let item_name = ctx
+        .db
+        .item_def()
+        .id()
+        .find(listing.item_def_id)
+        .map(|item_def| item_def.name)
+        .unwrap_or_default();
+
+    mail::send_system_mail(
+        ctx,
+        bidder_character_id,
+        AUCTION_HOUSE,
+        format!("Outbid: {item_name}"),
+        Vec::new(),
+        listing.bid,
+    );
+}
+
+/// Takes down a deleted character's listings, refunding their bidders. The escrowed
+/// items were the character's own and are destroyed along with its inventory, which is
+/// logged so they can be accounted for. Runs before `mail::delete_for`, so the
+/// character's mail is the last thing cleared.
+pub(crate) fn delete_for(ctx: &ReducerContext, character_id: u32) {
+    let listings: Vec<AuctionListing> = ctx
+        .db
+        .auction_listing()
+        .seller_character_id()
+        .filter(character_id)
+        .collect();
+    for listing in listings {
+        refund_bid(ctx, &listing);
+        log::info!(
+            "Destroyed {} of item {} escrowed on listing {} of deleted character {character_id}.",
+            listing.quantity,
+            listing.item_def_id,
+            listing.id
+        );
+        delete_listing(ctx, listing.id);
+    }
+}
+
+/// Lists part or all of an inventory stack on the auction house.
+#[reducer]
+pub fn create_auction(
+    ctx: &ReducerContext,
+    inventory_item_id: u32,
+    quantity: u16,
+    start_price: u64,
+    buyout: Option<u64>,
+    duration_hours: u8,
+) -> Result<(), String> {
+    let character_id = sender_character_id(ctx, "create_auction")?;
+
+    if !LISTING_HOURS.contains(&duration_hours) {
+        let err = format!("create_auction: invalid duration.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if start_price == 0 || buyout.is_some_and(|buyout| buyout < start_price) {
+        let err = format!("create_auction: invalid price.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if ctx
+        .db
+        .auction_listing()
+        .seller_character_id()
+        .filter(character_id)
+        .count()
+        >= MAX_LISTINGS
+    {
+        let err = format!("create_auction: too many listings.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    let item_def_id = inventory::remove_item(ctx, character_id, inventory_item_id, quantity)
+        .map_err(|err| format!("create_auction: {err}"))?;
+    let Some(item_def) = ctx.db.item_def().id().find(item_def_id) else {
+        let err = format!("create_auction: invalid item.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    let expires_at =
+        ctx.timestamp + TimeDuration::from_micros(duration_hours as i64 * 60 * 60 * 1_000_000);
+    let listing = ctx.db.auction_listing().insert(AuctionListing {
+        id: 0,
+        seller_character_id: character_id,
+        item_def_id,
+        item_type_id: item_def.item_type_id,
+        item_level: item_def.level,
+        quantity,
+        start_price,
+        buyout,
+        bid: 0,
+        bidder_character_id: None,
+        listed_at: ctx.timestamp,
+        expires_at,
+    });
+    ctx.db.auction_expiry_timer().insert(AuctionExpiryTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(expires_at),
+        listing_id: listing.id,
+    });
+
+    Ok(())
+}
+
+/// Places a bid above the current one. The previous bidder is refunded by mail.
+#[reducer]
+pub fn bid_auction(ctx: &ReducerContext, listing_id: u32, amount: u64) -> Result<(), String> {
+    let character_id = sender_character_id(ctx, "bid_auction")?;
+    let Some(mut listing) = ctx.db.auction_listing().id().find(listing_id) else {
+        let err = format!("bid_auction: listing not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if listing.seller_character_id == character_id {
+        let err = format!("bid_auction: cannot bid on your own listing.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if listing.bidder_character_id == Some(character_id) {
+        let err = format!("bid_auction: already the highest bidder.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if amount < listing.start_price || amount <= listing.bid {
+        let err = format!("bid_auction: bid is too low.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if listing.buyout.is_some_and(|buyout| amount >= buyout) {
+        let err = format!("bid_auction: bid meets the buyout price, buy it out instead.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    currency::withdraw(
+        ctx,
+        character_id,
+        amount,
+        CurrencyReason::AuctionBid(listing.id),
+    )
+    .map_err(|err| format!("bid_auction: {err}"))?;
+    refund_bid(ctx, &listing);
+
+    listing.bid = amount;
+    listing.bidder_character_id = Some(character_id);
+    ctx.db.auction_listing().id().update(listing);
+
+    Ok(())
+}
+
+/// Buys a listing outright at its buyout price.
+#[reducer]
+pub fn buyout_auction(ctx: &ReducerContext, listing_id: u32) -> Result<(), String> {
+    let character_id = sender_character_id(ctx, "buyout_auction")?;
+    let Some(listing) = ctx.db.auction_listing().id().find(listing_id) else {
+        let err = format!("buyout_auction: listing not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(buyout) = listing.buyout else {
+        let err = format!("buyout_auction: listing has no buyout price.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if listing.seller_character_id == character_id {
+        let err = format!("buyout_auction: cannot buy your own listing.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    currency::withdraw(
+        ctx,
+        character_id,
+        buyout,
+        CurrencyReason::AuctionBuyout(listing.id),
+    )
+    .map_err(|err| format!("buyout_auction: {err}"))?;
+    refund_bid(ctx, &listing);
+    complete_sale(ctx, &listing, character_id, buyout);
+    delete_listing(ctx, listing.id);
+
+    Ok(())
+}
+
+/// Takes down a listing nobody has bid on, mailing the items back to the seller.
+#[reducer]
+pub fn cancel_auction(ctx: &ReducerContext, listing_id: u32) -> Result<(), String> {
+    let character_id = sender_character_id(ctx, "cancel_auction")?;
+    let Some(listing) = ctx
+        .db
+        .auction_listing()
+        .id()
+        .find(listing_id)
+        .filter(|listing| listing.seller_character_id == character_id)
+    else {
+        let err = format!("cancel_auction: listing not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if listing.bidder_character_id.is_some() {
+        let err = format!("cancel_auction: listing already has bids.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    mail::send_system_mail(
+        ctx,
+        character_id,
+        AUCTION_HOUSE,
+        "Auction cancelled".into(),
+        vec![MailItem {
+            item_def_id: listing.item_def_id,
+            quantity: listing.quantity,
+        }],
+        0,
+    );
+    delete_listing(ctx, listing.id);
+
+    Ok(())
+}
+
+/// Ends a listing, selling it to the highest bidder or returning it to the seller.
+#[reducer]
+fn auction_expire(ctx: &ReducerContext, timer: AuctionExpiryTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`auction_expire` may not be invoked by clients.".into());
+    }
+
+    let Some(listing) = ctx.db.auction_listing().id().find(timer.listing_id) else {
+        return Ok(());
+    };
+
+    match listing.bidder_character_id {
+        Some(bidder_character_id) => {
+            complete_sale(ctx, &listing, bidder_character_id, listing.bid);
+        }
+        None => {
+            mail::send_system_mail(
+                ctx,
+                listing.seller_character_id,
+                AUCTION_HOUSE,
+                "Auction expired".into(),
+                vec![MailItem {
+                    item_def_id: listing.item_def_id,
+                    quantity: listing.quantity,
+                }],
+                0,
+            );
+        }
+    }
+    delete_listing(ctx, listing.id);
+
+    Ok(())
+}
@@ -1,6 +1,16 @@
 use crate::{
-    Health, Mana, Transform, class, health, mana, race, transform,
+    Health, Mana, Transform, auction, class, currency, guild, health,
+    inventory::inventory_item,
+    lore::{lore_collection_completed, lore_unlocked},
+    mail, mana,
+    map::OVERWORLD_MAP_ID,
+    profession::profession_skill,
+    progression::{Xp, xp},
+    quest::quest_progress,
+    race, transform,
+    travel::{hearth, teleport_cooldown, waypoint_discovered},
     types::{Quat, Vec3},
+    vendor::vendor_buyback,
 };
 use common::chunk;
 use spacetimedb::{
@@ -43,6 +53,9 @@ pub struct CharacterDef {
 
     #[index(btree)]
     pub mana_id: u32,
+
+    #[index(btree)]
+    pub xp_id: u32,
 }
 
 /// A type-narrowing table for in-game entities that are specifically player-controlled characters.
@@ -63,6 +76,15 @@ pub struct CharacterPawn {
     /// The reference to the generic in-game entity for this character
     #[index(btree)]
     pub entity_id: u32,
+
+    /// Copied from the character so that visibility filters don't need `character_def`
+    pub health_id: u32,
+    pub mana_id: u32,
+
+    /// The map the character is on, copied from its transform so that visibility filters
+    /// can scope rows to it. Kept in sync by `map::transfer_entity`.
+    #[index(btree)]
+    pub map_id: u32,
 }
 
 #[derive(SpacetimeType)]
@@ -132,10 +154,13 @@ pub fn create_character(ctx: &ReducerContext, input: CreateCharacterInput) -> Re
         translation: translation,
         rotation: Quat::default(),
         scale: Vec3::default(),
+        map_id: OVERWORLD_MAP_ID,
         chunk_id: chunk_id,
+        stealthed: false,
     });
     let health = ctx.db.health().insert(Health::new(100));
     let mana = ctx.db.mana().insert(Mana::new(100));
+    let xp = ctx.db.xp().insert(Xp { id: 0, xp: 0 });
     ctx.db.character_def().insert(CharacterDef {
         id: 0,
         name: trimmed_name.into(),
@@ -145,6 +170,7 @@ pub fn create_character(ctx: &ReducerContext, input: CreateCharacterInput) -> Re
         class_id: input.class_id,
         health_id: health.id,
         mana_id: mana.id,
+        xp_id: xp.id,
     });
 
     Ok(())
@@ -182,6 +208,31 @@ pub fn delete_character(ctx: &ReducerContext, character_id: u32) -> Result<(), S
         return Err(format!("Cannot delete a character in game."));
     }
 
+    guild::remove_member(ctx, character.id);
+    ctx.db.inventory_item().character_id().delete(character.id);
+    currency::close(ctx, character.id);
+    ctx.db.quest_progress().character_id().delete(character.id);
+    ctx.db.lore_unlocked().character_id().delete(character.id);
+    ctx.db
+        .lore_collection_completed()
+        .character_id()
+        .delete(character.id);
+    ctx.db.vendor_buyback().character_id().delete(character.id);
+    ctx.db
+        .profession_skill()
+        .character_id()
+        .delete(character.id);
+    ctx.db
+        .waypoint_discovered()
+        .character_id()
+        .delete(character.id);
+    ctx.db.hearth().character_id().delete(character.id);
+    ctx.db
+        .teleport_cooldown()
+        .character_id()
+        .delete(character.id);
+    auction::delete_for(ctx, character.id);
+    mail::delete_for(ctx, character.id);
     ctx.db.character_def().delete(character);
 
     Ok(())
@@ -0,0 +1,114 @@
+//! Static world geometry that blocks line of sight.
+//!
+//! Every shape is stored once for each chunk it overlaps, so a line of sight check only
+//! looks up the chunks its ray passes through on the map it is made on. The table is
+//! public and clients run the same checks through `common::line_of_sight` to highlight
+//! what they can target.
+
+use crate::{entity_location, types::Vec3};
+use common::line_of_sight::{self, Shape};
+use spacetimedb::{ReducerContext, SpacetimeType, Table, table};
+
+#[derive(SpacetimeType, Clone, Copy)]
+pub struct CollisionCircle {
+    pub x: f32,
+    pub z: f32,
+    pub radius: f32,
+}
+
+/// An axis aligned rectangle.
+#[derive(SpacetimeType, Clone, Copy)]
+pub struct CollisionRect {
+    pub min_x: f32,
+    pub min_z: f32,
+    pub max_x: f32,
+    pub max_z: f32,
+}
+
+#[derive(SpacetimeType, Clone, Copy)]
+pub enum CollisionShapeKind {
+    Circle(CollisionCircle),
+    Rect(CollisionRect),
+}
+
+impl CollisionShapeKind {
+    pub fn to_shape(self) -> Shape {
+        match self {
+            CollisionShapeKind::Circle(circle) => Shape::Circle {
+                center: [circle.x, circle.z],
+                radius: circle.radius,
+            },
+            CollisionShapeKind::Rect(rect) => Shape::Rect {
+                min: [rect.min_x, rect.min_z],
+                max: [rect.max_x, rect.max_z],
+            },
+        }
+    }
+}
+
+/// A static blocker, bucketed into one of the chunks it overlaps.
+#[table(
+    name = collision_shape,
+    public,
+    index(name = map_chunk, btree(columns = [map_id, chunk_id]))
+)]
+pub struct CollisionShape {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    /// Shared by every bucket of the same shape
+    pub shape_id: u32,
+
+    pub map_id: u32,
+
+    pub chunk_id: u32,
+
+    pub kind: CollisionShapeKind,
+}
+
+/// Adds a shape to the bucket of every chunk it overlaps.
+pub(crate) fn insert_shape(
+    ctx: &ReducerContext,
+    map_id: u32,
+    shape_id: u32,
+    kind: CollisionShapeKind,
+) {
+    for chunk_id in kind.to_shape().chunk_ids() {
+        ctx.db.collision_shape().insert(CollisionShape {
+            id: 0,
+            shape_id,
+            map_id,
+            chunk_id,
+            kind,
+        });
+    }
+}
+
+/// Whether no static geometry on `map_id` blocks the way from `from` to `to`.
+pub(crate) fn line_of_sight(ctx: &ReducerContext, map_id: u32, from: Vec3, to: Vec3) -> bool {
+    let (from, to) = (from.xz(), to.xz());
+    line_of_sight::chunks_along_ray(from, to)
+        .into_iter()
+        .all(|chunk_id| {
+            ctx.db
+                .collision_shape()
+                .map_chunk()
+                .filter((map_id, chunk_id))
+                .all(|row| row.kind.to_shape().ray_hit(from, to).is_none())
+        })
+}
+
+/// Whether two entities can see each other. Entities that can't be found, or are on
+/// different maps, can't be seen.
+pub(crate) fn entities_in_sight(ctx: &ReducerContext, a_entity_id: u32, b_entity_id: u32) -> bool {
+    match (
+        entity_location(ctx, a_entity_id),
+        entity_location(ctx, b_entity_id),
+    ) {
+        (Some((a_map_id, a)), Some((b_map_id, b))) => {
+            a_map_id == b_map_id && line_of_sight(ctx, a_map_id, a, b)
+        }
+        _ => false,
+    }
+}
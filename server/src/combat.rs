@@ -0,0 +1,159 @@
+//! Damage and healing shared by everything that hurts or mends an entity.
+
+use crate::{
+    character::{character_def, character_pawn},
+    effect::{self, Stat},
+    entity, entity_location, health, mount,
+    npc::{self, npc},
+    stealth,
+    summon::{self, owned_entity},
+    threat, transform, travel,
+};
+use spacetimedb::ReducerContext;
+
+/// Whether an entity faces another closely enough to act on it, within `half_angle`
+/// radians to either side. Entities on different maps never face each other.
+pub(crate) fn facing(
+    ctx: &ReducerContext,
+    entity_id: u32,
+    target_entity_id: u32,
+    half_angle: f32,
+) -> bool {
+    let Some(transform) = ctx
+        .db
+        .entity()
+        .id()
+        .find(entity_id)
+        .and_then(|entity| ctx.db.transform().id().find(entity.transform_id))
+    else {
+        return false;
+    };
+    match entity_location(ctx, target_entity_id) {
+        Some((map_id, target)) if map_id == transform.map_id => common::facing::in_facing_cone(
+            transform.translation.xz(),
+            transform.rotation.yaw(),
+            target.xz(),
+            half_angle,
+        ),
+        _ => false,
+    }
+}
+
+/// Finds the health row of a character, NPC or summon entity.
+pub(crate) fn entity_health_id(ctx: &ReducerContext, entity_id: u32) -> Option<u32> {
+    if let Some(npc) = ctx.db.npc().entity_id().find(entity_id) {
+        return Some(npc.health_id);
+    }
+    if let Some(owned) = ctx.db.owned_entity().entity_id().find(entity_id) {
+        return Some(owned.health_id);
+    }
+    let pawn = ctx
+        .db
+        .character_pawn()
+        .entity_id()
+        .filter(entity_id)
+        .next()?;
+    ctx.db
+        .character_def()
+        .id()
+        .find(pawn.character_id)
+        .map(|character| character.health_id)
+}
+
+/// The character controlling an entity, if it is a player's pawn or something a
+/// player's pawn summoned.
+pub(crate) fn entity_character_id(ctx: &ReducerContext, entity_id: u32) -> Option<u32> {
+    let entity_id = summon::owner_of(ctx, entity_id).unwrap_or(entity_id);
+    ctx.db
+        .character_pawn()
+        .entity_id()
+        .filter(entity_id)
+        .next()
+        .map(|pawn| pawn.character_id)
+}
+
+/// Whether two entities fight each other. Hostile NPCs fight everyone else, while
+/// characters, their summons and friendly NPCs are all on the same side.
+pub(crate) fn hostile(ctx: &ReducerContext, a_entity_id: u32, b_entity_id: u32) -> bool {
+    let npc = |entity_id| ctx.db.npc().entity_id().find(entity_id);
+    match (npc(a_entity_id), npc(b_entity_id)) {
+        (Some(npc), None) | (None, Some(npc)) => npc::is_hostile(ctx, &npc),
+        _ => false,
+    }
+}
+
+/// Deals damage to an entity, scaled by its damage taken modifiers. Damage to an NPC
+/// raises its threat towards the source, and NPCs brought down are killed, crediting
+/// the character behind the source if there is one. Summons brought down are dismissed.
+/// Evading NPCs ignore all damage.
+/// Dealing or taking damage breaks stealth and dismounts, and taking it interrupts a
+/// teleport.
+pub(crate) fn apply_damage(
+    ctx: &ReducerContext,
+    target_entity_id: u32,
+    source_entity_id: Option<u32>,
+    amount: u16,
+) {
+    if let Some(source_entity_id) = source_entity_id {
+        stealth::break_stealth(ctx, source_entity_id);
+        mount::dismount_entity(ctx, source_entity_id);
+    }
+    let Some(mut health) =
+        entity_health_id(ctx, target_entity_id).and_then(|id| ctx.db.health().id().find(id))
+    else {
+        return;
+    };
+    if health.health == 0 {
+        return;
+    }
+    if let Some(npc) = ctx.db.npc().entity_id().find(target_entity_id) {
+        if npc.evading {
+            return;
+        }
+        if let Some(source_entity_id) = source_entity_id {
+            threat::add_threat(ctx, target_entity_id, source_entity_id, amount as u32);
+        }
+    }
+    stealth::break_stealth(ctx, target_entity_id);
+    mount::dismount_entity(ctx, target_entity_id);
+    travel::interrupt(ctx, target_entity_id);
+
+    let scaled = amount as f32 * effect::stat_multiplier(ctx, target_entity_id, Stat::DamageTaken);
+    let remaining = health.health.saturating_sub(scaled.round() as u16);
+    health.update(remaining);
+    ctx.db.health().id().update(health);
+
+    if remaining > 0 {
+        return;
+    }
+    effect::clear(ctx, target_entity_id);
+    let killer_character_id = source_entity_id.and_then(|source| entity_character_id(ctx, source));
+    npc::kill(ctx, target_entity_id, killer_character_id);
+    summon::despawn(ctx, target_entity_id);
+}
+
+/// Heals an entity, up to its max health. The dead can't be healed. Healing draws the
+/// attention of NPCs fighting the target to the source.
+pub(crate) fn apply_heal(
+    ctx: &ReducerContext,
+    target_entity_id: u32,
+    source_entity_id: Option<u32>,
+    amount: u16,
+) {
+    let Some(mut health) =
+        entity_health_id(ctx, target_entity_id).and_then(|id| ctx.db.health().id().find(id))
+    else {
+        return;
+    };
+    if health.health == 0 {
+        return;
+    }
+
+    let healed = health.health.saturating_add(amount);
+    health.update(healed);
+    ctx.db.health().id().update(health);
+
+    if let Some(source_entity_id) = source_entity_id {
+        threat::add_heal_threat(ctx, source_entity_id, target_entity_id, amount);
+    }
+}
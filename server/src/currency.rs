@@ -0,0 +1,171 @@
+//! Character currency balances.
+//!
+//! Balances must only ever be changed through `deposit`, `withdraw` and `close`, which
+//! record every change in the `currency_ledger` so duplication bugs can be traced back
+//! to the reducer calls that caused them.
+
+use spacetimedb::{
+    Filter, ReducerContext, SpacetimeType, Table, Timestamp, client_visibility_filter, table,
+};
+
+#[client_visibility_filter]
+const CURRENCY_SECURITY: Filter = Filter::Sql(
+    "SELECT currency.* FROM currency JOIN character_def ON currency.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+/// The currency balance of a character, stored in the smallest denomination.
+#[table(name = currency, public)]
+pub struct Currency {
+    #[primary_key]
+    pub character_id: u32,
+
+    pub amount: u64,
+}
+
+/// Why a character's balance changed.
+#[derive(SpacetimeType, Clone, Copy)]
+pub enum CurrencyReason {
+    /// Traded with the given character
+    Trade(u32),
+    /// Bought an item from the given vendor
+    VendorBuy(u32),
+    /// Sold an item to the given vendor
+    VendorSell(u32),
+    /// Bought back a sold item from the given vendor
+    VendorBuyback(u32),
+    /// Attached currency to the given mail
+    MailSent(u32),
+    /// Took currency attached to the given mail
+    MailReceived(u32),
+    /// Paid cash on delivery for the given mail
+    MailCod(u32),
+    /// Bid on the given auction listing
+    AuctionBid(u32),
+    /// Bought out the given auction listing
+    AuctionBuyout(u32),
+    /// The character was deleted along with what was left of its balance
+    CharacterDeleted,
+}
+
+/// A single change to a character's balance. Rows are never updated or deleted, not
+/// even when the character is, so the ledger stays a complete history.
+#[table(name = currency_ledger)]
+pub struct CurrencyLedger {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub character_id: u32,
+
+    pub credit: u64,
+    pub debit: u64,
+
+    /// The balance after the change
+    pub balance: u64,
+
+    pub reason: CurrencyReason,
+    pub created_at: Timestamp,
+}
+
+/// The current balance of a character, zero if it has never held any currency.
+pub(crate) fn balance(ctx: &ReducerContext, character_id: u32) -> u64 {
+    ctx.db
+        .currency()
+        .character_id()
+        .find(character_id)
+        .map_or(0, |row| row.amount)
+}
+
+fn record(
+    ctx: &ReducerContext,
+    character_id: u32,
+    credit: u64,
+    debit: u64,
+    balance: u64,
+    reason: CurrencyReason,
+) {
+    ctx.db.currency_ledger().insert(CurrencyLedger {
+        id: 0,
+        character_id,
+        credit,
+        debit,
+        balance,
+        reason,
+        created_at: ctx.timestamp,
+    });
+}
+
+/// Adds `amount` to a character's balance, failing instead of wrapping on overflow.
+pub(crate) fn deposit(
+    ctx: &ReducerContext,
+    character_id: u32,
+    amount: u64,
+    reason: CurrencyReason,
+) -> Result<(), String> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let balance = match ctx.db.currency().character_id().find(character_id) {
+        Some(mut row) => {
+            row.amount = row
+                .amount
+                .checked_add(amount)
+                .ok_or_else(|| format!("Currency balance would overflow."))?;
+            ctx.db.currency().character_id().update(row).amount
+        }
+        None => {
+            ctx.db
+                .currency()
+                .insert(Currency {
+                    character_id,
+                    amount,
+                })
+                .amount
+        }
+    };
+    record(ctx, character_id, amount, 0, balance, reason);
+
+    Ok(())
+}
+
+/// Removes `amount` from a character's balance, failing if the balance is too low.
+pub(crate) fn withdraw(
+    ctx: &ReducerContext,
+    character_id: u32,
+    amount: u64,
+    reason: CurrencyReason,
+) -> Result<(), String> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let Some(mut row) = ctx.db.currency().character_id().find(character_id) else {
+        return Err(format!("Not enough currency."));
+    };
+    row.amount = row
+        .amount
+        .checked_sub(amount)
+        .ok_or_else(|| format!("Not enough currency."))?;
+    let balance = ctx.db.currency().character_id().update(row).amount;
+    record(ctx, character_id, 0, amount, balance, reason);
+
+    Ok(())
+}
+
+/// Deletes a character's balance, recording what was left in it as a final debit.
+pub(crate) fn close(ctx: &ReducerContext, character_id: u32) {
+    let Some(row) = ctx.db.currency().character_id().find(character_id) else {
+        return;
+    };
+    record(
+        ctx,
+        character_id,
+        0,
+        row.amount,
+        0,
+        CurrencyReason::CharacterDeleted,
+    );
+    ctx.db.currency().character_id().delete(character_id);
+}
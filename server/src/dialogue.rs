@@ -0,0 +1,297 @@
+//! Conversations with NPCs, driven entirely by seeded data.
+//!
+//! Every NPC with something to say has an entry `dialogue_node`. Each node offers a list
+//! of `dialogue_choice`s which may be gated by conditions on the character, may run
+//! actions when picked, and lead either to another node or out of the conversation.
+//!
+//! The choices a character may pick from are computed on the server when entering a
+//! node and stored on its `dialogue_session`, so clients never need to evaluate
+//! conditions themselves.
+
+use crate::{
+    character::{character_def, character_pawn},
+    effect, inventory, movement, npc, progression,
+    quest::{self, QuestState},
+    types::Vec3,
+};
+use spacetimedb::{
+    Filter, ReducerContext, SpacetimeType, Table, client_visibility_filter, reducer, table,
+};
+
+#[client_visibility_filter]
+const DIALOGUE_SESSION_SECURITY: Filter = Filter::Sql(
+    "SELECT dialogue_session.* FROM dialogue_session JOIN character_def ON dialogue_session.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+/// The state a character's quest needs to be in, `None` meaning never accepted.
+#[derive(SpacetimeType, Clone, Copy)]
+pub struct QuestCondition {
+    pub quest_def_id: u32,
+    pub state: Option<QuestState>,
+}
+
+#[derive(SpacetimeType, Clone, Copy)]
+pub enum DialogueCondition {
+    Quest(QuestCondition),
+    Race(u32),
+    Class(u32),
+    MinLevel(u8),
+    MaxLevel(u8),
+}
+
+#[derive(SpacetimeType, Clone, Copy)]
+pub struct ItemGrant {
+    pub item_def_id: u32,
+    pub quantity: u16,
+}
+
+#[derive(SpacetimeType, Clone, Copy)]
+pub enum DialogueAction {
+    StartQuest(u32),
+    GiveItem(ItemGrant),
+    Teleport(Vec3),
+    ApplyEffect(u32),
+}
+
+/// Something an NPC says.
+#[table(name = dialogue_node, public)]
+pub struct DialogueNode {
+    #[primary_key]
+    pub id: u32,
+
+    #[index(btree)]
+    pub npc_def_id: u32,
+
+    pub text: String,
+
+    /// Whether conversations with the NPC start at this node
+    pub entry: bool,
+}
+
+/// A reply the character can give at a node.
+#[table(name = dialogue_choice, public)]
+pub struct DialogueChoice {
+    #[primary_key]
+    pub id: u32,
+
+    #[index(btree)]
+    pub node_id: u32,
+
+    pub text: String,
+
+    /// All of these have to hold for the choice to be offered
+    pub conditions: Vec<DialogueCondition>,
+
+    /// Run in order when the choice is picked
+    pub actions: Vec<DialogueAction>,
+
+    /// Where the conversation goes next, `None` ending it
+    pub next_node_id: Option<u32>,
+}
+
+/// A character's ongoing conversation. A character only talks to one NPC at a time.
+#[table(name = dialogue_session, public)]
+pub struct DialogueSession {
+    #[primary_key]
+    pub character_id: u32,
+
+    pub npc_entity_id: u32,
+    pub node_id: u32,
+
+    /// The choices at the current node whose conditions the character meets
+    pub choice_ids: Vec<u32>,
+}
+
+fn condition_met(ctx: &ReducerContext, character_id: u32, condition: &DialogueCondition) -> bool {
+    match condition {
+        DialogueCondition::Quest(quest) => {
+            quest::quest_state(ctx, character_id, quest.quest_def_id) == quest.state
+        }
+        DialogueCondition::Race(race_id) => ctx
+            .db
+            .character_def()
+            .id()
+            .find(character_id)
+            .is_some_and(|character| character.race_id == *race_id),
+        DialogueCondition::Class(class_id) => ctx
+            .db
+            .character_def()
+            .id()
+            .find(character_id)
+            .is_some_and(|character| character.class_id == *class_id),
+        DialogueCondition::MinLevel(level) => {
+            progression::character_level(ctx, character_id) >= *level
+        }
+        DialogueCondition::MaxLevel(level) => {
+            progression::character_level(ctx, character_id) <= *level
+        }
+    }
+}
+
+/// Moves a character's conversation to `node_id`, or ends it when there is no next node.
+fn enter_node(ctx: &ReducerContext, character_id: u32, npc_entity_id: u32, node_id: Option<u32>) {
+    let Some(node_id) = node_id else {
+        ctx.db
+            .dialogue_session()
+            .character_id()
+            .delete(character_id);
+        return;
+    };
+
+    let mut choices: Vec<DialogueChoice> = ctx
+        .db
+        .dialogue_choice()
+        .node_id()
+        .filter(node_id)
+        .filter(|choice| {
+            choice
+                .conditions
+                .iter()
+                .all(|condition| condition_met(ctx, character_id, condition))
+        })
+        .collect();
+    choices.sort_by_key(|choice| choice.id);
+
+    let session = DialogueSession {
+        character_id,
+        npc_entity_id,
+        node_id,
+        choice_ids: choices.into_iter().map(|choice| choice.id).collect(),
+    };
+    if ctx
+        .db
+        .dialogue_session()
+        .character_id()
+        .find(character_id)
+        .is_some()
+    {
+        ctx.db.dialogue_session().character_id().update(session);
+    } else {
+        ctx.db.dialogue_session().insert(session);
+    }
+}
+
+fn run_action(
+    ctx: &ReducerContext,
+    character_id: u32,
+    entity_id: u32,
+    action: &DialogueAction,
+) -> Result<(), String> {
+    match action {
+        DialogueAction::StartQuest(quest_def_id) => {
+            quest::start_quest(ctx, "choose_dialogue_option", character_id, *quest_def_id)
+        }
+        DialogueAction::GiveItem(grant) => {
+            inventory::add_item(ctx, character_id, grant.item_def_id, grant.quantity)
+        }
+        DialogueAction::Teleport(translation) => movement::teleport(ctx, entity_id, *translation),
+        DialogueAction::ApplyEffect(effect_def_id) => {
+            effect::apply_effect(ctx, entity_id, *effect_def_id, None)
+        }
+    }
+}
+
+/// Starts a conversation with a nearby NPC. Talking to an NPC also counts towards
+/// quest objectives asking for it.
+#[reducer]
+pub fn start_dialogue(ctx: &ReducerContext, npc_entity_id: u32) -> Result<(), String> {
+    let (npc, character_id) = npc::npc_in_reach(ctx, "start_dialogue", npc_entity_id)?;
+
+    let Some(entry) = ctx
+        .db
+        .dialogue_node()
+        .npc_def_id()
+        .filter(npc.npc_def_id)
+        .find(|node| node.entry)
+    else {
+        let err = format!("start_dialogue: NPC has nothing to say.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    quest::record_talk(ctx, character_id, npc.npc_def_id);
+    enter_node(ctx, character_id, npc.entity_id, Some(entry.id));
+
+    Ok(())
+}
+
+/// Picks one of the choices offered at the current node of the sender's conversation.
+#[reducer]
+pub fn choose_dialogue_option(ctx: &ReducerContext, choice_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("choose_dialogue_option: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(session) = ctx
+        .db
+        .dialogue_session()
+        .character_id()
+        .find(pawn.character_id)
+    else {
+        let err = format!("choose_dialogue_option: not in a conversation.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    // Walking away from the NPC ends the conversation
+    if !npc::in_reach(ctx, pawn.entity_id, session.npc_entity_id) {
+        log::warn!("choose_dialogue_option: NPC is too far away, ending conversation.");
+        ctx.db
+            .dialogue_session()
+            .character_id()
+            .delete(pawn.character_id);
+        return Ok(());
+    }
+
+    let Some(choice) = ctx
+        .db
+        .dialogue_choice()
+        .id()
+        .find(choice_id)
+        .filter(|choice| choice.node_id == session.node_id)
+    else {
+        let err = format!("choose_dialogue_option: choice not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    // Conditions are checked again as they may have changed since entering the node
+    if !session.choice_ids.contains(&choice.id)
+        || !choice
+            .conditions
+            .iter()
+            .all(|condition| condition_met(ctx, pawn.character_id, condition))
+    {
+        let err = format!("choose_dialogue_option: choice not available.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    for action in &choice.actions {
+        run_action(ctx, pawn.character_id, pawn.entity_id, action)?;
+    }
+
+    // A teleport may have taken the character away from the NPC
+    let next_node_id = choice
+        .next_node_id
+        .filter(|_| npc::in_reach(ctx, pawn.entity_id, session.npc_entity_id));
+    enter_node(ctx, pawn.character_id, session.npc_entity_id, next_node_id);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn end_dialogue(ctx: &ReducerContext) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("end_dialogue: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    ctx.db
+        .dialogue_session()
+        .character_id()
+        .delete(pawn.character_id);
+
+    Ok(())
+}
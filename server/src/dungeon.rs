@@ -0,0 +1,486 @@
+//! Instanced dungeons.
+//!
+//! Every dungeon has an entrance portal in the open world. Stepping through it puts a
+//! character into its party's instance of the dungeon, opening one if the party has
+//! none yet. An instance is a map of its own with its own copies of the dungeon's NPCs,
+//! so its movement and combat never touch the open world or other instances. Characters
+//! on their own get an instance to themselves.
+//!
+//! Entering an instance saves the character to it until their lockout runs out, and no
+//! other instance of the same dungeon can be entered while saved. Instances reset after
+//! a fixed time, sending everyone inside back to the entrance, and are torn down early
+//! once nobody has been inside them for a while.
+
+use crate::{
+    Entity, Transform,
+    character::character_pawn,
+    entities_in_range, entity, entity_location,
+    map::{self, FIRST_INSTANCE_MAP_ID, Map, MapKind, map},
+    npc,
+    party::party_member,
+    progression::character_level,
+    threat::npc_threat,
+    transform,
+    types::{Quat, Vec3},
+};
+use common::chunk;
+use spacetimedb::{
+    Filter, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp, client_visibility_filter,
+    reducer, table,
+};
+
+/// How close a character needs to be to a portal to step through it.
+const PORTAL_RANGE_SQUARED: f32 = 5.0 * 5.0;
+/// How often empty instances and expired lockouts are looked for.
+const CLEANUP_MICRO_SECS: i64 = 30_000_000;
+/// How long an instance can stay empty before it is torn down.
+const EMPTY_TEARDOWN_MICRO_SECS: i64 = 5 * 60 * 1_000_000;
+
+#[client_visibility_filter]
+const DUNGEON_LOCKOUT_SECURITY: Filter = Filter::Sql(
+    "SELECT dungeon_lockout.* FROM dungeon_lockout JOIN character_def ON dungeon_lockout.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+/// The static definition of a dungeon.
+#[table(name = dungeon_def, public)]
+pub struct DungeonDef {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub description: String,
+    pub min_level: u8,
+
+    /// Where the entrance portal stands
+    pub entrance_map_id: u32,
+    pub entrance: Vec3,
+
+    /// Where characters arrive inside an instance
+    pub arrival: Vec3,
+
+    /// How long an instance lasts before it resets
+    pub reset_secs: u32,
+    /// How long entering an instance saves a character to it
+    pub lockout_secs: u32,
+}
+
+/// An NPC placed in every instance of a dungeon.
+#[table(name = dungeon_spawn)]
+pub struct DungeonSpawn {
+    #[primary_key]
+    pub id: u32,
+
+    #[index(btree)]
+    pub dungeon_def_id: u32,
+
+    pub npc_def_id: u32,
+    pub translation: Vec3,
+}
+
+/// A type-narrowing table for in-game entities that are dungeon entrances.
+#[table(name = dungeon_portal, public)]
+pub struct DungeonPortal {
+    #[primary_key]
+    pub entity_id: u32,
+
+    #[unique]
+    pub dungeon_def_id: u32,
+}
+
+/// An open copy of a dungeon.
+#[table(name = dungeon_instance, public)]
+pub struct DungeonInstance {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    /// The map the instance runs on
+    #[unique]
+    pub map_id: u32,
+
+    #[index(btree)]
+    pub dungeon_def_id: u32,
+
+    /// The party the instance belongs to, `None` for a character on their own
+    pub party_id: Option<u32>,
+    /// The character who opened the instance
+    pub owner_character_id: u32,
+
+    pub created_at: Timestamp,
+    pub resets_at: Timestamp,
+
+    /// When the last character inside left, `None` while anyone is inside
+    pub empty_since: Option<Timestamp>,
+}
+
+/// A character saved to an instance of a dungeon.
+#[table(name = dungeon_lockout, public)]
+pub struct DungeonLockout {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub character_id: u32,
+
+    pub dungeon_def_id: u32,
+    pub map_id: u32,
+    pub expires_at: Timestamp,
+}
+
+#[table(name = dungeon_reset_timer, scheduled(dungeon_reset))]
+struct DungeonResetTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+
+    #[index(btree)]
+    map_id: u32,
+}
+
+#[table(name = dungeon_cleanup_timer, scheduled(dungeon_cleanup))]
+struct DungeonCleanupTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+/// Places every entrance portal that isn't in the world yet and starts looking for
+/// instances to tear down.
+pub fn init(ctx: &ReducerContext) {
+    ctx.db.dungeon_cleanup_timer().scheduled_id().delete(1);
+    ctx.db.dungeon_cleanup_timer().insert(DungeonCleanupTimer {
+        scheduled_id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(CLEANUP_MICRO_SECS)),
+    });
+
+    for dungeon_def in ctx.db.dungeon_def().iter() {
+        if ctx
+            .db
+            .dungeon_portal()
+            .dungeon_def_id()
+            .find(dungeon_def.id)
+            .is_some()
+        {
+            continue;
+        }
+
+        let translation = dungeon_def.entrance;
+        let transform = ctx.db.transform().insert(Transform {
+            id: 0,
+            translation,
+            rotation: Quat::default(),
+            scale: Vec3::default(),
+            map_id: dungeon_def.entrance_map_id,
+            chunk_id: chunk::encode(translation.x, translation.z),
+            stealthed: false,
+        });
+        let entity = ctx.db.entity().insert(Entity {
+            id: 0,
+            transform_id: transform.id,
+        });
+        ctx.db.dungeon_portal().insert(DungeonPortal {
+            entity_id: entity.id,
+            dungeon_def_id: dungeon_def.id,
+        });
+    }
+}
+
+/// The instance of a dungeon belonging to a character's party, or to the character
+/// alone when they aren't in one.
+fn find_instance(
+    ctx: &ReducerContext,
+    dungeon_def_id: u32,
+    party_id: Option<u32>,
+    character_id: u32,
+) -> Option<DungeonInstance> {
+    ctx.db
+        .dungeon_instance()
+        .dungeon_def_id()
+        .filter(dungeon_def_id)
+        .find(|instance| match party_id {
+            Some(_) => instance.party_id == party_id,
+            None => instance.party_id.is_none() && instance.owner_character_id == character_id,
+        })
+}
+
+/// Opens a new instance of a dungeon, with its own map and NPCs.
+fn open_instance(
+    ctx: &ReducerContext,
+    dungeon_def: &DungeonDef,
+    party_id: Option<u32>,
+    character_id: u32,
+) -> DungeonInstance {
+    let resets_at =
+        ctx.timestamp + TimeDuration::from_micros(dungeon_def.reset_secs as i64 * 1_000_000);
+    let mut instance = ctx.db.dungeon_instance().insert(DungeonInstance {
+        id: 0,
+        map_id: 0,
+        dungeon_def_id: dungeon_def.id,
+        party_id,
+        owner_character_id: character_id,
+        created_at: ctx.timestamp,
+        resets_at,
+        empty_since: None,
+    });
+    // Instance IDs are never reused, so neither are their maps
+    instance.map_id = FIRST_INSTANCE_MAP_ID + instance.id;
+    let instance = ctx.db.dungeon_instance().id().update(instance);
+
+    ctx.db.map().insert(Map {
+        id: instance.map_id,
+        name: dungeon_def.name.clone(),
+        kind: MapKind::Dungeon,
+        arrival: dungeon_def.arrival,
+    });
+    for spawn in ctx
+        .db
+        .dungeon_spawn()
+        .dungeon_def_id()
+        .filter(dungeon_def.id)
+    {
+        npc::spawn_once(ctx, instance.map_id, spawn.npc_def_id, spawn.translation);
+    }
+    ctx.db.dungeon_reset_timer().insert(DungeonResetTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(resets_at),
+        map_id: instance.map_id,
+    });
+
+    instance
+}
+
+/// Closes an instance, sending everyone still inside back to the dungeon's entrance.
+fn teardown(ctx: &ReducerContext, instance: DungeonInstance) {
+    let map_id = instance.map_id;
+    let entrance = ctx
+        .db
+        .dungeon_def()
+        .id()
+        .find(instance.dungeon_def_id)
+        .map(|dungeon_def| (dungeon_def.entrance_map_id, dungeon_def.entrance))
+        .unwrap_or((map::OVERWORLD_MAP_ID, Vec3::default()));
+
+    let inside: Vec<u32> = ctx
+        .db
+        .character_pawn()
+        .iter()
+        .filter(|pawn| entity_location(ctx, pawn.entity_id).is_some_and(|(id, _)| id == map_id))
+        .map(|pawn| pawn.entity_id)
+        .collect();
+    for entity_id in inside {
+        if let Err(err) = map::transfer_entity(ctx, entity_id, entrance.0, entrance.1) {
+            log::warn!("Unable to move entity {entity_id} out of instance {map_id}: {err}");
+        }
+    }
+    npc::despawn_map(ctx, map_id);
+
+    // Characters that left the world inside come back at the entrance
+    let left_behind: Vec<Transform> = ctx.db.transform().map_chunk().filter(map_id).collect();
+    for mut transform in left_behind {
+        transform.map_id = entrance.0;
+        transform.translation = entrance.1;
+        transform.chunk_id = chunk::encode(entrance.1.x, entrance.1.z);
+        ctx.db.transform().id().update(transform);
+    }
+
+    ctx.db.dungeon_reset_timer().map_id().delete(map_id);
+    ctx.db.map().id().delete(map_id);
+    ctx.db.dungeon_instance().delete(instance);
+}
+
+/// Steps through a dungeon's entrance portal into the instance of the sender's party.
+#[reducer]
+pub fn enter_dungeon(ctx: &ReducerContext, portal_entity_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("enter_dungeon: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(portal) = ctx.db.dungeon_portal().entity_id().find(portal_entity_id) else {
+        let err = format!("enter_dungeon: not a dungeon entrance.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(dungeon_def) = ctx.db.dungeon_def().id().find(portal.dungeon_def_id) else {
+        let err = format!("enter_dungeon: dungeon not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if !entities_in_range(ctx, pawn.entity_id, portal.entity_id, PORTAL_RANGE_SQUARED) {
+        let err = format!("enter_dungeon: entrance is too far away.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if character_level(ctx, pawn.character_id) < dungeon_def.min_level {
+        let err = format!(
+            "enter_dungeon: {} requires level {}.",
+            dungeon_def.name, dungeon_def.min_level
+        );
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if ctx
+        .db
+        .npc_threat()
+        .target_entity_id()
+        .filter(pawn.entity_id)
+        .next()
+        .is_some()
+    {
+        let err = format!("enter_dungeon: cannot enter a dungeon in combat.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    let party_id = ctx
+        .db
+        .party_member()
+        .character_id()
+        .find(pawn.character_id)
+        .map(|member| member.party_id);
+    let instance = find_instance(ctx, dungeon_def.id, party_id, pawn.character_id);
+    let lockout = ctx
+        .db
+        .dungeon_lockout()
+        .character_id()
+        .filter(pawn.character_id)
+        .find(|lockout| {
+            lockout.dungeon_def_id == dungeon_def.id && lockout.expires_at > ctx.timestamp
+        });
+    if let Some(lockout) = &lockout
+        && instance.as_ref().map(|instance| instance.map_id) != Some(lockout.map_id)
+    {
+        let err = format!(
+            "enter_dungeon: you are saved to another instance of {}.",
+            dungeon_def.name
+        );
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    let mut instance =
+        instance.unwrap_or_else(|| open_instance(ctx, &dungeon_def, party_id, pawn.character_id));
+    if lockout.is_none() {
+        ctx.db.dungeon_lockout().insert(DungeonLockout {
+            id: 0,
+            character_id: pawn.character_id,
+            dungeon_def_id: dungeon_def.id,
+            map_id: instance.map_id,
+            expires_at: ctx.timestamp
+                + TimeDuration::from_micros(dungeon_def.lockout_secs as i64 * 1_000_000),
+        });
+    }
+    let map_id = instance.map_id;
+    if instance.empty_since.is_some() {
+        instance.empty_since = None;
+        ctx.db.dungeon_instance().id().update(instance);
+    }
+
+    map::transfer_entity(ctx, pawn.entity_id, map_id, dungeon_def.arrival)
+        .map_err(|err| format!("enter_dungeon: {err}"))
+}
+
+/// Leaves the dungeon instance the sender's character is in, back to its entrance.
+#[reducer]
+pub fn leave_dungeon(ctx: &ReducerContext) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("leave_dungeon: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let instance = entity_location(ctx, pawn.entity_id)
+        .and_then(|(map_id, _)| ctx.db.dungeon_instance().map_id().find(map_id));
+    let Some(instance) = instance else {
+        let err = format!("leave_dungeon: not inside a dungeon.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(dungeon_def) = ctx.db.dungeon_def().id().find(instance.dungeon_def_id) else {
+        let err = format!("leave_dungeon: dungeon not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    map::transfer_entity(
+        ctx,
+        pawn.entity_id,
+        dungeon_def.entrance_map_id,
+        dungeon_def.entrance,
+    )
+    .map_err(|err| format!("leave_dungeon: {err}"))
+}
+
+/// Resets an instance whose time ran out.
+#[reducer]
+fn dungeon_reset(ctx: &ReducerContext, timer: DungeonResetTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`dungeon_reset` may not be invoked by clients.".into());
+    }
+
+    if let Some(instance) = ctx.db.dungeon_instance().map_id().find(timer.map_id) {
+        teardown(ctx, instance);
+    }
+
+    Ok(())
+}
+
+/// Tears down instances nobody has been inside of for a while, and forgets lockouts
+/// that ran out.
+#[reducer]
+fn dungeon_cleanup(ctx: &ReducerContext, _timer: DungeonCleanupTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`dungeon_cleanup` may not be invoked by clients.".into());
+    }
+
+    let expired: Vec<DungeonLockout> = ctx
+        .db
+        .dungeon_lockout()
+        .iter()
+        .filter(|lockout| lockout.expires_at <= ctx.timestamp)
+        .collect();
+    for lockout in expired {
+        ctx.db.dungeon_lockout().delete(lockout);
+    }
+
+    let occupied: Vec<u32> = ctx
+        .db
+        .character_pawn()
+        .iter()
+        .filter_map(|pawn| entity_location(ctx, pawn.entity_id))
+        .map(|(map_id, _)| map_id)
+        .collect();
+    let instances: Vec<DungeonInstance> = ctx.db.dungeon_instance().iter().collect();
+    for mut instance in instances {
+        if occupied.contains(&instance.map_id) {
+            if instance.empty_since.is_some() {
+                instance.empty_since = None;
+                ctx.db.dungeon_instance().id().update(instance);
+            }
+            continue;
+        }
+
+        match instance.empty_since {
+            None => {
+                instance.empty_since = Some(ctx.timestamp);
+                ctx.db.dungeon_instance().id().update(instance);
+            }
+            Some(empty_since) => {
+                let empty_for = ctx
+                    .timestamp
+                    .time_duration_since(empty_since)
+                    .map_or(0, |duration| duration.to_micros());
+                if empty_for >= EMPTY_TEARDOWN_MICRO_SECS {
+                    teardown(ctx, instance);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
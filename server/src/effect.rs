@@ -0,0 +1,263 @@
+//! Buffs and debuffs on entities.
+//!
+//! Effects are defined statically in `effect_def` and applied to an entity as an
+//! `active_effect`. Periodic effects deal damage or heal on every tick, while modifiers
+//! change a stat for as long as they last. Other systems never look at active effects
+//! directly but ask for the aggregated `stat_multiplier` of an entity instead.
+
+use crate::{area, combat, entity_location};
+use common::area::Area;
+use spacetimedb::{
+    ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp, reducer, table,
+};
+
+/// How often effects are checked for ticks and expiry.
+const TICK_MICRO_SECS: i64 = 250_000;
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum Stat {
+    MoveSpeed,
+    DamageTaken,
+}
+
+/// A change to a stat, in percent per stack.
+#[derive(SpacetimeType, Clone, Copy)]
+pub struct StatModifier {
+    pub stat: Stat,
+    pub percent: i16,
+}
+
+#[derive(SpacetimeType, Clone, Copy)]
+pub enum EffectKind {
+    /// Damage dealt per stack on every tick
+    Damage(u16),
+    /// Health restored per stack on every tick
+    Heal(u16),
+    Modifier(StatModifier),
+}
+
+/// The static definition of a buff or debuff.
+#[table(name = effect_def, public)]
+pub struct EffectDef {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub description: String,
+    pub kind: EffectKind,
+    pub duration_secs: u32,
+
+    /// How often periodic effects tick, zero for effects that don't
+    pub tick_secs: u32,
+
+    /// Periodic effects with a radius hit everything around the entity carrying them,
+    /// damage hitting its enemies and healing its allies, itself included. Zero for
+    /// effects that only touch the carrier.
+    pub radius: f32,
+
+    pub max_stacks: u8,
+}
+
+/// An effect currently applied to an entity.
+#[table(name = active_effect, public)]
+pub struct ActiveEffect {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub entity_id: u32,
+
+    pub effect_def_id: u32,
+
+    /// The entity that applied the effect, `None` for the world itself
+    pub source_entity_id: Option<u32>,
+
+    pub stacks: u8,
+    pub tick_secs: u32,
+    pub next_tick_at: Timestamp,
+    pub expires_at: Timestamp,
+}
+
+#[table(name = effect_tick_timer, scheduled(effect_tick))]
+struct EffectTickTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+pub fn init(ctx: &ReducerContext) {
+    ctx.db.effect_tick_timer().scheduled_id().delete(1);
+    ctx.db.effect_tick_timer().insert(EffectTickTimer {
+        scheduled_id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(TICK_MICRO_SECS)),
+    });
+}
+
+fn secs(secs: u32) -> TimeDuration {
+    TimeDuration::from_micros(secs as i64 * 1_000_000)
+}
+
+/// Applies an effect to an entity. Reapplying an effect from the same source refreshes
+/// its duration and adds a stack, up to the effect's max.
+pub(crate) fn apply_effect(
+    ctx: &ReducerContext,
+    entity_id: u32,
+    effect_def_id: u32,
+    source_entity_id: Option<u32>,
+) -> Result<(), String> {
+    let Some(effect_def) = ctx.db.effect_def().id().find(effect_def_id) else {
+        let err = format!("apply_effect: invalid effect.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let expires_at = ctx.timestamp + secs(effect_def.duration_secs);
+
+    let existing = ctx
+        .db
+        .active_effect()
+        .entity_id()
+        .filter(entity_id)
+        .find(|effect| {
+            effect.effect_def_id == effect_def_id && effect.source_entity_id == source_entity_id
+        });
+    match existing {
+        Some(mut effect) => {
+            effect.stacks = (effect.stacks + 1).min(effect_def.max_stacks.max(1));
+            effect.expires_at = expires_at;
+            ctx.db.active_effect().id().update(effect);
+        }
+        None => {
+            ctx.db.active_effect().insert(ActiveEffect {
+                id: 0,
+                entity_id,
+                effect_def_id,
+                source_entity_id,
+                stacks: 1,
+                tick_secs: effect_def.tick_secs,
+                next_tick_at: ctx.timestamp + secs(effect_def.tick_secs),
+                expires_at,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes every effect from an entity, e.g. when it dies or leaves the world.
+pub(crate) fn clear(ctx: &ReducerContext, entity_id: u32) {
+    ctx.db.active_effect().entity_id().delete(entity_id);
+}
+
+/// The combined multiplier of every modifier on an entity for a stat, `1.0` meaning
+/// unchanged. Modifiers add up, so two 30% slows make a 60% slow, and the result never
+/// drops below zero.
+pub(crate) fn stat_multiplier(ctx: &ReducerContext, entity_id: u32, stat: Stat) -> f32 {
+    let percent: i32 = ctx
+        .db
+        .active_effect()
+        .entity_id()
+        .filter(entity_id)
+        .filter_map(|effect| {
+            let effect_def = ctx.db.effect_def().id().find(effect.effect_def_id)?;
+            match effect_def.kind {
+                EffectKind::Modifier(modifier) if modifier.stat == stat => {
+                    Some(modifier.percent as i32 * effect.stacks as i32)
+                }
+                _ => None,
+            }
+        })
+        .sum();
+
+    (1.0 + percent as f32 / 100.0).max(0.0)
+}
+
+/// The entities a tick of a periodic effect lands on: the carrier itself, or for
+/// effects with a radius either the carrier's enemies or its allies around it.
+fn targets(
+    ctx: &ReducerContext,
+    effect: &ActiveEffect,
+    effect_def: &EffectDef,
+    hostile: bool,
+) -> Vec<u32> {
+    if effect_def.radius <= 0.0 {
+        return vec![effect.entity_id];
+    }
+    let Some((map_id, center)) = entity_location(ctx, effect.entity_id) else {
+        return Vec::new();
+    };
+
+    let area = Area::Circle {
+        center: center.xz(),
+        radius: effect_def.radius,
+    };
+    area::entities_in_area(ctx, map_id, &area)
+        .into_iter()
+        .filter(|target| {
+            combat::entity_health_id(ctx, *target).is_some()
+                && combat::hostile(ctx, effect.entity_id, *target) == hostile
+        })
+        .collect()
+}
+
+/// Ticks periodic effects that are due and removes the ones that ran out.
+#[reducer]
+fn effect_tick(ctx: &ReducerContext, _timer: EffectTickTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`effect_tick` may not be invoked by clients.".into());
+    }
+
+    let effects: Vec<ActiveEffect> = ctx.db.active_effect().iter().collect();
+    for mut effect in effects {
+        // Ticks landing exactly on expiry still count
+        let due =
+            effect.tick_secs > 0 && effect.next_tick_at <= ctx.timestamp.min(effect.expires_at);
+        if due {
+            let Some(effect_def) = ctx.db.effect_def().id().find(effect.effect_def_id) else {
+                ctx.db.active_effect().id().delete(effect.id);
+                continue;
+            };
+            let stacks = effect.stacks as u16;
+            match effect_def.kind {
+                EffectKind::Damage(amount) => {
+                    for target in targets(ctx, &effect, &effect_def, true) {
+                        combat::apply_damage(
+                            ctx,
+                            target,
+                            effect.source_entity_id,
+                            amount.saturating_mul(stacks),
+                        );
+                    }
+                }
+                EffectKind::Heal(amount) => {
+                    for target in targets(ctx, &effect, &effect_def, false) {
+                        combat::apply_heal(
+                            ctx,
+                            target,
+                            effect.source_entity_id,
+                            amount.saturating_mul(stacks),
+                        );
+                    }
+                }
+                EffectKind::Modifier(_) => {}
+            }
+
+            // The tick may have killed the entity and cleared its effects
+            if ctx.db.active_effect().id().find(effect.id).is_none() {
+                continue;
+            }
+            effect.next_tick_at = effect.next_tick_at + secs(effect.tick_secs);
+        }
+
+        if effect.expires_at <= ctx.timestamp {
+            ctx.db.active_effect().id().delete(effect.id);
+        } else if due {
+            ctx.db.active_effect().id().update(effect);
+        }
+    }
+
+    Ok(())
+}
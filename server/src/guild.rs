@@ -0,0 +1,619 @@
+//! Long-lived communities of characters.
+//!
+//! Membership belongs to a `CharacterDef`, not to the player, so every character of a
+//! player can be in a different guild. Each guild owns an ordered list of ranks where
+//! a lower `rank_order` outranks a higher one and rank 0 is the guild master.
+
+use crate::{
+    character::{character_def, character_pawn},
+    social,
+};
+use spacetimedb::{
+    Filter, ReducerContext, Table, Timestamp, client_visibility_filter, reducer, table,
+};
+
+const MAX_GUILD_NAME_LENGTH: usize = 24;
+const MAX_GUILD_MESSAGE_LENGTH: usize = 256;
+/// Older messages are dropped once a member has received more than this many messages.
+const MAX_GUILD_MESSAGES: usize = 100;
+
+pub(crate) const PERMISSION_INVITE: u32 = 1 << 0;
+pub(crate) const PERMISSION_KICK: u32 = 1 << 1;
+pub(crate) const PERMISSION_PROMOTE: u32 = 1 << 2;
+pub(crate) const PERMISSION_DEMOTE: u32 = 1 << 3;
+pub(crate) const PERMISSION_SPEAK: u32 = 1 << 4;
+pub(crate) const PERMISSION_DISBAND: u32 = 1 << 5;
+
+/// The ranks every new guild starts with, from guild master down.
+const DEFAULT_RANKS: [(&str, u32); 4] = [
+    (
+        "Guild Master",
+        PERMISSION_INVITE
+            | PERMISSION_KICK
+            | PERMISSION_PROMOTE
+            | PERMISSION_DEMOTE
+            | PERMISSION_SPEAK
+            | PERMISSION_DISBAND,
+    ),
+    (
+        "Officer",
+        PERMISSION_INVITE
+            | PERMISSION_KICK
+            | PERMISSION_PROMOTE
+            | PERMISSION_DEMOTE
+            | PERMISSION_SPEAK,
+    ),
+    ("Member", PERMISSION_SPEAK),
+    ("Initiate", PERMISSION_SPEAK),
+];
+
+#[client_visibility_filter]
+const GUILD_INVITE_SECURITY: Filter = Filter::Sql(
+    "SELECT guild_invite.* FROM guild_invite JOIN character_def ON guild_invite.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+#[client_visibility_filter]
+const GUILD_MESSAGE_SECURITY: Filter = Filter::Sql(
+    "SELECT guild_message.* FROM guild_message JOIN character_def ON guild_message.recipient_character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+#[table(name = guild, public)]
+pub struct Guild {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub created_at: Timestamp,
+}
+
+#[table(name = guild_rank, public)]
+pub struct GuildRank {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub guild_id: u32,
+
+    /// Position of the rank within the guild, 0 being the guild master
+    pub rank_order: u8,
+
+    pub name: String,
+
+    /// Bitmask of the `PERMISSION_*` flags granted to members of this rank
+    pub permissions: u32,
+}
+
+/// A character's membership in a guild.
+/// Keyed by character so that a character can only be in a single guild at a time.
+#[table(name = guild_member, public)]
+pub struct GuildMember {
+    #[primary_key]
+    pub character_id: u32,
+
+    #[index(btree)]
+    pub guild_id: u32,
+
+    #[index(btree)]
+    pub rank_id: u32,
+
+    /// Copied from the character so that rosters don't need access to `character_def`
+    pub name: String,
+
+    pub joined_at: Timestamp,
+}
+
+/// A pending invitation for a character to join a guild.
+#[table(name = guild_invite, public)]
+pub struct GuildInvite {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub guild_id: u32,
+
+    /// The invited character
+    #[index(btree)]
+    pub character_id: u32,
+
+    pub inviter_character_id: u32,
+
+    pub created_at: Timestamp,
+}
+
+/// A message sent to a guild's chat channel, as delivered to a single member.
+/// Delivering a row per member lets the server leave out members ignoring the sender.
+#[table(name = guild_message, public)]
+pub struct GuildMessage {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub guild_id: u32,
+
+    #[index(btree)]
+    pub recipient_character_id: u32,
+
+    pub sender_character_id: u32,
+    pub sender_name: String,
+
+    pub text: String,
+    pub sent_at: Timestamp,
+}
+
+/// The guild membership of the sender's in-game character along with its rank.
+fn sender_membership(
+    ctx: &ReducerContext,
+    reducer: &str,
+) -> Result<(GuildMember, GuildRank), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("{reducer}: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(member) = ctx.db.guild_member().character_id().find(pawn.character_id) else {
+        let err = format!("{reducer}: not in a guild.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(rank) = ctx.db.guild_rank().id().find(member.rank_id) else {
+        let err = format!("{reducer}: rank not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    Ok((member, rank))
+}
+
+/// Fails unless `rank` grants `permission`.
+fn require_permission(reducer: &str, rank: &GuildRank, permission: u32) -> Result<(), String> {
+    if rank.permissions & permission != permission {
+        let err = format!("{reducer}: rank does not allow this.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Finds a character in the same guild that the acting member outranks.
+fn outranked_member(
+    ctx: &ReducerContext,
+    reducer: &str,
+    actor: &GuildMember,
+    actor_rank: &GuildRank,
+    character_id: u32,
+) -> Result<(GuildMember, GuildRank), String> {
+    let Some((member, rank)) = ctx
+        .db
+        .guild_member()
+        .character_id()
+        .find(character_id)
+        .filter(|member| member.guild_id == actor.guild_id)
+        .and_then(|member| {
+            let rank = ctx.db.guild_rank().id().find(member.rank_id)?;
+            Some((member, rank))
+        })
+    else {
+        let err = format!("{reducer}: character is not in the guild.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    if rank.rank_order <= actor_rank.rank_order {
+        let err = format!("{reducer}: character is not outranked.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    Ok((member, rank))
+}
+
+fn rank_by_order(ctx: &ReducerContext, guild_id: u32, rank_order: u8) -> Option<GuildRank> {
+    ctx.db
+        .guild_rank()
+        .guild_id()
+        .filter(guild_id)
+        .find(|rank| rank.rank_order == rank_order)
+}
+
+fn delete_guild(ctx: &ReducerContext, guild_id: u32) {
+    ctx.db.guild_message().guild_id().delete(guild_id);
+    ctx.db.guild_invite().guild_id().delete(guild_id);
+    ctx.db.guild_member().guild_id().delete(guild_id);
+    ctx.db.guild_rank().guild_id().delete(guild_id);
+    ctx.db.guild().id().delete(guild_id);
+}
+
+/// Removes a character from its guild. If the guild master leaves, the highest ranked
+/// remaining member takes over, and a guild without members is disbanded.
+pub(crate) fn remove_member(ctx: &ReducerContext, character_id: u32) {
+    ctx.db.guild_invite().character_id().delete(character_id);
+    ctx.db
+        .guild_message()
+        .recipient_character_id()
+        .delete(character_id);
+    let Some(member) = ctx.db.guild_member().character_id().find(character_id) else {
+        return;
+    };
+    ctx.db.guild_member().character_id().delete(character_id);
+
+    let Some(master_rank) = rank_by_order(ctx, member.guild_id, 0) else {
+        return;
+    };
+    if member.rank_id != master_rank.id {
+        return;
+    }
+
+    let successor = ctx
+        .db
+        .guild_member()
+        .guild_id()
+        .filter(member.guild_id)
+        .filter_map(|other| {
+            let rank = ctx.db.guild_rank().id().find(other.rank_id)?;
+            Some((rank.rank_order, other.joined_at, other))
+        })
+        .min_by_key(|(rank_order, joined_at, _)| (*rank_order, *joined_at));
+
+    match successor {
+        Some((_, _, mut successor)) => {
+            successor.rank_id = master_rank.id;
+            ctx.db.guild_member().character_id().update(successor);
+        }
+        None => delete_guild(ctx, member.guild_id),
+    }
+}
+
+#[reducer]
+pub fn create_guild(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let trimmed_name = name.trim();
+
+    // Is this name valid?
+    if trimmed_name.is_empty() || trimmed_name.chars().count() > MAX_GUILD_NAME_LENGTH {
+        log::warn!(
+            "Create guild attempt failed: InvalidName\nidentity: {}",
+            ctx.sender
+        );
+        return Err(format!("Invalid guild name."));
+    }
+
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        log::warn!(
+            "Create guild attempt failed: NotInGame\nidentity: {}",
+            ctx.sender
+        );
+        return Err(format!("Character must be in game to create a guild."));
+    };
+
+    if ctx
+        .db
+        .guild_member()
+        .character_id()
+        .find(pawn.character_id)
+        .is_some()
+    {
+        log::warn!(
+            "Create guild attempt failed: AlreadyInGuild\nidentity: {}",
+            ctx.sender
+        );
+        return Err(format!("Character is already in a guild."));
+    }
+
+    // Is this name taken?
+    if ctx
+        .db
+        .guild()
+        .name()
+        .find(trimmed_name.to_string())
+        .is_some()
+    {
+        log::warn!(
+            "Create guild attempt failed: NameTaken\nidentity: {}",
+            ctx.sender
+        );
+        return Err(format!("Guild name is already taken."));
+    }
+
+    let Some(character) = ctx.db.character_def().id().find(pawn.character_id) else {
+        return Err(format!("Invalid character"));
+    };
+
+    let guild = ctx.db.guild().insert(Guild {
+        id: 0,
+        name: trimmed_name.into(),
+        created_at: ctx.timestamp,
+    });
+
+    let mut master_rank_id = 0;
+    for (rank_order, (rank_name, permissions)) in DEFAULT_RANKS.into_iter().enumerate() {
+        let rank = ctx.db.guild_rank().insert(GuildRank {
+            id: 0,
+            guild_id: guild.id,
+            rank_order: rank_order as u8,
+            name: rank_name.into(),
+            permissions,
+        });
+        if rank_order == 0 {
+            master_rank_id = rank.id;
+        }
+    }
+
+    ctx.db.guild_invite().character_id().delete(character.id);
+    ctx.db.guild_member().insert(GuildMember {
+        character_id: character.id,
+        guild_id: guild.id,
+        rank_id: master_rank_id,
+        name: character.name,
+        joined_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+/// Invites a character to the sender's guild, by name. The character doesn't need to be online.
+#[reducer]
+pub fn invite_to_guild(ctx: &ReducerContext, character_name: String) -> Result<(), String> {
+    let (member, rank) = sender_membership(ctx, "invite_to_guild")?;
+    require_permission("invite_to_guild", &rank, PERMISSION_INVITE)?;
+
+    let Some(character) = ctx
+        .db
+        .character_def()
+        .name()
+        .filter(character_name.trim())
+        .next()
+    else {
+        let err = format!("invite_to_guild: character not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if social::is_ignoring(ctx, character.identity, ctx.sender) {
+        let err = format!("invite_to_guild: character is ignoring you.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if ctx
+        .db
+        .guild_member()
+        .character_id()
+        .find(character.id)
+        .is_some()
+    {
+        let err = format!("invite_to_guild: character is already in a guild.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if ctx
+        .db
+        .guild_invite()
+        .character_id()
+        .filter(character.id)
+        .any(|invite| invite.guild_id == member.guild_id)
+    {
+        let err = format!("invite_to_guild: character has already been invited.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    ctx.db.guild_invite().insert(GuildInvite {
+        id: 0,
+        guild_id: member.guild_id,
+        character_id: character.id,
+        inviter_character_id: member.character_id,
+        created_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+/// Finds an invite addressed to one of the sender's characters.
+fn sender_invite(
+    ctx: &ReducerContext,
+    reducer: &str,
+    invite_id: u32,
+) -> Result<GuildInvite, String> {
+    let Some(invite) = ctx.db.guild_invite().id().find(invite_id).filter(|invite| {
+        ctx.db
+            .character_def()
+            .id()
+            .find(invite.character_id)
+            .is_some_and(|character| character.identity == ctx.sender)
+    }) else {
+        let err = format!("{reducer}: invite not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    Ok(invite)
+}
+
+#[reducer]
+pub fn accept_guild_invite(ctx: &ReducerContext, invite_id: u32) -> Result<(), String> {
+    let invite = sender_invite(ctx, "accept_guild_invite", invite_id)?;
+
+    let Some(lowest_rank) = ctx
+        .db
+        .guild_rank()
+        .guild_id()
+        .filter(invite.guild_id)
+        .max_by_key(|rank| rank.rank_order)
+    else {
+        let err = format!("accept_guild_invite: guild not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    let Some(character) = ctx.db.character_def().id().find(invite.character_id) else {
+        return Err(format!("Invalid character"));
+    };
+
+    // Joining a guild voids every other pending invite of the character
+    ctx.db.guild_invite().character_id().delete(character.id);
+    ctx.db.guild_member().insert(GuildMember {
+        character_id: character.id,
+        guild_id: invite.guild_id,
+        rank_id: lowest_rank.id,
+        name: character.name,
+        joined_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn decline_guild_invite(ctx: &ReducerContext, invite_id: u32) -> Result<(), String> {
+    let invite = sender_invite(ctx, "decline_guild_invite", invite_id)?;
+    ctx.db.guild_invite().delete(invite);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn leave_guild(ctx: &ReducerContext) -> Result<(), String> {
+    let (member, _) = sender_membership(ctx, "leave_guild")?;
+    remove_member(ctx, member.character_id);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn kick_from_guild(ctx: &ReducerContext, character_id: u32) -> Result<(), String> {
+    let (actor, actor_rank) = sender_membership(ctx, "kick_from_guild")?;
+    require_permission("kick_from_guild", &actor_rank, PERMISSION_KICK)?;
+    outranked_member(ctx, "kick_from_guild", &actor, &actor_rank, character_id)?;
+    remove_member(ctx, character_id);
+
+    Ok(())
+}
+
+/// Moves a member one rank up. Promoting someone to guild master hands the guild over,
+/// so the current guild master steps down to the next rank.
+#[reducer]
+pub fn promote_guild_member(ctx: &ReducerContext, character_id: u32) -> Result<(), String> {
+    let (mut actor, actor_rank) = sender_membership(ctx, "promote_guild_member")?;
+    require_permission("promote_guild_member", &actor_rank, PERMISSION_PROMOTE)?;
+    let (mut member, rank) = outranked_member(
+        ctx,
+        "promote_guild_member",
+        &actor,
+        &actor_rank,
+        character_id,
+    )?;
+
+    let new_order = rank.rank_order - 1;
+    if new_order == actor_rank.rank_order && actor_rank.rank_order != 0 {
+        let err = format!("promote_guild_member: cannot promote to or above your own rank.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    let Some(new_rank) = rank_by_order(ctx, member.guild_id, new_order) else {
+        let err = format!("promote_guild_member: rank not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if new_order == 0 {
+        let Some(officer_rank) = rank_by_order(ctx, actor.guild_id, 1) else {
+            let err = format!("promote_guild_member: rank not found.");
+            log::warn!("{err}");
+            return Err(err);
+        };
+        actor.rank_id = officer_rank.id;
+        ctx.db.guild_member().character_id().update(actor);
+    }
+
+    member.rank_id = new_rank.id;
+    ctx.db.guild_member().character_id().update(member);
+
+    Ok(())
+}
+
+/// Moves a member one rank down.
+#[reducer]
+pub fn demote_guild_member(ctx: &ReducerContext, character_id: u32) -> Result<(), String> {
+    let (actor, actor_rank) = sender_membership(ctx, "demote_guild_member")?;
+    require_permission("demote_guild_member", &actor_rank, PERMISSION_DEMOTE)?;
+    let (mut member, rank) = outranked_member(
+        ctx,
+        "demote_guild_member",
+        &actor,
+        &actor_rank,
+        character_id,
+    )?;
+
+    let Some(new_rank) = rank_by_order(ctx, member.guild_id, rank.rank_order + 1) else {
+        let err = format!("demote_guild_member: character already has the lowest rank.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    member.rank_id = new_rank.id;
+    ctx.db.guild_member().character_id().update(member);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn disband_guild(ctx: &ReducerContext) -> Result<(), String> {
+    let (member, rank) = sender_membership(ctx, "disband_guild")?;
+    require_permission("disband_guild", &rank, PERMISSION_DISBAND)?;
+    delete_guild(ctx, member.guild_id);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn send_guild_message(ctx: &ReducerContext, text: String) -> Result<(), String> {
+    let (member, rank) = sender_membership(ctx, "send_guild_message")?;
+    require_permission("send_guild_message", &rank, PERMISSION_SPEAK)?;
+
+    let text = text.trim();
+    if text.is_empty() || text.chars().count() > MAX_GUILD_MESSAGE_LENGTH {
+        let err = format!("send_guild_message: invalid message.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    for recipient in ctx.db.guild_member().guild_id().filter(member.guild_id) {
+        if ctx
+            .db
+            .character_def()
+            .id()
+            .find(recipient.character_id)
+            .is_some_and(|character| social::is_ignoring(ctx, character.identity, ctx.sender))
+        {
+            continue;
+        }
+
+        ctx.db.guild_message().insert(GuildMessage {
+            id: 0,
+            guild_id: member.guild_id,
+            recipient_character_id: recipient.character_id,
+            sender_character_id: member.character_id,
+            sender_name: member.name.clone(),
+            text: text.into(),
+            sent_at: ctx.timestamp,
+        });
+
+        // Only keep the most recent messages around
+        let mut messages: Vec<GuildMessage> = ctx
+            .db
+            .guild_message()
+            .recipient_character_id()
+            .filter(recipient.character_id)
+            .collect();
+        if messages.len() > MAX_GUILD_MESSAGES {
+            messages.sort_by_key(|message| message.id);
+            for message in messages.drain(..messages.len() - MAX_GUILD_MESSAGES) {
+                ctx.db.guild_message().delete(message);
+            }
+        }
+    }
+
+    Ok(())
+}
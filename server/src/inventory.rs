@@ -0,0 +1,208 @@
+use crate::{mount, quest};
+use spacetimedb::{Filter, ReducerContext, Table, client_visibility_filter, table};
+
+/// The number of distinct item stacks a character can hold.
+pub(crate) const MAX_INVENTORY_SLOTS: usize = 30;
+
+#[client_visibility_filter]
+const INVENTORY_SECURITY: Filter = Filter::Sql(
+    "SELECT inventory_item.* FROM inventory_item JOIN character_def ON inventory_item.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+/// A broad category of items, used to browse the auction house.
+#[table(name = item_type, public)]
+pub struct ItemType {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+}
+
+/// The static definition of an item that can exist in the world.
+#[table(name = item_def, public)]
+pub struct ItemDef {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub description: String,
+
+    #[index(btree)]
+    pub item_type_id: u32,
+
+    /// The level of content the item comes from
+    pub level: u8,
+
+    /// The maximum quantity of this item a single inventory slot can hold.
+    pub max_stack: u16,
+
+    /// What vendors pay for a single item, zero if they won't buy it.
+    pub sell_price: u64,
+}
+
+/// A stack of items owned by a character.
+#[table(name = inventory_item, public)]
+pub struct InventoryItem {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    /// Reference to the owning character
+    #[index(btree)]
+    pub character_id: u32,
+
+    #[index(btree)]
+    pub item_def_id: u32,
+
+    pub quantity: u16,
+}
+
+/// Adds `quantity` of an item to a character's inventory, topping up existing stacks
+/// before opening new slots.
+///
+/// Fails without touching the database if the inventory cannot hold the items.
+pub(crate) fn add_item(
+    ctx: &ReducerContext,
+    character_id: u32,
+    item_def_id: u32,
+    quantity: u16,
+) -> Result<(), String> {
+    let Some(item_def) = ctx.db.item_def().id().find(item_def_id) else {
+        return Err(format!("Invalid item."));
+    };
+    let max_stack = item_def.max_stack.max(1);
+
+    let owned: Vec<InventoryItem> = ctx
+        .db
+        .inventory_item()
+        .character_id()
+        .filter(character_id)
+        .collect();
+
+    // Make sure everything fits before writing anything
+    let free_in_stacks: u32 = owned
+        .iter()
+        .filter(|item| item.item_def_id == item_def_id)
+        .map(|item| max_stack.saturating_sub(item.quantity) as u32)
+        .sum();
+    let overflow = (quantity as u32).saturating_sub(free_in_stacks);
+    let slots_needed = overflow.div_ceil(max_stack as u32) as usize;
+    if owned.len() + slots_needed > MAX_INVENTORY_SLOTS {
+        return Err(format!("Inventory is full."));
+    }
+
+    let mut remaining = quantity;
+    for mut item in owned
+        .into_iter()
+        .filter(|item| item.item_def_id == item_def_id && item.quantity < max_stack)
+    {
+        if remaining == 0 {
+            break;
+        }
+        let added = remaining.min(max_stack - item.quantity);
+        item.quantity += added;
+        remaining -= added;
+        ctx.db.inventory_item().id().update(item);
+    }
+
+    while remaining > 0 {
+        let added = remaining.min(max_stack);
+        ctx.db.inventory_item().insert(InventoryItem {
+            id: 0,
+            character_id,
+            item_def_id,
+            quantity: added,
+        });
+        remaining -= added;
+    }
+    quest::refresh_collected(ctx, character_id);
+
+    Ok(())
+}
+
+/// Removes `quantity` from a specific inventory stack owned by `character_id`,
+/// deleting the stack when it is emptied. Returns the removed item's definition id.
+pub(crate) fn remove_item(
+    ctx: &ReducerContext,
+    character_id: u32,
+    inventory_item_id: u32,
+    quantity: u16,
+) -> Result<u32, String> {
+    let Some(mut item) = ctx.db.inventory_item().id().find(inventory_item_id) else {
+        return Err(format!("Item not found."));
+    };
+    if item.character_id != character_id {
+        return Err(format!("Item not found."));
+    }
+    if quantity == 0 || item.quantity < quantity {
+        return Err(format!("Not enough items."));
+    }
+
+    let item_def_id = item.item_def_id;
+    item.quantity -= quantity;
+    if item.quantity == 0 {
+        ctx.db.inventory_item().id().delete(item.id);
+    } else {
+        ctx.db.inventory_item().id().update(item);
+    }
+    quest::refresh_collected(ctx, character_id);
+    mount::refresh_owned(ctx, character_id);
+
+    Ok(item_def_id)
+}
+
+/// Returns how many of the given item the character owns across all stacks.
+pub(crate) fn item_count(ctx: &ReducerContext, character_id: u32, item_def_id: u32) -> u32 {
+    ctx.db
+        .inventory_item()
+        .character_id()
+        .filter(character_id)
+        .filter(|item| item.item_def_id == item_def_id)
+        .map(|item| item.quantity as u32)
+        .sum()
+}
+
+/// Removes `quantity` of an item from a character's inventory, emptying the smallest
+/// stacks first.
+///
+/// Fails without touching the database if the character doesn't own enough.
+pub(crate) fn take_items(
+    ctx: &ReducerContext,
+    character_id: u32,
+    item_def_id: u32,
+    quantity: u32,
+) -> Result<(), String> {
+    let mut stacks: Vec<InventoryItem> = ctx
+        .db
+        .inventory_item()
+        .character_id()
+        .filter(character_id)
+        .filter(|item| item.item_def_id == item_def_id)
+        .collect();
+    if stacks.iter().map(|item| item.quantity as u32).sum::<u32>() < quantity {
+        return Err(format!("Not enough items."));
+    }
+    stacks.sort_by_key(|item| item.quantity);
+
+    let mut remaining = quantity;
+    for mut item in stacks {
+        if remaining == 0 {
+            break;
+        }
+        let taken = remaining.min(item.quantity as u32);
+        remaining -= taken;
+        item.quantity -= taken as u16;
+        if item.quantity == 0 {
+            ctx.db.inventory_item().id().delete(item.id);
+        } else {
+            ctx.db.inventory_item().id().update(item);
+        }
+    }
+    quest::refresh_collected(ctx, character_id);
+    mount::refresh_owned(ctx, character_id);
+
+    Ok(())
+}
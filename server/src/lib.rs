@@ -1,13 +1,41 @@
+mod area;
+mod auction;
 mod character;
+mod collision;
+mod combat;
+mod currency;
+mod dialogue;
+mod dungeon;
+mod effect;
+mod guild;
+mod inventory;
+mod lore;
+mod mail;
+mod map;
+mod mount;
 mod movement;
+mod npc;
+mod party;
+mod profession;
 mod progression;
+mod quest;
 mod seed;
+mod social;
+mod stealth;
+mod summon;
+mod threat;
+mod trade;
+mod travel;
 mod types;
+mod veil;
+mod vendor;
 
+use dialogue::dialogue_session;
 use movement::entity_movement;
 use seed::seed_static_data;
-use spacetimedb::{Identity, ReducerContext, Table, table};
+use spacetimedb::{Filter, Identity, ReducerContext, Table, client_visibility_filter, table};
 use types::*;
+use veil::veil_exposure;
 
 use crate::character::{CharacterPawn, character_def, character_pawn};
 
@@ -17,7 +45,11 @@ struct Player {
     identity: Identity,
 }
 
-#[table(name = transform, public)]
+#[table(
+    name = transform,
+    public,
+    index(name = map_chunk, btree(columns = [map_id, chunk_id]))
+)]
 pub struct Transform {
     #[primary_key]
     #[auto_inc]
@@ -32,10 +64,16 @@ pub struct Transform {
     /// Scale of the entity.
     pub scale: Vec3,
 
+    /// The map the entity is on, see `map`. Positions on different maps never meet.
+    pub map_id: u32,
+
     /// Deterministic spatial hashing computed at runtime when transform gets updated.
     /// Used to filter down the transforms required to be processed for spatial filters.
-    #[index(btree)]
+    /// Only meaningful together with `map_id`, which is why they share an index.
     pub chunk_id: u32,
+
+    /// Stealthed transforms are only sent to the clients that detect them, see `stealth`.
+    pub stealthed: bool,
 }
 
 /// An ephemeral, generic representation of an in-game entity "spawned" into the world.
@@ -73,6 +111,28 @@ pub struct Race {
     pub description: String,
 }
 
+#[client_visibility_filter]
+const HEALTH_OWNER: Filter = Filter::Sql(
+    "SELECT health.* FROM health JOIN character_def ON health.id = character_def.health_id WHERE character_def.identity = :sender",
+);
+
+/// Party members see each other's health wherever they are.
+#[client_visibility_filter]
+const HEALTH_PARTY: Filter = Filter::Sql(
+    "SELECT health.* FROM health JOIN party_member ON health.id = party_member.health_id JOIN party_member AS viewer ON party_member.party_id = viewer.party_id WHERE viewer.identity = :sender",
+);
+
+/// NPC health is visible to everyone, for target frames and nameplates.
+#[client_visibility_filter]
+const HEALTH_NPC: Filter =
+    Filter::Sql("SELECT health.* FROM health JOIN npc ON health.id = npc.health_id");
+
+/// Characters in the world see the health of everyone on the same map as them.
+#[client_visibility_filter]
+const HEALTH_WORLD: Filter = Filter::Sql(
+    "SELECT health.* FROM health JOIN character_pawn ON health.id = character_pawn.health_id JOIN character_pawn AS viewer ON character_pawn.map_id = viewer.map_id WHERE viewer.identity = :sender",
+);
+
 #[table(name = health, public)]
 pub struct Health {
     #[primary_key]
@@ -99,6 +159,23 @@ impl Health {
     }
 }
 
+#[client_visibility_filter]
+const MANA_OWNER: Filter = Filter::Sql(
+    "SELECT mana.* FROM mana JOIN character_def ON mana.id = character_def.mana_id WHERE character_def.identity = :sender",
+);
+
+/// Party members see each other's mana wherever they are.
+#[client_visibility_filter]
+const MANA_PARTY: Filter = Filter::Sql(
+    "SELECT mana.* FROM mana JOIN party_member ON mana.id = party_member.mana_id JOIN party_member AS viewer ON party_member.party_id = viewer.party_id WHERE viewer.identity = :sender",
+);
+
+/// Characters in the world see the mana of everyone on the same map as them.
+#[client_visibility_filter]
+const MANA_WORLD: Filter = Filter::Sql(
+    "SELECT mana.* FROM mana JOIN character_pawn ON mana.id = character_pawn.mana_id JOIN character_pawn AS viewer ON character_pawn.map_id = viewer.map_id WHERE viewer.identity = :sender",
+);
+
 #[table(name = mana, public)]
 pub struct Mana {
     #[primary_key]
@@ -125,10 +202,57 @@ impl Mana {
     }
 }
 
+/// Looks up the current translation of a spawned entity.
+pub(crate) fn entity_translation(ctx: &ReducerContext, entity_id: u32) -> Option<Vec3> {
+    let entity = ctx.db.entity().id().find(entity_id)?;
+    let transform = ctx.db.transform().id().find(entity.transform_id)?;
+    Some(transform.translation)
+}
+
+/// Looks up the map a spawned entity is on and its translation there.
+pub(crate) fn entity_location(ctx: &ReducerContext, entity_id: u32) -> Option<(u32, Vec3)> {
+    let entity = ctx.db.entity().id().find(entity_id)?;
+    let transform = ctx.db.transform().id().find(entity.transform_id)?;
+    Some((transform.map_id, transform.translation))
+}
+
+/// Whether two spawned entities are on the same map and within `range_squared` of each
+/// other. Entities that can't be found are never in range.
+pub(crate) fn entities_in_range(
+    ctx: &ReducerContext,
+    a_entity_id: u32,
+    b_entity_id: u32,
+    range_squared: f32,
+) -> bool {
+    match (
+        entity_location(ctx, a_entity_id),
+        entity_location(ctx, b_entity_id),
+    ) {
+        (Some((a_map_id, a)), Some((b_map_id, b))) => {
+            a_map_id == b_map_id && a.distance_squared_xz(b) <= range_squared
+        }
+        _ => false,
+    }
+}
+
 #[spacetimedb::reducer(init)]
 pub fn init(ctx: &ReducerContext) {
     seed_static_data(ctx);
     movement::init(ctx);
+    trade::init(ctx);
+    npc::init(ctx);
+    quest::init(ctx);
+    veil::init(ctx);
+    lore::init(ctx);
+    vendor::init(ctx);
+    profession::init(ctx);
+    mail::init(ctx);
+    effect::init(ctx);
+    threat::init(ctx);
+    stealth::init(ctx);
+    summon::init(ctx);
+    dungeon::init(ctx);
+    travel::init(ctx);
 }
 
 #[spacetimedb::reducer(client_connected)]
@@ -192,7 +316,11 @@ pub fn enter_world(ctx: &ReducerContext, character_id: u32) -> Result<(), String
         identity: ctx.sender,
         entity_id: entity.id,
         character_id: character.id,
+        health_id: character.health_id,
+        mana_id: character.mana_id,
+        map_id: transform.map_id,
     });
+    social::enter_world(ctx, ctx.sender, character.id);
 
     Ok(())
 }
@@ -202,6 +330,21 @@ pub fn leave_world(ctx: &ReducerContext) -> Result<(), String> {
     let Some(ci) = ctx.db.character_pawn().identity().find(ctx.sender) else {
         return Err(format!("No valid character instance"));
     };
+    trade::cancel_for(ctx, ctx.sender);
+    party::leave_world(ctx, ctx.sender, ci.character_id);
+    social::leave_world(ctx, ctx.sender, ci.character_id);
+    ctx.db
+        .dialogue_session()
+        .character_id()
+        .delete(ci.character_id);
+    profession::cancel_gather_for(ctx, ci.character_id);
+    travel::cancel_teleport_for(ctx, ci.character_id);
+    effect::clear(ctx, ci.entity_id);
+    ctx.db.veil_exposure().entity_id().delete(ci.entity_id);
+    threat::forget_target(ctx, ci.entity_id);
+    stealth::break_stealth(ctx, ci.entity_id);
+    mount::dismount_entity(ctx, ci.entity_id);
+    summon::despawn_for_owner(ctx, ci.entity_id);
     ctx.db.character_pawn().identity().delete(ctx.sender);
     ctx.db.entity_movement().entity_id().delete(ci.entity_id);
     ctx.db.entity().id().delete(ci.entity_id);
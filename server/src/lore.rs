@@ -0,0 +1,231 @@
+//! Lore collectibles: journals, Veil shards and ruins scattered across the world.
+//!
+//! Every lore entry is placed in the world as an entity that characters can discover.
+//! Entries are grouped into collections, and completing a collection permanently raises
+//! the character's max health and mana.
+
+use crate::{
+    Entity, Transform,
+    character::{character_def, character_pawn},
+    entities_in_range, entity, health, mana,
+    map::OVERWORLD_MAP_ID,
+    progression, transform,
+    types::{Quat, Vec3},
+};
+use common::chunk;
+use spacetimedb::{
+    Filter, ReducerContext, SpacetimeType, Table, Timestamp, client_visibility_filter, reducer,
+    table,
+};
+
+/// How close a character needs to be to discover a lore object.
+const DISCOVER_RANGE_SQUARED: f32 = 5.0 * 5.0;
+
+#[client_visibility_filter]
+const LORE_UNLOCKED_SECURITY: Filter = Filter::Sql(
+    "SELECT lore_unlocked.* FROM lore_unlocked JOIN character_def ON lore_unlocked.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+#[client_visibility_filter]
+const LORE_COLLECTION_COMPLETED_SECURITY: Filter = Filter::Sql(
+    "SELECT lore_collection_completed.* FROM lore_collection_completed JOIN character_def ON lore_collection_completed.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum LoreKind {
+    Journal,
+    Shard,
+    Ruin,
+}
+
+/// A group of lore entries with a bonus for discovering all of them.
+#[table(name = lore_collection, public)]
+pub struct LoreCollection {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub description: String,
+
+    pub bonus_max_health: u16,
+    pub bonus_max_mana: u16,
+}
+
+/// A piece of lore and where it can be found.
+#[table(name = lore_entry, public)]
+pub struct LoreEntry {
+    #[primary_key]
+    pub id: u32,
+
+    #[index(btree)]
+    pub collection_id: u32,
+
+    pub kind: LoreKind,
+    pub title: String,
+    pub text: String,
+
+    pub translation: Vec3,
+    pub xp_reward: u32,
+}
+
+/// A type-narrowing table for in-game entities that are lore objects.
+#[table(name = lore_object, public)]
+pub struct LoreObject {
+    #[primary_key]
+    pub entity_id: u32,
+
+    #[unique]
+    pub lore_entry_id: u32,
+}
+
+/// A lore entry discovered by a character.
+#[table(name = lore_unlocked, public)]
+pub struct LoreUnlocked {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub character_id: u32,
+
+    pub lore_entry_id: u32,
+    pub unlocked_at: Timestamp,
+}
+
+/// A collection a character has completed, granting its bonus.
+#[table(name = lore_collection_completed, public)]
+pub struct LoreCollectionCompleted {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub character_id: u32,
+
+    pub collection_id: u32,
+    pub completed_at: Timestamp,
+}
+
+/// Places every lore entry that isn't in the world yet.
+pub fn init(ctx: &ReducerContext) {
+    for lore_entry in ctx.db.lore_entry().iter() {
+        if ctx
+            .db
+            .lore_object()
+            .lore_entry_id()
+            .find(lore_entry.id)
+            .is_some()
+        {
+            continue;
+        }
+
+        let transform = ctx.db.transform().insert(Transform {
+            id: 0,
+            translation: lore_entry.translation,
+            rotation: Quat::default(),
+            scale: Vec3::default(),
+            map_id: OVERWORLD_MAP_ID,
+            chunk_id: chunk::encode(lore_entry.translation.x, lore_entry.translation.z),
+            stealthed: false,
+        });
+        let entity = ctx.db.entity().insert(Entity {
+            id: 0,
+            transform_id: transform.id,
+        });
+        ctx.db.lore_object().insert(LoreObject {
+            entity_id: entity.id,
+            lore_entry_id: lore_entry.id,
+        });
+    }
+}
+
+fn is_unlocked(ctx: &ReducerContext, character_id: u32, lore_entry_id: u32) -> bool {
+    ctx.db
+        .lore_unlocked()
+        .character_id()
+        .filter(character_id)
+        .any(|row| row.lore_entry_id == lore_entry_id)
+}
+
+/// Raises a character's max health and mana by a collection's bonus.
+fn grant_collection_bonus(ctx: &ReducerContext, character_id: u32, collection: &LoreCollection) {
+    let Some(character) = ctx.db.character_def().id().find(character_id) else {
+        return;
+    };
+    if let Some(mut health) = ctx.db.health().id().find(character.health_id) {
+        health.max_health = health
+            .max_health
+            .saturating_add(collection.bonus_max_health);
+        ctx.db.health().id().update(health);
+    }
+    if let Some(mut mana) = ctx.db.mana().id().find(character.mana_id) {
+        mana.max_mana = mana.max_mana.saturating_add(collection.bonus_max_mana);
+        ctx.db.mana().id().update(mana);
+    }
+}
+
+/// Discovers a nearby lore object, unlocking its entry in the codex.
+#[reducer]
+pub fn discover_lore(ctx: &ReducerContext, entity_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("discover_lore: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(lore_entry) = ctx
+        .db
+        .lore_object()
+        .entity_id()
+        .find(entity_id)
+        .and_then(|object| ctx.db.lore_entry().id().find(object.lore_entry_id))
+    else {
+        let err = format!("discover_lore: target is not a lore object.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    let in_reach = entities_in_range(ctx, pawn.entity_id, entity_id, DISCOVER_RANGE_SQUARED);
+    if !in_reach {
+        let err = format!("discover_lore: lore object is too far away.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if is_unlocked(ctx, pawn.character_id, lore_entry.id) {
+        let err = format!("discover_lore: already discovered.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    ctx.db.lore_unlocked().insert(LoreUnlocked {
+        id: 0,
+        character_id: pawn.character_id,
+        lore_entry_id: lore_entry.id,
+        unlocked_at: ctx.timestamp,
+    });
+    progression::grant_xp(ctx, pawn.character_id, lore_entry.xp_reward);
+
+    let complete = ctx
+        .db
+        .lore_entry()
+        .collection_id()
+        .filter(lore_entry.collection_id)
+        .all(|entry| is_unlocked(ctx, pawn.character_id, entry.id));
+    if complete
+        && let Some(collection) = ctx.db.lore_collection().id().find(lore_entry.collection_id)
+    {
+        ctx.db
+            .lore_collection_completed()
+            .insert(LoreCollectionCompleted {
+                id: 0,
+                character_id: pawn.character_id,
+                collection_id: collection.id,
+                completed_at: ctx.timestamp,
+            });
+        grant_collection_bonus(ctx, pawn.character_id, &collection);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,506 @@
+//! Mail between characters, delivered whether or not the recipient is online.
+//!
+//! Attached items and currency leave the sender as soon as a mail is sent and are held
+//! on the mail itself until the recipient takes them at a mailbox. Mail with
+//! cash-on-delivery can only be taken by paying, and the payment is mailed back to the
+//! sender. Mail nobody takes is returned to its sender once it expires. Mail that can't
+//! go back, like payments from the game, keeps its attachments until they are taken.
+
+use crate::{
+    Entity, Transform,
+    character::{character_def, character_pawn},
+    currency::{self, CurrencyReason},
+    entities_in_range, entity, inventory,
+    map::OVERWORLD_MAP_ID,
+    social, transform,
+    types::{Quat, Vec3},
+};
+use common::chunk;
+use spacetimedb::{
+    Filter, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp,
+    client_visibility_filter, reducer, table,
+};
+
+/// How close a character needs to be to use a mailbox.
+const MAILBOX_RANGE_SQUARED: f32 = 5.0 * 5.0;
+/// How many item stacks a single mail can carry.
+const MAX_ATTACHMENTS: usize = 8;
+/// How long mail waits to be taken before it is returned.
+const MAIL_EXPIRY_MICROS: i64 = 30 * 24 * 60 * 60 * 1_000_000;
+const MAX_SUBJECT_LEN: usize = 64;
+const MAX_BODY_LEN: usize = 1000;
+
+#[client_visibility_filter]
+const MAIL_SECURITY: Filter = Filter::Sql(
+    "SELECT mail.* FROM mail JOIN character_def ON mail.recipient_character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+/// An item stack held on a mail.
+#[derive(SpacetimeType, Clone, Copy)]
+pub struct MailItem {
+    pub item_def_id: u32,
+    pub quantity: u16,
+}
+
+/// An inventory stack, or part of one, to attach to a mail being sent.
+#[derive(SpacetimeType, Clone, Copy)]
+pub struct MailAttachment {
+    pub inventory_item_id: u32,
+    pub quantity: u16,
+}
+
+/// A static location a mailbox is placed at.
+#[table(name = mailbox_spawn)]
+pub struct MailboxSpawn {
+    #[primary_key]
+    pub id: u32,
+
+    pub translation: Vec3,
+}
+
+/// A type-narrowing table for in-game entities that are mailboxes.
+#[table(name = mailbox, public)]
+pub struct Mailbox {
+    #[primary_key]
+    pub entity_id: u32,
+
+    #[unique]
+    pub spawn_id: u32,
+}
+
+#[table(name = mail, public)]
+pub struct Mail {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub recipient_character_id: u32,
+
+    /// Who the mail is returned to, `None` for mail sent by the game itself
+    pub sender_character_id: Option<u32>,
+    pub sender_name: String,
+
+    pub subject: String,
+    pub body: String,
+
+    pub items: Vec<MailItem>,
+    pub currency: u64,
+
+    /// What the recipient has to pay to take the items, zero for none
+    pub cod: u64,
+
+    /// Whether this mail is on its way back to its sender
+    pub returned: bool,
+
+    pub sent_at: Timestamp,
+    pub expires_at: Timestamp,
+}
+
+#[table(name = mail_expiry_timer, scheduled(mail_expire))]
+struct MailExpiryTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+
+    #[index(btree)]
+    mail_id: u32,
+}
+
+/// Places every mailbox that isn't in the world yet.
+pub fn init(ctx: &ReducerContext) {
+    for spawn in ctx.db.mailbox_spawn().iter() {
+        if ctx.db.mailbox().spawn_id().find(spawn.id).is_some() {
+            continue;
+        }
+
+        let transform = ctx.db.transform().insert(Transform {
+            id: 0,
+            translation: spawn.translation,
+            rotation: Quat::default(),
+            scale: Vec3::default(),
+            map_id: OVERWORLD_MAP_ID,
+            chunk_id: chunk::encode(spawn.translation.x, spawn.translation.z),
+            stealthed: false,
+        });
+        let entity = ctx.db.entity().insert(Entity {
+            id: 0,
+            transform_id: transform.id,
+        });
+        ctx.db.mailbox().insert(Mailbox {
+            entity_id: entity.id,
+            spawn_id: spawn.id,
+        });
+    }
+}
+
+/// Makes sure the sender's character is standing next to a mailbox, returning the
+/// character's id.
+fn at_mailbox(ctx: &ReducerContext, reducer: &str, mailbox_entity_id: u32) -> Result<u32, String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("{reducer}: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    if ctx
+        .db
+        .mailbox()
+        .entity_id()
+        .find(mailbox_entity_id)
+        .is_none()
+    {
+        let err = format!("{reducer}: target is not a mailbox.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    let in_reach = entities_in_range(
+        ctx,
+        pawn.entity_id,
+        mailbox_entity_id,
+        MAILBOX_RANGE_SQUARED,
+    );
+    if !in_reach {
+        let err = format!("{reducer}: mailbox is too far away.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    Ok(pawn.character_id)
+}
+
+/// Delivers a mail and schedules it to expire.
+fn deliver(ctx: &ReducerContext, mail: Mail) -> Mail {
+    let expires_at = mail.expires_at;
+    let mail = ctx.db.mail().insert(mail);
+    ctx.db.mail_expiry_timer().insert(MailExpiryTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(expires_at),
+        mail_id: mail.id,
+    });
+    mail
+}
+
+fn delete_mail_row(ctx: &ReducerContext, mail_id: u32) {
+    ctx.db.mail().id().delete(mail_id);
+    ctx.db.mail_expiry_timer().mail_id().delete(mail_id);
+}
+
+/// Sends mail from the game itself, e.g. payments and refunds. It cannot be returned.
+pub(crate) fn send_system_mail(
+    ctx: &ReducerContext,
+    recipient_character_id: u32,
+    sender_name: &str,
+    subject: String,
+    items: Vec<MailItem>,
+    currency: u64,
+) {
+    deliver(
+        ctx,
+        Mail {
+            id: 0,
+            recipient_character_id,
+            sender_character_id: None,
+            sender_name: sender_name.into(),
+            subject,
+            body: String::new(),
+            items,
+            currency,
+            cod: 0,
+            returned: false,
+            sent_at: ctx.timestamp,
+            expires_at: ctx.timestamp + TimeDuration::from_micros(MAIL_EXPIRY_MICROS),
+        },
+    );
+}
+
+/// Sends a mail back to its sender with whatever is still attached. Mail without a
+/// sender, or whose sender no longer exists, is deleted instead.
+fn return_to_sender(ctx: &ReducerContext, mail: Mail) {
+    delete_mail_row(ctx, mail.id);
+
+    let Some(sender) = mail
+        .sender_character_id
+        .and_then(|id| ctx.db.character_def().id().find(id))
+    else {
+        return;
+    };
+    if mail.items.is_empty() && mail.currency == 0 {
+        return;
+    }
+    let recipient_name = ctx
+        .db
+        .character_def()
+        .id()
+        .find(mail.recipient_character_id)
+        .map(|character| character.name)
+        .unwrap_or_default();
+
+    deliver(
+        ctx,
+        Mail {
+            id: 0,
+            recipient_character_id: sender.id,
+            sender_character_id: None,
+            sender_name: recipient_name,
+            subject: format!("Returned: {}", mail.subject),
+            body: mail.body,
+            items: mail.items,
+            currency: mail.currency,
+            cod: 0,
+            returned: true,
+            sent_at: ctx.timestamp,
+            expires_at: ctx.timestamp + TimeDuration::from_micros(MAIL_EXPIRY_MICROS),
+        },
+    );
+}
+
+/// Removes all mail addressed to a character, returning what other characters sent
+/// them. Only what was the character's own, like payments and returned mail, is lost.
+pub(crate) fn delete_for(ctx: &ReducerContext, character_id: u32) {
+    let mails: Vec<Mail> = ctx
+        .db
+        .mail()
+        .recipient_character_id()
+        .filter(character_id)
+        .collect();
+    for mail in mails {
+        return_to_sender(ctx, mail);
+    }
+}
+
+#[reducer]
+pub fn send_mail(
+    ctx: &ReducerContext,
+    mailbox_entity_id: u32,
+    recipient_name: String,
+    subject: String,
+    body: String,
+    attachments: Vec<MailAttachment>,
+    currency: u64,
+    cod: u64,
+) -> Result<(), String> {
+    let character_id = at_mailbox(ctx, "send_mail", mailbox_entity_id)?;
+    let Some(sender) = ctx.db.character_def().id().find(character_id) else {
+        let err = format!("send_mail: unable to find character for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let recipient_name = recipient_name.trim();
+    let Some(recipient) = ctx.db.character_def().name().filter(recipient_name).next() else {
+        let err = format!("send_mail: no character named {recipient_name}.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if recipient.id == sender.id {
+        let err = format!("send_mail: cannot mail yourself.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if social::is_ignoring(ctx, recipient.identity, ctx.sender) {
+        let err = format!("send_mail: {} is not accepting your mail.", recipient.name);
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if subject.len() > MAX_SUBJECT_LEN || body.len() > MAX_BODY_LEN {
+        let err = format!("send_mail: message is too long.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if attachments.len() > MAX_ATTACHMENTS {
+        let err = format!("send_mail: too many attachments.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if cod > 0 && (attachments.is_empty() || currency > 0) {
+        let err = format!("send_mail: cash on delivery needs items and no currency attached.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    // Attachments leave the sender right away, so they can't be sold or traded while
+    // the mail is in flight
+    let mut items = Vec::with_capacity(attachments.len());
+    for attachment in &attachments {
+        let item_def_id = inventory::remove_item(
+            ctx,
+            sender.id,
+            attachment.inventory_item_id,
+            attachment.quantity,
+        )
+        .map_err(|err| format!("send_mail: {err}"))?;
+        items.push(MailItem {
+            item_def_id,
+            quantity: attachment.quantity,
+        });
+    }
+
+    let mail = deliver(
+        ctx,
+        Mail {
+            id: 0,
+            recipient_character_id: recipient.id,
+            sender_character_id: Some(sender.id),
+            sender_name: sender.name,
+            subject,
+            body,
+            items,
+            currency,
+            cod,
+            returned: false,
+            sent_at: ctx.timestamp,
+            expires_at: ctx.timestamp + TimeDuration::from_micros(MAIL_EXPIRY_MICROS),
+        },
+    );
+    currency::withdraw(ctx, sender.id, currency, CurrencyReason::MailSent(mail.id))
+        .map_err(|err| format!("send_mail: {err}"))?;
+
+    Ok(())
+}
+
+/// Takes everything attached to a mail, paying its cash-on-delivery first.
+#[reducer]
+pub fn take_mail(ctx: &ReducerContext, mailbox_entity_id: u32, mail_id: u32) -> Result<(), String> {
+    let character_id = at_mailbox(ctx, "take_mail", mailbox_entity_id)?;
+    let Some(mut mail) = ctx
+        .db
+        .mail()
+        .id()
+        .find(mail_id)
+        .filter(|mail| mail.recipient_character_id == character_id)
+    else {
+        let err = format!("take_mail: mail not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    // Nobody is left to pay once the sender is deleted, so their cash on delivery is
+    // waived
+    let cod_sender = mail
+        .sender_character_id
+        .and_then(|id| ctx.db.character_def().id().find(id));
+    if let Some(sender) = cod_sender.filter(|_| mail.cod > 0) {
+        currency::withdraw(
+            ctx,
+            character_id,
+            mail.cod,
+            CurrencyReason::MailCod(mail.id),
+        )
+        .map_err(|err| format!("take_mail: {err}"))?;
+        send_system_mail(
+            ctx,
+            sender.id,
+            "Postmaster",
+            format!("Cash on delivery: {}", mail.subject),
+            Vec::new(),
+            mail.cod,
+        );
+    }
+
+    for item in &mail.items {
+        inventory::add_item(ctx, character_id, item.item_def_id, item.quantity)
+            .map_err(|err| format!("take_mail: {err}"))?;
+    }
+    currency::deposit(
+        ctx,
+        character_id,
+        mail.currency,
+        CurrencyReason::MailReceived(mail.id),
+    )
+    .map_err(|err| format!("take_mail: {err}"))?;
+
+    mail.items.clear();
+    mail.currency = 0;
+    mail.cod = 0;
+    ctx.db.mail().id().update(mail);
+
+    Ok(())
+}
+
+/// Sends a mail back to its sender without taking anything, e.g. to refuse paying
+/// its cash-on-delivery.
+#[reducer]
+pub fn return_mail(
+    ctx: &ReducerContext,
+    mailbox_entity_id: u32,
+    mail_id: u32,
+) -> Result<(), String> {
+    let character_id = at_mailbox(ctx, "return_mail", mailbox_entity_id)?;
+    let Some(mail) = ctx
+        .db
+        .mail()
+        .id()
+        .find(mail_id)
+        .filter(|mail| mail.recipient_character_id == character_id)
+    else {
+        let err = format!("return_mail: mail not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if mail.returned || mail.sender_character_id.is_none() {
+        let err = format!("return_mail: mail cannot be returned.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    return_to_sender(ctx, mail);
+
+    Ok(())
+}
+
+/// Deletes a mail that has nothing left attached.
+#[reducer]
+pub fn delete_mail(ctx: &ReducerContext, mail_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("delete_mail: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(mail) = ctx
+        .db
+        .mail()
+        .id()
+        .find(mail_id)
+        .filter(|mail| mail.recipient_character_id == pawn.character_id)
+    else {
+        let err = format!("delete_mail: mail not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if !mail.items.is_empty() || mail.currency > 0 {
+        let err = format!("delete_mail: take the attachments first.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    delete_mail_row(ctx, mail.id);
+
+    Ok(())
+}
+
+/// Returns expired mail to its sender. Mail that can't go back, because the game sent it,
+/// it was already returned or its sender was deleted, is only deleted once it has
+/// nothing attached.
+#[reducer]
+fn mail_expire(ctx: &ReducerContext, timer: MailExpiryTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`mail_expire` may not be invoked by clients.".into());
+    }
+
+    let Some(mail) = ctx.db.mail().id().find(timer.mail_id) else {
+        return Ok(());
+    };
+    let sender_exists = mail
+        .sender_character_id
+        .and_then(|id| ctx.db.character_def().id().find(id))
+        .is_some();
+    if !mail.returned && sender_exists {
+        return_to_sender(ctx, mail);
+    } else if mail.items.is_empty() && mail.currency == 0 {
+        delete_mail_row(ctx, mail.id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//! The maps the world is split into.
+//!
+//! Every transform belongs to a map and positions on different maps never meet: the
+//! spatial index on `transform` is keyed by `(map_id, chunk_id)`, range checks and
+//! movement require both entities to be on the same map, and clients are only sent the
+//! transforms of the map their character is on, see the filters in `stealth`. The open
+//! world is [`OVERWORLD_MAP_ID`]. Dungeons and housing get maps of their own.
+
+use crate::{
+    character::character_pawn,
+    entity_location, movement,
+    summon::{self, SummonKind, owned_entity},
+    threat::{self, npc_threat},
+    types::Vec3,
+};
+use spacetimedb::{ReducerContext, SpacetimeType, reducer, table};
+
+/// The map of Aelynmar itself, where characters are created.
+pub const OVERWORLD_MAP_ID: u32 = 1;
+/// Static maps are seeded with IDs below this, instances are numbered from it.
+pub const FIRST_INSTANCE_MAP_ID: u32 = 1_000_000;
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum MapKind {
+    /// Open to everyone, and can be travelled to with `transfer_map`
+    World,
+    /// Only entered through a dungeon's entrance
+    Dungeon,
+    /// Only entered by the owner and their guests
+    Housing,
+}
+
+#[table(name = map, public)]
+pub struct Map {
+    #[primary_key]
+    pub id: u32,
+
+    pub name: String,
+
+    pub kind: MapKind,
+
+    /// Where entities transferred onto the map arrive
+    pub arrival: Vec3,
+}
+
+/// Moves an entity onto another map, along with the pets following it. Whatever tied it
+/// to the map it left is dropped: NPCs forget it and its totems are dismissed.
+pub(crate) fn transfer_entity(
+    ctx: &ReducerContext,
+    entity_id: u32,
+    map_id: u32,
+    translation: Vec3,
+) -> Result<(), String> {
+    movement::transfer(ctx, entity_id, map_id, translation)?;
+    threat::forget_target(ctx, entity_id);
+    if let Some(mut pawn) = ctx.db.character_pawn().entity_id().filter(entity_id).next() {
+        pawn.map_id = map_id;
+        ctx.db.character_pawn().id().update(pawn);
+    }
+
+    let owned: Vec<_> = ctx
+        .db
+        .owned_entity()
+        .owner_entity_id()
+        .filter(entity_id)
+        .collect();
+    for owned in owned {
+        match owned.kind {
+            SummonKind::Pet => {
+                movement::transfer(ctx, owned.entity_id, map_id, translation)?;
+                threat::forget_target(ctx, owned.entity_id);
+            }
+            SummonKind::Totem => summon::despawn(ctx, owned.entity_id),
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves the sender's character to the arrival point of another open world map.
+#[reducer]
+pub fn transfer_map(ctx: &ReducerContext, map_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("transfer_map: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(map) = ctx.db.map().id().find(map_id) else {
+        let err = format!("transfer_map: map {map_id} not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    if map.kind != MapKind::World {
+        let err = format!(
+            "transfer_map: {} can only be entered through its entrance.",
+            map.name
+        );
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    let Some((current_map_id, _)) = entity_location(ctx, pawn.entity_id) else {
+        let err = format!("transfer_map: unable to find transform.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    if current_map_id == map.id {
+        let err = format!("transfer_map: already on {}.", map.name);
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if ctx
+        .db
+        .npc_threat()
+        .target_entity_id()
+        .filter(pawn.entity_id)
+        .next()
+        .is_some()
+    {
+        let err = format!("transfer_map: cannot change maps in combat.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    transfer_entity(ctx, pawn.entity_id, map.id, map.arrival)
+        .map_err(|err| format!("transfer_map: {err}"))
+}
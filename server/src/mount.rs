@@ -0,0 +1,158 @@
+//! Mounts.
+//!
+//! A mount is ridden by owning its item, or learned as a spell by the class it belongs
+//! to. Riding multiplies the rider's movement speed on top of their effects. Riders are
+//! knocked off their mount as soon as they enter combat or deal or take damage, and
+//! can't mount again until they are out of combat. Selling, trading or otherwise giving
+//! away the item a mount came from also knocks its rider off.
+
+use crate::{
+    character::{character_def, character_pawn},
+    inventory,
+    threat::npc_threat,
+};
+use spacetimedb::{ReducerContext, Table, reducer, table};
+
+/// The static definition of a mount.
+#[table(name = mount_def, public)]
+pub struct MountDef {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub description: String,
+
+    /// How much faster its rider moves, in percent
+    pub speed_percent: u16,
+
+    /// The item that lets its owner ride the mount, `None` if no item does
+    pub item_def_id: Option<u32>,
+
+    /// The class that can call the mount as a spell, `None` if no class can
+    pub class_id: Option<u32>,
+}
+
+/// A type-narrowing table for in-game entities riding a mount. Public so every client
+/// can show the mount under its rider.
+#[table(name = mounted, public)]
+pub struct Mounted {
+    #[primary_key]
+    pub entity_id: u32,
+
+    pub mount_def_id: u32,
+}
+
+/// How much a mount speeds up an entity, `1.0` when it isn't riding one.
+pub(crate) fn speed_multiplier(ctx: &ReducerContext, entity_id: u32) -> f32 {
+    ctx.db
+        .mounted()
+        .entity_id()
+        .find(entity_id)
+        .and_then(|mounted| ctx.db.mount_def().id().find(mounted.mount_def_id))
+        .map_or(1.0, |mount_def| {
+            1.0 + mount_def.speed_percent as f32 / 100.0
+        })
+}
+
+/// Whether a character owns the item of a mount or knows it as a class spell.
+fn can_ride(ctx: &ReducerContext, character_id: u32, mount_def: &MountDef) -> bool {
+    let owns_item = mount_def
+        .item_def_id
+        .is_some_and(|item_def_id| inventory::item_count(ctx, character_id, item_def_id) > 0);
+    let knows_spell = mount_def.class_id.is_some()
+        && ctx
+            .db
+            .character_def()
+            .id()
+            .find(character_id)
+            .map(|character| character.class_id)
+            == mount_def.class_id;
+    owns_item || knows_spell
+}
+
+/// Dismounts a character that can no longer ride its mount. Called whenever items leave
+/// a character's inventory, in case they were the mount's.
+pub(crate) fn refresh_owned(ctx: &ReducerContext, character_id: u32) {
+    let Some(pawn) = ctx
+        .db
+        .character_pawn()
+        .character_id()
+        .filter(character_id)
+        .next()
+    else {
+        return;
+    };
+    let Some(mount_def) = ctx
+        .db
+        .mounted()
+        .entity_id()
+        .find(pawn.entity_id)
+        .and_then(|mounted| ctx.db.mount_def().id().find(mounted.mount_def_id))
+    else {
+        return;
+    };
+    if !can_ride(ctx, character_id, &mount_def) {
+        dismount_entity(ctx, pawn.entity_id);
+    }
+}
+
+/// Knocks an entity off its mount, if it is riding one.
+pub(crate) fn dismount_entity(ctx: &ReducerContext, entity_id: u32) {
+    ctx.db.mounted().entity_id().delete(entity_id);
+}
+
+/// Mounts the sender's character, replacing the mount they are riding.
+#[reducer]
+pub fn mount(ctx: &ReducerContext, mount_def_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("mount: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(mount_def) = ctx.db.mount_def().id().find(mount_def_id) else {
+        let err = format!("mount: invalid mount.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if !can_ride(ctx, pawn.character_id, &mount_def) {
+        let err = format!("mount: you cannot ride {}.", mount_def.name);
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if ctx
+        .db
+        .npc_threat()
+        .target_entity_id()
+        .filter(pawn.entity_id)
+        .next()
+        .is_some()
+    {
+        let err = format!("mount: cannot mount in combat.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    dismount_entity(ctx, pawn.entity_id);
+    ctx.db.mounted().insert(Mounted {
+        entity_id: pawn.entity_id,
+        mount_def_id: mount_def.id,
+    });
+
+    Ok(())
+}
+
+/// Gets the sender's character off the mount they are riding.
+#[reducer]
+pub fn dismount(ctx: &ReducerContext) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("dismount: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    dismount_entity(ctx, pawn.entity_id);
+
+    Ok(())
+}
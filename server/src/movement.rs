@@ -1,11 +1,22 @@
-use crate::{character::character_pawn, entity, transform, types::Vec3};
-use common::calculate_step::calculate_step_2d;
+use crate::{
+    Transform,
+    character::character_pawn,
+    effect::{self, Stat},
+    entity, mount, transform, travel,
+    types::{Quat, Vec3},
+};
+use common::{calculate_step::calculate_step, chunk, facing};
 use spacetimedb::{ReducerContext, SpacetimeType, Table, TimeDuration, Timestamp, reducer, table};
+use std::f32::consts::TAU;
 
 /// The HZ (FPS) at which the server should tick for movement.
 const TICK_RATE: i64 = 30;
 const DELTA_MICRO_SECS: i64 = 1_000_000 / TICK_RATE;
 const MAX_MOVE_DISTANCE_SQUARED: f32 = 50.0 * 50.0;
+/// How fast entities move before any modifiers, in meters per second.
+const BASE_MOVE_SPEED: f32 = 5.0;
+/// How fast moving entities turn towards where they are going, in radians per second.
+const TURN_RATE: f32 = TAU;
 
 #[derive(SpacetimeType)]
 pub enum MoveIntent {
@@ -44,6 +55,95 @@ pub fn init(ctx: &ReducerContext) {
     });
 }
 
+/// Replaces whatever movement an entity is doing. Setting the entity it is already
+/// chasing is a no-op, so callers can set their intent on every tick.
+pub(crate) fn set_intent(ctx: &ReducerContext, entity_id: u32, intent: MoveIntent) {
+    match ctx.db.entity_movement().entity_id().find(entity_id) {
+        Some(mut movement) => {
+            if let (MoveIntent::Entity(current), MoveIntent::Entity(target)) =
+                (&movement.intent, &intent)
+                && current == target
+            {
+                return;
+            }
+            movement.intent = intent;
+            ctx.db.entity_movement().entity_id().update(movement);
+        }
+        None => {
+            ctx.db
+                .entity_movement()
+                .insert(EntityMovement { entity_id, intent });
+        }
+    }
+}
+
+fn find_transform(ctx: &ReducerContext, entity_id: u32) -> Result<Transform, String> {
+    ctx.db
+        .entity()
+        .id()
+        .find(entity_id)
+        .and_then(|entity| ctx.db.transform().id().find(entity.transform_id))
+        .ok_or_else(|| format!("Transform not found for entity: {entity_id}"))
+}
+
+/// Instantly moves an entity, cancelling any movement it was doing.
+pub(crate) fn teleport(
+    ctx: &ReducerContext,
+    entity_id: u32,
+    translation: Vec3,
+) -> Result<(), String> {
+    let transform = find_transform(ctx, entity_id)?;
+    let map_id = transform.map_id;
+    place(ctx, entity_id, transform, map_id, translation);
+    Ok(())
+}
+
+/// Instantly moves an entity onto another map, cancelling any movement it was doing.
+/// Use `map::transfer_entity` to bring its pets along.
+pub(crate) fn transfer(
+    ctx: &ReducerContext,
+    entity_id: u32,
+    map_id: u32,
+    translation: Vec3,
+) -> Result<(), String> {
+    let transform = find_transform(ctx, entity_id)?;
+    place(ctx, entity_id, transform, map_id, translation);
+    Ok(())
+}
+
+/// Turns a transform towards `direction` on the XZ plane by at most `max_turn` radians.
+fn turn(transform: &mut Transform, direction: Vec3, max_turn: f32) {
+    let Some(target_yaw) = facing::yaw_towards(direction.xz()) else {
+        return;
+    };
+    // Rebuilt from the yaw, rotations stored before they were kept valid are all zeros
+    let yaw = facing::turn_towards(transform.rotation.yaw(), target_yaw, max_turn);
+    transform.rotation = Quat::from_yaw(yaw);
+}
+
+/// Instantly turns an entity to face a point.
+pub(crate) fn face(ctx: &ReducerContext, entity_id: u32, target: Vec3) -> Result<(), String> {
+    let mut transform = find_transform(ctx, entity_id)?;
+    let direction = target - transform.translation;
+    turn(&mut transform, direction, TAU);
+    ctx.db.transform().id().update(transform);
+    Ok(())
+}
+
+fn place(
+    ctx: &ReducerContext,
+    entity_id: u32,
+    mut transform: Transform,
+    map_id: u32,
+    translation: Vec3,
+) {
+    transform.map_id = map_id;
+    transform.translation = translation;
+    transform.chunk_id = chunk::encode(translation.x, translation.z);
+    ctx.db.transform().id().update(transform);
+    ctx.db.entity_movement().entity_id().delete(entity_id);
+}
+
 #[reducer]
 fn movement_tick(ctx: &ReducerContext, mut timer: MovementTickTimer) -> Result<(), String> {
     if ctx.sender != ctx.identity() {
@@ -71,6 +171,9 @@ fn movement_tick(ctx: &ReducerContext, mut timer: MovementTickTimer) -> Result<(
             ctx.db.entity_movement().delete(entity_movement);
             continue;
         };
+        let speed = BASE_MOVE_SPEED
+            * effect::stat_multiplier(ctx, se.id, Stat::MoveSpeed)
+            * mount::speed_multiplier(ctx, se.id);
         match &mut entity_movement.intent {
             MoveIntent::Entity(entity_id) => {
                 let Some(te) = ctx.db.entity().id().find(entity_id.clone()) else {
@@ -83,16 +186,17 @@ fn movement_tick(ctx: &ReducerContext, mut timer: MovementTickTimer) -> Result<(
                     ctx.db.entity_movement().delete(entity_movement);
                     continue;
                 };
+                // The target left for another map, there is no way to follow it
+                if tt.map_id != st.map_id {
+                    ctx.db.entity_movement().delete(entity_movement);
+                    continue;
+                }
 
-                let step = calculate_step_2d(
-                    st.translation.to_2d_array(),
-                    tt.translation.to_2d_array(),
-                    0.5,
-                    5.0,
-                    delta_time_secs,
-                );
-                st.translation.x = step.new_position[0];
-                st.translation.z = step.new_position[1];
+                let step =
+                    calculate_step(st.translation, tt.translation, 0.5, speed, delta_time_secs);
+                turn(&mut st, step.step, TURN_RATE * delta_time_secs);
+                st.translation = step.new_position;
+                st.chunk_id = chunk::encode(st.translation.x, st.translation.z);
                 ctx.db.transform().id().update(st);
 
                 if step.movement_finished {
@@ -106,16 +210,11 @@ fn movement_tick(ctx: &ReducerContext, mut timer: MovementTickTimer) -> Result<(
                     continue;
                 };
                 // calculate step toward it
-                let step = calculate_step_2d(
-                    st.translation.to_2d_array(),
-                    tt.to_2d_array(),
-                    0.5,
-                    5.0,
-                    delta_time_secs,
-                );
-
-                st.translation.x = step.new_position[0];
-                st.translation.z = step.new_position[1];
+                let step = calculate_step(st.translation, *tt, 0.5, speed, delta_time_secs);
+
+                turn(&mut st, step.step, TURN_RATE * delta_time_secs);
+                st.translation = step.new_position;
+                st.chunk_id = chunk::encode(st.translation.x, st.translation.z);
                 ctx.db.transform().id().update(st);
 
                 // if we have reached the point (within acceptance radius), remove that element from the vec
@@ -179,14 +278,15 @@ pub fn request_move(ctx: &ReducerContext, move_intent: MoveIntent) -> Result<(),
                 log::warn!("{err}");
                 return Err(err);
             };
+            if target_transform.map_id != character_transform.map_id {
+                let err = format!("request_move: target entity is on another map.");
+                log::warn!("{err}");
+                return Err(err);
+            }
             let target_translation = target_transform.translation;
 
-            let distance_squared = common::distance_squared(
-                [target_translation.x, target_translation.z],
-                [char_translation.x, char_translation.z],
-            );
-
-            if distance_squared >= MAX_MOVE_DISTANCE_SQUARED {
+            if target_translation.distance_squared_xz(char_translation) >= MAX_MOVE_DISTANCE_SQUARED
+            {
                 let err = format!("request_move: target entity is too far away.");
                 log::warn!("{err}");
                 return Err(err);
@@ -194,11 +294,8 @@ pub fn request_move(ctx: &ReducerContext, move_intent: MoveIntent) -> Result<(),
         }
         MoveIntent::Path(translations) => {
             let out_of_range = translations.iter().any(|target_translation| {
-                let distance_squared = common::distance_squared(
-                    [target_translation.x, target_translation.z],
-                    [char_translation.x, char_translation.z],
-                );
-                distance_squared >= MAX_MOVE_DISTANCE_SQUARED
+                target_translation.distance_squared_xz(char_translation)
+                    >= MAX_MOVE_DISTANCE_SQUARED
             });
 
             if out_of_range {
@@ -209,6 +306,8 @@ pub fn request_move(ctx: &ReducerContext, move_intent: MoveIntent) -> Result<(),
         }
     }
 
+    // Walking off interrupts a teleport
+    travel::interrupt(ctx, character_entity.id);
     ctx.db.entity_movement().insert(EntityMovement {
         entity_id: character_entity.id,
         intent: move_intent,
@@ -0,0 +1,292 @@
+//! Non-player characters.
+//!
+//! NPCs are spawned from spawn points when the module starts, or when a dungeon
+//! instance places its own copies of the dungeon's NPCs. Like
+//! `character_pawn` for players, the `npc` table narrows a generic `entity` down to
+//! a specific NPC definition.
+
+use crate::{
+    Entity, Health, Transform,
+    character::character_pawn,
+    effect, entities_in_range, entity, health,
+    map::OVERWORLD_MAP_ID,
+    movement::entity_movement,
+    party, progression, quest, threat, transform,
+    types::{Quat, Vec3},
+    veil,
+};
+use common::chunk;
+use spacetimedb::{ReducerContext, ScheduleAt, Table, TimeDuration, reducer, table};
+
+/// How close a character needs to be to interact with an NPC.
+const INTERACT_RANGE_SQUARED: f32 = 5.0 * 5.0;
+/// How close party members need to be to share kill credit.
+const KILL_CREDIT_RANGE_SQUARED: f32 = 50.0 * 50.0;
+
+/// The static definition of an NPC.
+#[table(name = npc_def, public)]
+pub struct NpcDef {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub description: String,
+    pub level: u8,
+    pub max_health: u16,
+
+    /// Whether characters can fight the NPC. Vendors, quest givers and other townsfolk
+    /// can't be attacked, taunted or hit by area effects.
+    pub hostile: bool,
+
+    /// The experience split between the killer's party members nearby
+    pub xp_reward: u32,
+}
+
+/// A location an NPC is spawned at.
+#[table(name = npc_spawn)]
+pub struct NpcSpawn {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub map_id: u32,
+
+    pub npc_def_id: u32,
+    pub translation: Vec3,
+
+    /// How long after dying the NPC comes back, zero for never
+    pub respawn_secs: u32,
+}
+
+/// A type-narrowing table for in-game entities that are specifically NPCs.
+#[table(name = npc, public)]
+pub struct Npc {
+    #[primary_key]
+    pub entity_id: u32,
+
+    #[index(btree)]
+    pub npc_def_id: u32,
+
+    #[unique]
+    pub spawn_id: u32,
+
+    pub health_id: u32,
+
+    /// Whether the NPC is standing inside the Veil, which makes it stronger
+    pub empowered: bool,
+
+    /// Whether the NPC gave up its fight and is walking back to its spawn point
+    pub evading: bool,
+}
+
+#[table(name = npc_respawn_timer, scheduled(npc_respawn))]
+struct NpcRespawnTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+
+    spawn_id: u32,
+}
+
+/// Whether an NPC can be fought, see [`NpcDef::hostile`].
+pub(crate) fn is_hostile(ctx: &ReducerContext, npc: &Npc) -> bool {
+    ctx.db
+        .npc_def()
+        .id()
+        .find(npc.npc_def_id)
+        .is_some_and(|npc_def| npc_def.hostile)
+}
+
+/// Spawns every NPC that isn't in the world yet.
+pub fn init(ctx: &ReducerContext) {
+    for spawn in ctx.db.npc_spawn().iter() {
+        if ctx.db.npc().spawn_id().find(spawn.id).is_none() {
+            spawn_npc(ctx, &spawn);
+        }
+    }
+}
+
+fn spawn_npc(ctx: &ReducerContext, spawn: &NpcSpawn) {
+    let Some(npc_def) = ctx.db.npc_def().id().find(spawn.npc_def_id) else {
+        log::warn!("NPC definition not found for spawn: {}", spawn.id);
+        return;
+    };
+
+    let transform = ctx.db.transform().insert(Transform {
+        id: 0,
+        translation: spawn.translation,
+        rotation: Quat::default(),
+        scale: Vec3::default(),
+        map_id: spawn.map_id,
+        chunk_id: chunk::encode(spawn.translation.x, spawn.translation.z),
+        stealthed: false,
+    });
+    let entity = ctx.db.entity().insert(Entity {
+        id: 0,
+        transform_id: transform.id,
+    });
+    // The Veil only drifts over the overworld
+    let empowered = spawn.map_id == OVERWORLD_MAP_ID && veil::contains(ctx, transform.chunk_id);
+    let health = ctx
+        .db
+        .health()
+        .insert(Health::new(max_health(&npc_def, empowered)));
+    ctx.db.npc().insert(Npc {
+        entity_id: entity.id,
+        npc_def_id: npc_def.id,
+        spawn_id: spawn.id,
+        health_id: health.id,
+        empowered,
+        evading: false,
+    });
+}
+
+/// Spawns an NPC that stays dead once killed, e.g. inside a dungeon instance.
+pub(crate) fn spawn_once(ctx: &ReducerContext, map_id: u32, npc_def_id: u32, translation: Vec3) {
+    let spawn = ctx.db.npc_spawn().insert(NpcSpawn {
+        id: 0,
+        map_id,
+        npc_def_id,
+        translation,
+        respawn_secs: 0,
+    });
+    spawn_npc(ctx, &spawn);
+}
+
+/// Removes every NPC on a map along with the spawn points they came from, e.g. when
+/// a dungeon instance is torn down.
+pub(crate) fn despawn_map(ctx: &ReducerContext, map_id: u32) {
+    let spawns: Vec<NpcSpawn> = ctx.db.npc_spawn().map_id().filter(map_id).collect();
+    for spawn in spawns {
+        if let Some(npc) = ctx.db.npc().spawn_id().find(spawn.id) {
+            remove(ctx, npc);
+        }
+        ctx.db.npc_spawn().delete(spawn);
+    }
+}
+
+/// Removes an NPC's entity and everything tracking it.
+fn remove(ctx: &ReducerContext, npc: Npc) {
+    threat::clear_npc(ctx, npc.entity_id);
+    threat::forget_target(ctx, npc.entity_id);
+    effect::clear(ctx, npc.entity_id);
+    ctx.db.entity_movement().entity_id().delete(npc.entity_id);
+    if let Some(entity) = ctx.db.entity().id().find(npc.entity_id) {
+        ctx.db.transform().id().delete(entity.transform_id);
+        ctx.db.entity().delete(entity);
+    }
+    ctx.db.health().id().delete(npc.health_id);
+    ctx.db.npc().delete(npc);
+}
+
+fn max_health(npc_def: &NpcDef, empowered: bool) -> u16 {
+    if empowered {
+        (npc_def.max_health as u32 * veil::EMPOWERED_HEALTH_PERCENT / 100).min(u16::MAX as u32)
+            as u16
+    } else {
+        npc_def.max_health
+    }
+}
+
+/// Empowers or weakens an NPC as the Veil covers or leaves it. Its health is scaled so
+/// that it keeps the same fraction of its max health.
+pub(crate) fn set_empowered(ctx: &ReducerContext, mut npc: Npc, empowered: bool) {
+    let Some(npc_def) = ctx.db.npc_def().id().find(npc.npc_def_id) else {
+        return;
+    };
+    if let Some(mut health) = ctx.db.health().id().find(npc.health_id) {
+        let max_health = max_health(&npc_def, empowered);
+        let scaled = health.health as u32 * max_health as u32 / health.max_health.max(1) as u32;
+        health.max_health = max_health;
+        health.update(scaled as u16);
+        ctx.db.health().id().update(health);
+    }
+
+    npc.empowered = empowered;
+    ctx.db.npc().entity_id().update(npc);
+}
+
+/// Whether an entity is close enough to interact with an NPC.
+pub(crate) fn in_reach(ctx: &ReducerContext, entity_id: u32, npc_entity_id: u32) -> bool {
+    entities_in_range(ctx, entity_id, npc_entity_id, INTERACT_RANGE_SQUARED)
+}
+
+/// Finds the NPC spawned as `entity_id`, making sure the sender's character is close
+/// enough to interact with it. Returns the NPC along with the sender's character id.
+pub(crate) fn npc_in_reach(
+    ctx: &ReducerContext,
+    reducer: &str,
+    entity_id: u32,
+) -> Result<(Npc, u32), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("{reducer}: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(npc) = ctx.db.npc().entity_id().find(entity_id) else {
+        let err = format!("{reducer}: target is not an NPC.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if !in_reach(ctx, pawn.entity_id, npc.entity_id) {
+        let err = format!("{reducer}: NPC is too far away.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    Ok((npc, pawn.character_id))
+}
+
+/// Removes a dead NPC from the world, credits the kill to the killer's party and
+/// schedules the NPC to respawn. NPCs killed by the world credit nobody.
+pub(crate) fn kill(ctx: &ReducerContext, entity_id: u32, killer_character_id: Option<u32>) {
+    let Some(npc) = ctx.db.npc().entity_id().find(entity_id) else {
+        return;
+    };
+
+    if let Some(killer_character_id) = killer_character_id {
+        for character_id in
+            party::members_in_range(ctx, killer_character_id, KILL_CREDIT_RANGE_SQUARED)
+        {
+            quest::record_kill(ctx, character_id, npc.npc_def_id);
+        }
+        if let Some(npc_def) = ctx.db.npc_def().id().find(npc.npc_def_id) {
+            progression::award_xp(ctx, killer_character_id, npc_def.xp_reward);
+        }
+    }
+
+    let spawn_id = npc.spawn_id;
+    remove(ctx, npc);
+
+    if let Some(spawn) = ctx.db.npc_spawn().id().find(spawn_id)
+        && spawn.respawn_secs > 0
+    {
+        let respawn_at =
+            ctx.timestamp + TimeDuration::from_micros(spawn.respawn_secs as i64 * 1_000_000);
+        ctx.db.npc_respawn_timer().insert(NpcRespawnTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Time(respawn_at),
+            spawn_id: spawn.id,
+        });
+    }
+}
+
+#[reducer]
+fn npc_respawn(ctx: &ReducerContext, timer: NpcRespawnTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`npc_respawn` may not be invoked by clients.".into());
+    }
+
+    if let Some(spawn) = ctx.db.npc_spawn().id().find(timer.spawn_id)
+        && ctx.db.npc().spawn_id().find(spawn.id).is_none()
+    {
+        spawn_npc(ctx, &spawn);
+    }
+
+    Ok(())
+}
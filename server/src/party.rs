@@ -0,0 +1,405 @@
+//! Social grouping of in-game characters.
+//!
+//! A party is created when the first invite is accepted and disbanded as soon as fewer
+//! than two members remain. Membership only lasts while a character is in the world.
+
+use crate::{
+    character::{character_def, character_pawn},
+    entities_in_range, social,
+};
+use spacetimedb::{
+    Filter, Identity, ReducerContext, Table, Timestamp, client_visibility_filter, reducer, table,
+};
+
+pub(crate) const MAX_PARTY_SIZE: usize = 5;
+
+#[client_visibility_filter]
+const PARTY_SECURITY: Filter = Filter::Sql(
+    "SELECT party.* FROM party JOIN party_member ON party.id = party_member.party_id WHERE party_member.identity = :sender",
+);
+
+#[client_visibility_filter]
+const PARTY_MEMBER_SECURITY: Filter = Filter::Sql(
+    "SELECT party_member.* FROM party_member JOIN party_member AS viewer ON party_member.party_id = viewer.party_id WHERE viewer.identity = :sender",
+);
+
+#[client_visibility_filter]
+const PARTY_INVITE_INVITEE: Filter =
+    Filter::Sql("SELECT * FROM party_invite WHERE invitee = :sender");
+
+#[client_visibility_filter]
+const PARTY_INVITE_INVITER: Filter =
+    Filter::Sql("SELECT * FROM party_invite WHERE inviter = :sender");
+
+#[table(name = party, public)]
+pub struct Party {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    /// The character allowed to invite, kick and hand over leadership
+    pub leader_character_id: u32,
+}
+
+/// A character's membership in a party.
+/// Keyed by character so that a character can only be in a single party at a time.
+#[table(name = party_member, public)]
+pub struct PartyMember {
+    #[primary_key]
+    pub character_id: u32,
+
+    #[index(btree)]
+    pub party_id: u32,
+
+    /// The player controlling the member, used by visibility filters
+    #[unique]
+    pub identity: Identity,
+
+    /// Copied from the character so that party frames don't need access to `character_def`
+    pub name: String,
+    pub health_id: u32,
+    pub mana_id: u32,
+}
+
+/// A pending invitation to join the inviter's party.
+#[table(name = party_invite, public)]
+pub struct PartyInvite {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub inviter: Identity,
+
+    #[index(btree)]
+    pub invitee: Identity,
+
+    pub inviter_character_id: u32,
+    pub invitee_character_id: u32,
+
+    pub created_at: Timestamp,
+}
+
+/// Returns the party member row for the character controlled by `identity`, if any.
+fn member_for(ctx: &ReducerContext, identity: Identity) -> Option<PartyMember> {
+    ctx.db.party_member().identity().find(identity)
+}
+
+/// Adds a character to a party, copying the data needed by party frames.
+fn add_member(ctx: &ReducerContext, party_id: u32, character_id: u32) -> Result<(), String> {
+    let Some(character) = ctx.db.character_def().id().find(character_id) else {
+        return Err(format!("Invalid character"));
+    };
+    ctx.db.party_member().insert(PartyMember {
+        character_id,
+        party_id,
+        identity: character.identity,
+        name: character.name,
+        health_id: character.health_id,
+        mana_id: character.mana_id,
+    });
+
+    Ok(())
+}
+
+/// Removes a character from its party, handing leadership over or disbanding the party as needed.
+pub(crate) fn remove_member(ctx: &ReducerContext, character_id: u32) {
+    let Some(member) = ctx.db.party_member().character_id().find(character_id) else {
+        return;
+    };
+    ctx.db.party_member().character_id().delete(character_id);
+
+    let remaining: Vec<PartyMember> = ctx
+        .db
+        .party_member()
+        .party_id()
+        .filter(member.party_id)
+        .collect();
+
+    if remaining.len() < 2 {
+        ctx.db.party_member().party_id().delete(member.party_id);
+        ctx.db.party().id().delete(member.party_id);
+        return;
+    }
+
+    if let Some(mut party) = ctx.db.party().id().find(member.party_id) {
+        if party.leader_character_id == character_id {
+            party.leader_character_id = remaining[0].character_id;
+            ctx.db.party().id().update(party);
+        }
+    }
+}
+
+/// Cleans up any party state of a player leaving the world.
+pub(crate) fn leave_world(ctx: &ReducerContext, identity: Identity, character_id: u32) {
+    ctx.db.party_invite().inviter().delete(identity);
+    ctx.db.party_invite().invitee().delete(identity);
+    remove_member(ctx, character_id);
+}
+
+/// Returns the characters in the same party as `character_id` whose entities are on its
+/// map and within `range_squared` of it, including the character itself. Characters
+/// that aren't in a party only get themselves back.
+pub(crate) fn members_in_range(
+    ctx: &ReducerContext,
+    character_id: u32,
+    range_squared: f32,
+) -> Vec<u32> {
+    let Some(member) = ctx.db.party_member().character_id().find(character_id) else {
+        return vec![character_id];
+    };
+    let entity_of = |character_id: u32| {
+        ctx.db
+            .character_pawn()
+            .character_id()
+            .filter(character_id)
+            .next()
+            .map(|pawn| pawn.entity_id)
+    };
+    let Some(origin) = entity_of(character_id) else {
+        return vec![character_id];
+    };
+
+    ctx.db
+        .party_member()
+        .party_id()
+        .filter(member.party_id)
+        .filter(|other| {
+            other.character_id == character_id
+                || entity_of(other.character_id).is_some_and(|entity_id| {
+                    entities_in_range(ctx, origin, entity_id, range_squared)
+                })
+        })
+        .map(|other| other.character_id)
+        .collect()
+}
+
+/// Invites an in-game character to the sender's party, by name.
+#[reducer]
+pub fn invite_to_party(ctx: &ReducerContext, character_name: String) -> Result<(), String> {
+    let Some(own_pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("invite_to_party: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    let Some(target_pawn) = ctx
+        .db
+        .character_def()
+        .name()
+        .filter(character_name.trim())
+        .find_map(|character| {
+            ctx.db
+                .character_pawn()
+                .identity()
+                .find(character.identity)
+                .filter(|pawn| pawn.character_id == character.id)
+        })
+    else {
+        let err = format!("invite_to_party: character is not in the world.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if target_pawn.identity == ctx.sender {
+        let err = format!("invite_to_party: cannot invite yourself.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if social::is_ignoring(ctx, target_pawn.identity, ctx.sender) {
+        let err = format!("invite_to_party: character is ignoring you.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if member_for(ctx, target_pawn.identity).is_some() {
+        let err = format!("invite_to_party: character is already in a party.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if let Some(member) = member_for(ctx, ctx.sender) {
+        let Some(party) = ctx.db.party().id().find(member.party_id) else {
+            let err = format!("invite_to_party: party not found.");
+            log::warn!("{err}");
+            return Err(err);
+        };
+        if party.leader_character_id != member.character_id {
+            let err = format!("invite_to_party: only the party leader can invite.");
+            log::warn!("{err}");
+            return Err(err);
+        }
+        if ctx.db.party_member().party_id().filter(party.id).count() >= MAX_PARTY_SIZE {
+            let err = format!("invite_to_party: party is full.");
+            log::warn!("{err}");
+            return Err(err);
+        }
+    }
+
+    if ctx
+        .db
+        .party_invite()
+        .invitee()
+        .filter(target_pawn.identity)
+        .any(|invite| invite.inviter == ctx.sender)
+    {
+        let err = format!("invite_to_party: character has already been invited.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    ctx.db.party_invite().insert(PartyInvite {
+        id: 0,
+        inviter: ctx.sender,
+        invitee: target_pawn.identity,
+        inviter_character_id: own_pawn.character_id,
+        invitee_character_id: target_pawn.character_id,
+        created_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn accept_party_invite(ctx: &ReducerContext, invite_id: u32) -> Result<(), String> {
+    let Some(invite) = ctx
+        .db
+        .party_invite()
+        .id()
+        .find(invite_id)
+        .filter(|invite| invite.invitee == ctx.sender)
+    else {
+        let err = format!("accept_party_invite: invite not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    ctx.db.party_invite().id().delete(invite.id);
+
+    if member_for(ctx, ctx.sender).is_some() {
+        let err = format!("accept_party_invite: already in a party.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    let party_id = match ctx
+        .db
+        .party_member()
+        .character_id()
+        .find(invite.inviter_character_id)
+    {
+        Some(inviter) => {
+            if ctx
+                .db
+                .party_member()
+                .party_id()
+                .filter(inviter.party_id)
+                .count()
+                >= MAX_PARTY_SIZE
+            {
+                let err = format!("accept_party_invite: party is full.");
+                log::warn!("{err}");
+                return Err(err);
+            }
+            inviter.party_id
+        }
+        None => {
+            let party = ctx.db.party().insert(Party {
+                id: 0,
+                leader_character_id: invite.inviter_character_id,
+            });
+            add_member(ctx, party.id, invite.inviter_character_id)?;
+            party.id
+        }
+    };
+    add_member(ctx, party_id, invite.invitee_character_id)?;
+
+    // Any other pending invites are void now that the sender is in a party
+    ctx.db.party_invite().invitee().delete(ctx.sender);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn decline_party_invite(ctx: &ReducerContext, invite_id: u32) -> Result<(), String> {
+    let Some(invite) = ctx
+        .db
+        .party_invite()
+        .id()
+        .find(invite_id)
+        .filter(|invite| invite.invitee == ctx.sender)
+    else {
+        let err = format!("decline_party_invite: invite not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    ctx.db.party_invite().delete(invite);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn leave_party(ctx: &ReducerContext) -> Result<(), String> {
+    let Some(member) = member_for(ctx, ctx.sender) else {
+        let err = format!("leave_party: not in a party.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    remove_member(ctx, member.character_id);
+
+    Ok(())
+}
+
+/// Checks that the sender leads its party and that `character_id` is another member of it.
+fn leader_and_member(
+    ctx: &ReducerContext,
+    reducer: &str,
+    character_id: u32,
+) -> Result<Party, String> {
+    let Some(leader) = member_for(ctx, ctx.sender) else {
+        let err = format!("{reducer}: not in a party.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(party) = ctx
+        .db
+        .party()
+        .id()
+        .find(leader.party_id)
+        .filter(|party| party.leader_character_id == leader.character_id)
+    else {
+        let err = format!("{reducer}: only the party leader can do this.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    if character_id == leader.character_id
+        || !ctx
+            .db
+            .party_member()
+            .character_id()
+            .find(character_id)
+            .is_some_and(|member| member.party_id == party.id)
+    {
+        let err = format!("{reducer}: character is not another member of the party.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    Ok(party)
+}
+
+#[reducer]
+pub fn kick_from_party(ctx: &ReducerContext, character_id: u32) -> Result<(), String> {
+    leader_and_member(ctx, "kick_from_party", character_id)?;
+    remove_member(ctx, character_id);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn transfer_party_leader(ctx: &ReducerContext, character_id: u32) -> Result<(), String> {
+    let mut party = leader_and_member(ctx, "transfer_party_leader", character_id)?;
+    party.leader_character_id = character_id;
+    ctx.db.party().id().update(party);
+
+    Ok(())
+}
@@ -0,0 +1,466 @@
+//! Gathering and crafting professions.
+//!
+//! Resource nodes are spawned from static spawn points like NPCs. Gathering one takes a
+//! cast, tracked by a `gather_cast` row and completed by a one-shot timer, and depletes
+//! the node until it respawns. Recipes turn inventory items into new ones instantly.
+//!
+//! Characters start every profession at skill zero, and raise it by gathering or
+//! crafting things close to their skill level.
+
+use crate::{
+    Entity, Transform,
+    character::{character_def, character_pawn},
+    entities_in_range, entity, inventory,
+    map::OVERWORLD_MAP_ID,
+    transform,
+    types::{Quat, Vec3},
+};
+use common::chunk;
+use spacetimedb::{
+    Filter, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp, client_visibility_filter,
+    reducer, table,
+};
+
+/// How close a character needs to be to gather from a resource node.
+const GATHER_RANGE_SQUARED: f32 = 5.0 * 5.0;
+/// The highest skill a profession can be raised to.
+const MAX_SKILL: u16 = 300;
+/// How far above its requirement a node or recipe still raises skill.
+const SKILL_UP_RANGE: u16 = 25;
+
+#[client_visibility_filter]
+const PROFESSION_SKILL_SECURITY: Filter = Filter::Sql(
+    "SELECT profession_skill.* FROM profession_skill JOIN character_def ON profession_skill.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+#[client_visibility_filter]
+const GATHER_CAST_SECURITY: Filter = Filter::Sql(
+    "SELECT gather_cast.* FROM gather_cast JOIN character_def ON gather_cast.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+/// The static definition of a profession.
+#[table(name = profession, public)]
+pub struct Profession {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub description: String,
+}
+
+/// A character's skill in a profession. Missing rows mean a skill of zero.
+#[table(name = profession_skill, public)]
+pub struct ProfessionSkill {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub character_id: u32,
+
+    pub profession_id: u32,
+    pub skill: u16,
+}
+
+/// A static location a resource node is spawned at.
+#[table(name = resource_node_spawn)]
+pub struct ResourceNodeSpawn {
+    #[primary_key]
+    pub id: u32,
+
+    pub translation: Vec3,
+
+    /// The gathering profession the node belongs to, and the skill it requires
+    pub profession_id: u32,
+    pub required_skill: u16,
+
+    /// What a single gather yields
+    pub item_def_id: u32,
+    pub quantity: u16,
+
+    pub gather_secs: u32,
+
+    /// How long after being gathered the node comes back
+    pub respawn_secs: u32,
+}
+
+/// A type-narrowing table for in-game entities that are resource nodes.
+#[table(name = resource_node, public)]
+pub struct ResourceNode {
+    #[primary_key]
+    pub entity_id: u32,
+
+    #[unique]
+    pub spawn_id: u32,
+
+    pub profession_id: u32,
+    pub item_def_id: u32,
+}
+
+#[table(name = resource_node_respawn_timer, scheduled(resource_node_respawn))]
+struct ResourceNodeRespawnTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+
+    spawn_id: u32,
+}
+
+/// A character's gather in progress. A character only gathers one node at a time.
+#[table(name = gather_cast, public)]
+pub struct GatherCast {
+    #[primary_key]
+    pub character_id: u32,
+
+    pub node_entity_id: u32,
+    pub started_at: Timestamp,
+    pub completes_at: Timestamp,
+}
+
+#[table(name = gather_timer, scheduled(complete_gather))]
+struct GatherTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+
+    #[index(btree)]
+    character_id: u32,
+}
+
+/// Something a profession can make.
+#[table(name = recipe, public)]
+pub struct Recipe {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub profession_id: u32,
+    pub required_skill: u16,
+
+    pub result_item_def_id: u32,
+    pub result_quantity: u16,
+}
+
+/// An item consumed by a recipe.
+#[table(name = recipe_ingredient, public)]
+pub struct RecipeIngredient {
+    #[primary_key]
+    pub id: u32,
+
+    #[index(btree)]
+    pub recipe_id: u32,
+
+    pub item_def_id: u32,
+    pub quantity: u16,
+}
+
+/// Spawns every resource node that isn't in the world yet.
+pub fn init(ctx: &ReducerContext) {
+    for spawn in ctx.db.resource_node_spawn().iter() {
+        if ctx.db.resource_node().spawn_id().find(spawn.id).is_none() {
+            spawn_node(ctx, &spawn);
+        }
+    }
+}
+
+fn spawn_node(ctx: &ReducerContext, spawn: &ResourceNodeSpawn) {
+    let transform = ctx.db.transform().insert(Transform {
+        id: 0,
+        translation: spawn.translation,
+        rotation: Quat::default(),
+        scale: Vec3::default(),
+        map_id: OVERWORLD_MAP_ID,
+        chunk_id: chunk::encode(spawn.translation.x, spawn.translation.z),
+        stealthed: false,
+    });
+    let entity = ctx.db.entity().insert(Entity {
+        id: 0,
+        transform_id: transform.id,
+    });
+    ctx.db.resource_node().insert(ResourceNode {
+        entity_id: entity.id,
+        spawn_id: spawn.id,
+        profession_id: spawn.profession_id,
+        item_def_id: spawn.item_def_id,
+    });
+}
+
+/// Removes a gathered node from the world and schedules it to respawn.
+fn deplete_node(ctx: &ReducerContext, node: ResourceNode) {
+    if let Some(entity) = ctx.db.entity().id().find(node.entity_id) {
+        ctx.db.transform().id().delete(entity.transform_id);
+        ctx.db.entity().delete(entity);
+    }
+    let spawn_id = node.spawn_id;
+    ctx.db.resource_node().delete(node);
+
+    if let Some(spawn) = ctx.db.resource_node_spawn().id().find(spawn_id) {
+        let respawn_at =
+            ctx.timestamp + TimeDuration::from_micros(spawn.respawn_secs as i64 * 1_000_000);
+        ctx.db
+            .resource_node_respawn_timer()
+            .insert(ResourceNodeRespawnTimer {
+                scheduled_id: 0,
+                scheduled_at: ScheduleAt::Time(respawn_at),
+                spawn_id: spawn.id,
+            });
+    }
+}
+
+fn in_reach(ctx: &ReducerContext, entity_id: u32, node_entity_id: u32) -> bool {
+    entities_in_range(ctx, entity_id, node_entity_id, GATHER_RANGE_SQUARED)
+}
+
+/// A character's skill in a profession.
+fn skill(ctx: &ReducerContext, character_id: u32, profession_id: u32) -> u16 {
+    ctx.db
+        .profession_skill()
+        .character_id()
+        .filter(character_id)
+        .find(|row| row.profession_id == profession_id)
+        .map_or(0, |row| row.skill)
+}
+
+/// Raises a character's skill by one, unless the content used is too easy for them.
+fn raise_skill(ctx: &ReducerContext, character_id: u32, profession_id: u32, required_skill: u16) {
+    let row = ctx
+        .db
+        .profession_skill()
+        .character_id()
+        .filter(character_id)
+        .find(|row| row.profession_id == profession_id);
+    let current = row.as_ref().map_or(0, |row| row.skill);
+    if current >= MAX_SKILL || current >= required_skill.saturating_add(SKILL_UP_RANGE) {
+        return;
+    }
+
+    match row {
+        Some(mut row) => {
+            row.skill += 1;
+            ctx.db.profession_skill().id().update(row);
+        }
+        None => {
+            ctx.db.profession_skill().insert(ProfessionSkill {
+                id: 0,
+                character_id,
+                profession_id,
+                skill: 1,
+            });
+        }
+    }
+}
+
+/// Stops a character's gather in progress, if any.
+pub(crate) fn cancel_gather_for(ctx: &ReducerContext, character_id: u32) {
+    ctx.db.gather_cast().character_id().delete(character_id);
+    ctx.db.gather_timer().character_id().delete(character_id);
+}
+
+/// Starts gathering a nearby resource node.
+#[reducer]
+pub fn start_gather(ctx: &ReducerContext, node_entity_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("start_gather: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(spawn) = ctx
+        .db
+        .resource_node()
+        .entity_id()
+        .find(node_entity_id)
+        .and_then(|node| ctx.db.resource_node_spawn().id().find(node.spawn_id))
+    else {
+        let err = format!("start_gather: target is not a resource node.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if ctx
+        .db
+        .gather_cast()
+        .character_id()
+        .find(pawn.character_id)
+        .is_some()
+    {
+        let err = format!("start_gather: already gathering.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if !in_reach(ctx, pawn.entity_id, node_entity_id) {
+        let err = format!("start_gather: resource node is too far away.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if skill(ctx, pawn.character_id, spawn.profession_id) < spawn.required_skill {
+        let err = format!("start_gather: skill too low.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    let completes_at =
+        ctx.timestamp + TimeDuration::from_micros(spawn.gather_secs as i64 * 1_000_000);
+    ctx.db.gather_cast().insert(GatherCast {
+        character_id: pawn.character_id,
+        node_entity_id,
+        started_at: ctx.timestamp,
+        completes_at,
+    });
+    ctx.db.gather_timer().insert(GatherTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(completes_at),
+        character_id: pawn.character_id,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn cancel_gather(ctx: &ReducerContext) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("cancel_gather: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    cancel_gather_for(ctx, pawn.character_id);
+
+    Ok(())
+}
+
+/// Finishes a gather, handing out the node's yield if the character is still next to it
+/// and nobody else got to it first.
+#[reducer]
+fn complete_gather(ctx: &ReducerContext, timer: GatherTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`complete_gather` may not be invoked by clients.".into());
+    }
+
+    let Some(cast) = ctx.db.gather_cast().character_id().find(timer.character_id) else {
+        return Ok(());
+    };
+    ctx.db
+        .gather_cast()
+        .character_id()
+        .delete(cast.character_id);
+
+    let Some(pawn) = ctx
+        .db
+        .character_def()
+        .id()
+        .find(cast.character_id)
+        .and_then(|character| ctx.db.character_pawn().identity().find(character.identity))
+        .filter(|pawn| pawn.character_id == cast.character_id)
+    else {
+        return Ok(());
+    };
+    let Some(node) = ctx.db.resource_node().entity_id().find(cast.node_entity_id) else {
+        return Ok(());
+    };
+    let Some(spawn) = ctx.db.resource_node_spawn().id().find(node.spawn_id) else {
+        return Ok(());
+    };
+    if !in_reach(ctx, pawn.entity_id, node.entity_id) {
+        log::warn!("complete_gather: resource node is too far away.");
+        return Ok(());
+    }
+
+    // A full inventory leaves the node for someone else rather than failing the timer
+    if let Err(err) = inventory::add_item(ctx, cast.character_id, spawn.item_def_id, spawn.quantity)
+    {
+        log::warn!("complete_gather: {err}");
+        return Ok(());
+    }
+    raise_skill(
+        ctx,
+        cast.character_id,
+        spawn.profession_id,
+        spawn.required_skill,
+    );
+    deplete_node(ctx, node);
+
+    Ok(())
+}
+
+#[reducer]
+fn resource_node_respawn(
+    ctx: &ReducerContext,
+    timer: ResourceNodeRespawnTimer,
+) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`resource_node_respawn` may not be invoked by clients.".into());
+    }
+
+    if let Some(spawn) = ctx.db.resource_node_spawn().id().find(timer.spawn_id)
+        && ctx.db.resource_node().spawn_id().find(spawn.id).is_none()
+    {
+        spawn_node(ctx, &spawn);
+    }
+
+    Ok(())
+}
+
+/// Crafts a recipe `count` times. Ingredients are taken before results are added so
+/// that the space they free up can be used, and any failure rolls back the whole call.
+#[reducer]
+pub fn craft(ctx: &ReducerContext, recipe_id: u32, count: u16) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("craft: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(recipe) = ctx.db.recipe().id().find(recipe_id) else {
+        let err = format!("craft: recipe not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if count == 0 {
+        let err = format!("craft: count must be greater than zero.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if skill(ctx, pawn.character_id, recipe.profession_id) < recipe.required_skill {
+        let err = format!("craft: skill too low.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    let Some(result_quantity) = recipe.result_quantity.checked_mul(count) else {
+        let err = format!("craft: too many at once.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    for ingredient in ctx.db.recipe_ingredient().recipe_id().filter(recipe.id) {
+        inventory::take_items(
+            ctx,
+            pawn.character_id,
+            ingredient.item_def_id,
+            ingredient.quantity as u32 * count as u32,
+        )
+        .map_err(|err| format!("craft: {err}"))?;
+    }
+    inventory::add_item(
+        ctx,
+        pawn.character_id,
+        recipe.result_item_def_id,
+        result_quantity,
+    )
+    .map_err(|err| format!("craft: {err}"))?;
+
+    for _ in 0..count {
+        raise_skill(
+            ctx,
+            pawn.character_id,
+            recipe.profession_id,
+            recipe.required_skill,
+        );
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
-use spacetimedb::table;
+use crate::{character::character_def, party};
+use spacetimedb::{ReducerContext, Table, table};
 
 pub(crate) const MAX_LEVEL: u8 = 50;
 
@@ -22,3 +23,68 @@ pub struct XpProgression {
     /// The cumulative experience points required to reach the level.
     pub total_xp: u32,
 }
+
+/// How close party members need to be to the character earning experience to get a share.
+const XP_SHARE_RANGE_SQUARED: f32 = 50.0 * 50.0;
+
+/// Computes the level reached with `xp` cumulative experience points.
+pub(crate) fn level_for_xp(ctx: &ReducerContext, xp: u32) -> u8 {
+    ctx.db
+        .xp_progression()
+        .iter()
+        .filter(|row| row.total_xp <= xp)
+        .map(|row| row.level)
+        .max()
+        .unwrap_or(1)
+}
+
+/// Computes the current level of a character.
+pub(crate) fn character_level(ctx: &ReducerContext, character_id: u32) -> u8 {
+    ctx.db
+        .character_def()
+        .id()
+        .find(character_id)
+        .and_then(|character| ctx.db.xp().id().find(character.xp_id))
+        .map_or(1, |row| level_for_xp(ctx, row.xp))
+}
+
+/// Adds experience points to a single character, capped at the max level.
+pub(crate) fn grant_xp(ctx: &ReducerContext, character_id: u32, amount: u32) {
+    let Some(character) = ctx.db.character_def().id().find(character_id) else {
+        log::warn!("grant_xp: character not found: {}", character_id);
+        return;
+    };
+    let Some(mut row) = ctx.db.xp().id().find(character.xp_id) else {
+        log::warn!("grant_xp: xp not found for character: {}", character_id);
+        return;
+    };
+    let max_xp = ctx
+        .db
+        .xp_progression()
+        .level()
+        .find(MAX_LEVEL)
+        .map_or(u32::MAX, |row| row.total_xp);
+
+    row.xp = row.xp.saturating_add(amount).min(max_xp);
+    ctx.db.xp().id().update(row);
+}
+
+/// Awards experience earned by a character, splitting it between the party members nearby.
+/// Each member's share is weighted by their level, so that low level characters can't be
+/// carried through content far above them at full speed.
+pub(crate) fn award_xp(ctx: &ReducerContext, character_id: u32, amount: u32) {
+    let members: Vec<(u32, u8)> =
+        party::members_in_range(ctx, character_id, XP_SHARE_RANGE_SQUARED)
+            .into_iter()
+            .map(|member_id| (member_id, character_level(ctx, member_id)))
+            .collect();
+    let total_levels: u64 = members.iter().map(|(_, level)| *level as u64).sum();
+    if total_levels == 0 {
+        return;
+    }
+
+    for (member_id, level) in members {
+        let share = (amount as u64 * level as u64 / total_levels) as u32;
+        grant_xp(ctx, member_id, share);
+    }
+}
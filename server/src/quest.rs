@@ -0,0 +1,434 @@
+//! Quests handed out and turned in at NPCs.
+//!
+//! A quest is made of a list of objectives. A character's progress on a quest is a
+//! single `quest_progress` row holding one count per objective, in the same order as
+//! the objectives sorted by id. Progress rows are kept after turning a quest in so that
+//! quests can't be repeated and can be used as prerequisites.
+
+use crate::{
+    character::character_pawn, entity_location, inventory, map::OVERWORLD_MAP_ID, npc, progression,
+    types::Vec3,
+};
+use common::chunk;
+use spacetimedb::{
+    Filter, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp,
+    client_visibility_filter, reducer, table,
+};
+
+/// The number of quests a character can have in its journal at once.
+const MAX_ACTIVE_QUESTS: usize = 20;
+/// How often in-game characters are checked against location objectives.
+const LOCATION_CHECK_MICRO_SECS: i64 = 1_000_000;
+
+#[client_visibility_filter]
+const QUEST_PROGRESS_SECURITY: Filter = Filter::Sql(
+    "SELECT quest_progress.* FROM quest_progress JOIN character_def ON quest_progress.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+/// A circular area in the world.
+#[derive(SpacetimeType, Clone, Copy)]
+pub struct ReachArea {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+#[derive(SpacetimeType, Clone, Copy)]
+pub enum ObjectiveKind {
+    /// Kill NPCs of the given definition.
+    Kill(u32),
+    /// Hold items of the given definition. Collected items are handed over on turn in.
+    Collect(u32),
+    /// Enter the given chunk.
+    ReachChunk(u32),
+    /// Enter the given area.
+    ReachArea(ReachArea),
+    /// Talk to an NPC of the given definition.
+    TalkTo(u32),
+}
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum QuestState {
+    /// Some objectives are still incomplete.
+    Active,
+    /// Every objective is complete and the quest can be turned in.
+    Complete,
+    TurnedIn,
+}
+
+#[table(name = quest_def, public)]
+pub struct QuestDef {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub description: String,
+
+    pub min_level: u8,
+
+    /// A quest that has to be turned in before this one can be accepted
+    pub prerequisite_quest_id: Option<u32>,
+
+    /// The NPC handing out the quest
+    #[index(btree)]
+    pub giver_npc_def_id: u32,
+
+    /// The NPC the quest is turned in to
+    #[index(btree)]
+    pub turn_in_npc_def_id: u32,
+
+    pub xp_reward: u32,
+}
+
+#[table(name = quest_objective, public)]
+pub struct QuestObjective {
+    #[primary_key]
+    pub id: u32,
+
+    #[index(btree)]
+    pub quest_def_id: u32,
+
+    pub description: String,
+    pub kind: ObjectiveKind,
+
+    /// The count needed to complete the objective
+    pub required: u32,
+}
+
+/// A character's progress on a quest.
+#[table(name = quest_progress, public)]
+pub struct QuestProgress {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub character_id: u32,
+
+    #[index(btree)]
+    pub quest_def_id: u32,
+
+    pub state: QuestState,
+
+    /// The current count of each objective, ordered like the objectives sorted by id
+    pub counts: Vec<u32>,
+
+    pub accepted_at: Timestamp,
+}
+
+#[table(name = quest_location_timer, scheduled(quest_location_check))]
+struct QuestLocationTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+pub fn init(ctx: &ReducerContext) {
+    ctx.db.quest_location_timer().scheduled_id().delete(1);
+    ctx.db.quest_location_timer().insert(QuestLocationTimer {
+        scheduled_id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(LOCATION_CHECK_MICRO_SECS)),
+    });
+}
+
+/// The objectives of a quest, sorted by id.
+fn objectives(ctx: &ReducerContext, quest_def_id: u32) -> Vec<QuestObjective> {
+    let mut objectives: Vec<QuestObjective> = ctx
+        .db
+        .quest_objective()
+        .quest_def_id()
+        .filter(quest_def_id)
+        .collect();
+    objectives.sort_by_key(|objective| objective.id);
+    objectives
+}
+
+fn progress_for(
+    ctx: &ReducerContext,
+    character_id: u32,
+    quest_def_id: u32,
+) -> Option<QuestProgress> {
+    ctx.db
+        .quest_progress()
+        .character_id()
+        .filter(character_id)
+        .find(|progress| progress.quest_def_id == quest_def_id)
+}
+
+/// The state of a quest for a character, or `None` if the character never accepted it.
+pub(crate) fn quest_state(
+    ctx: &ReducerContext,
+    character_id: u32,
+    quest_def_id: u32,
+) -> Option<QuestState> {
+    progress_for(ctx, character_id, quest_def_id).map(|progress| progress.state)
+}
+
+/// Recomputes the objective counts of every quest a character hasn't turned in yet.
+/// `next_count` receives an objective along with its current count and returns the new
+/// count, which is capped at the objective's requirement.
+fn update_objectives(
+    ctx: &ReducerContext,
+    character_id: u32,
+    next_count: impl Fn(&QuestObjective, u32) -> u32,
+) {
+    let journal: Vec<QuestProgress> = ctx
+        .db
+        .quest_progress()
+        .character_id()
+        .filter(character_id)
+        .filter(|progress| progress.state != QuestState::TurnedIn)
+        .collect();
+
+    for mut progress in journal {
+        let objectives = objectives(ctx, progress.quest_def_id);
+        progress.counts.resize(objectives.len(), 0);
+
+        let counts: Vec<u32> = objectives
+            .iter()
+            .zip(&progress.counts)
+            .map(|(objective, count)| next_count(objective, *count).min(objective.required))
+            .collect();
+        let state = if objectives
+            .iter()
+            .zip(&counts)
+            .all(|(objective, count)| *count >= objective.required)
+        {
+            QuestState::Complete
+        } else {
+            QuestState::Active
+        };
+
+        if counts != progress.counts || state != progress.state {
+            progress.counts = counts;
+            progress.state = state;
+            ctx.db.quest_progress().id().update(progress);
+        }
+    }
+}
+
+/// Credits a character with killing an NPC.
+pub(crate) fn record_kill(ctx: &ReducerContext, character_id: u32, npc_def_id: u32) {
+    update_objectives(ctx, character_id, |objective, count| match objective.kind {
+        ObjectiveKind::Kill(id) if id == npc_def_id => count + 1,
+        _ => count,
+    });
+}
+
+/// Credits a character with talking to an NPC.
+pub(crate) fn record_talk(ctx: &ReducerContext, character_id: u32, npc_def_id: u32) {
+    update_objectives(ctx, character_id, |objective, count| match objective.kind {
+        ObjectiveKind::TalkTo(id) if id == npc_def_id => 1,
+        _ => count,
+    });
+}
+
+/// Syncs collect objectives with what the character is holding. Called whenever a
+/// character's inventory changes.
+pub(crate) fn refresh_collected(ctx: &ReducerContext, character_id: u32) {
+    update_objectives(ctx, character_id, |objective, count| match objective.kind {
+        ObjectiveKind::Collect(item_def_id) => {
+            inventory::item_count(ctx, character_id, item_def_id)
+        }
+        _ => count,
+    });
+}
+
+/// Adds a quest to a character's journal after checking that the character can take it.
+/// Used when accepting quests from NPCs and by dialogue actions.
+pub(crate) fn start_quest(
+    ctx: &ReducerContext,
+    reducer: &str,
+    character_id: u32,
+    quest_def_id: u32,
+) -> Result<(), String> {
+    let Some(quest) = ctx.db.quest_def().id().find(quest_def_id) else {
+        let err = format!("{reducer}: quest not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if progress_for(ctx, character_id, quest.id).is_some() {
+        let err = format!("{reducer}: quest already accepted.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if progression::character_level(ctx, character_id) < quest.min_level {
+        let err = format!("{reducer}: level too low.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if let Some(prerequisite_id) = quest.prerequisite_quest_id
+        && quest_state(ctx, character_id, prerequisite_id) != Some(QuestState::TurnedIn)
+    {
+        let err = format!("{reducer}: prerequisite quest not completed.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if ctx
+        .db
+        .quest_progress()
+        .character_id()
+        .filter(character_id)
+        .filter(|progress| progress.state != QuestState::TurnedIn)
+        .count()
+        >= MAX_ACTIVE_QUESTS
+    {
+        let err = format!("{reducer}: journal is full.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    ctx.db.quest_progress().insert(QuestProgress {
+        id: 0,
+        character_id,
+        quest_def_id: quest.id,
+        state: QuestState::Active,
+        counts: vec![0; objectives(ctx, quest.id).len()],
+        accepted_at: ctx.timestamp,
+    });
+
+    // Items collected before accepting the quest count towards it
+    refresh_collected(ctx, character_id);
+
+    Ok(())
+}
+
+/// Accepts a quest from a nearby NPC.
+#[reducer]
+pub fn accept_quest(
+    ctx: &ReducerContext,
+    quest_def_id: u32,
+    npc_entity_id: u32,
+) -> Result<(), String> {
+    let (npc, character_id) = npc::npc_in_reach(ctx, "accept_quest", npc_entity_id)?;
+
+    if !ctx
+        .db
+        .quest_def()
+        .id()
+        .find(quest_def_id)
+        .is_some_and(|quest| quest.giver_npc_def_id == npc.npc_def_id)
+    {
+        let err = format!("accept_quest: NPC doesn't offer this quest.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    start_quest(ctx, "accept_quest", character_id, quest_def_id)
+}
+
+/// Drops a quest from the journal, losing all progress on it.
+#[reducer]
+pub fn abandon_quest(ctx: &ReducerContext, quest_def_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("abandon_quest: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    let Some(progress) = progress_for(ctx, pawn.character_id, quest_def_id)
+        .filter(|progress| progress.state != QuestState::TurnedIn)
+    else {
+        let err = format!("abandon_quest: quest not in journal.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    ctx.db.quest_progress().delete(progress);
+
+    Ok(())
+}
+
+/// Turns a completed quest in to a nearby NPC, handing over collected items and
+/// granting the quest's experience.
+#[reducer]
+pub fn turn_in_quest(
+    ctx: &ReducerContext,
+    quest_def_id: u32,
+    npc_entity_id: u32,
+) -> Result<(), String> {
+    let (npc, character_id) = npc::npc_in_reach(ctx, "turn_in_quest", npc_entity_id)?;
+
+    let Some(quest) = ctx
+        .db
+        .quest_def()
+        .id()
+        .find(quest_def_id)
+        .filter(|quest| quest.turn_in_npc_def_id == npc.npc_def_id)
+    else {
+        let err = format!("turn_in_quest: NPC doesn't take this quest.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    let Some(mut progress) = progress_for(ctx, character_id, quest.id)
+        .filter(|progress| progress.state == QuestState::Complete)
+    else {
+        let err = format!("turn_in_quest: quest is not complete.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    for objective in objectives(ctx, quest.id) {
+        if let ObjectiveKind::Collect(item_def_id) = objective.kind {
+            inventory::take_items(ctx, character_id, item_def_id, objective.required)?;
+        }
+    }
+
+    // Taking the items refreshed the progress row
+    progress = ctx
+        .db
+        .quest_progress()
+        .id()
+        .find(progress.id)
+        .unwrap_or(progress);
+    progress.state = QuestState::TurnedIn;
+    ctx.db.quest_progress().id().update(progress);
+
+    progression::grant_xp(ctx, character_id, quest.xp_reward);
+
+    Ok(())
+}
+
+/// Talks to a nearby NPC, completing any objectives asking for it.
+#[reducer]
+pub fn talk_to_npc(ctx: &ReducerContext, npc_entity_id: u32) -> Result<(), String> {
+    let (npc, character_id) = npc::npc_in_reach(ctx, "talk_to_npc", npc_entity_id)?;
+    record_talk(ctx, character_id, npc.npc_def_id);
+
+    Ok(())
+}
+
+#[reducer]
+fn quest_location_check(ctx: &ReducerContext, _timer: QuestLocationTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`quest_location_check` may not be invoked by clients.".into());
+    }
+
+    for pawn in ctx.db.character_pawn().iter() {
+        // Location objectives all point somewhere in the overworld
+        let Some((OVERWORLD_MAP_ID, translation)) = entity_location(ctx, pawn.entity_id) else {
+            continue;
+        };
+        let chunk_id = chunk::encode(translation.x, translation.z);
+
+        // Reaching a location is permanent, walking away again doesn't undo it
+        update_objectives(ctx, pawn.character_id, |objective, count| {
+            let reached = match objective.kind {
+                ObjectiveKind::ReachChunk(target) => target == chunk_id,
+                ObjectiveKind::ReachArea(area) => {
+                    translation.distance_squared_xz(area.center) <= area.radius * area.radius
+                }
+                _ => false,
+            };
+            if reached { 1 } else { count }
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::{
+    collision::{self, CollisionCircle, CollisionRect, CollisionShapeKind, collision_shape},
+    map::OVERWORLD_MAP_ID,
+};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.collision_shape().iter().next().is_none() {
+        // Broken pillars around the ruins east of the village
+        for (shape_id, x, z) in [(1, 22., 26.), (2, 26., 22.), (3, 41., 40.)] {
+            collision::insert_shape(
+                ctx,
+                OVERWORLD_MAP_ID,
+                shape_id,
+                CollisionShapeKind::Circle(CollisionCircle { x, z, radius: 1.5 }),
+            );
+        }
+
+        // What is left of the ruins' walls
+        for (shape_id, min_x, min_z, max_x, max_z) in
+            [(4, 24., 42., 36., 43.), (5, 38., 18., 42., 24.)]
+        {
+            collision::insert_shape(
+                ctx,
+                OVERWORLD_MAP_ID,
+                shape_id,
+                CollisionShapeKind::Rect(CollisionRect {
+                    min_x,
+                    min_z,
+                    max_x,
+                    max_z,
+                }),
+            );
+        }
+    }
+}
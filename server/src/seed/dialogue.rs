@@ -0,0 +1,176 @@
+use crate::{
+    dialogue::{
+        DialogueAction, DialogueChoice, DialogueCondition, DialogueNode, ItemGrant, QuestCondition,
+        dialogue_choice, dialogue_node,
+    },
+    types::Vec3,
+};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.dialogue_node().iter().next().is_none() {
+        // Warden Elric
+        ctx.db.dialogue_node().insert(DialogueNode {
+            id: 1,
+            npc_def_id: 1,
+            text: "The mist has been restless tonight. Stay close to the lanterns, traveler."
+                .into(),
+            entry: true,
+        });
+
+        ctx.db.dialogue_node().insert(DialogueNode {
+            id: 2,
+            npc_def_id: 1,
+            text: "Walk out to the edge of the mist and see how far it has crept. Then let Old Maren know whether it's safe to gather. Take some food, it's a long walk.".into(),
+            entry: false,
+        });
+
+        // Old Maren
+        ctx.db.dialogue_node().insert(DialogueNode {
+            id: 3,
+            npc_def_id: 2,
+            text: "Mind where you step, glowcaps bruise easily.".into(),
+            entry: true,
+        });
+
+        // Aurora
+        ctx.db.dialogue_node().insert(DialogueNode {
+            id: 4,
+            npc_def_id: 4,
+            text: "...the heart... it remembers you. Do you hear it too?".into(),
+            entry: true,
+        });
+
+        ctx.db.dialogue_node().insert(DialogueNode {
+            id: 5,
+            npc_def_id: 4,
+            text:
+                "Aurora... I was Aurora. I opened it. The light was so beautiful, before it wasn't."
+                    .into(),
+            entry: false,
+        });
+
+        ctx.db.dialogue_node().insert(DialogueNode {
+            id: 6,
+            npc_def_id: 4,
+            text: "Old roots, old blood... they drank the mist and did not drown. Not all of you will be so lucky.".into(),
+            entry: false,
+        });
+    }
+
+    if ctx.db.dialogue_choice().iter().next().is_none() {
+        ctx.db.dialogue_choice().insert(DialogueChoice {
+            id: 1,
+            node_id: 1,
+            text: "Is there anything I can do?".into(),
+            conditions: vec![DialogueCondition::Quest(QuestCondition {
+                quest_def_id: 1,
+                state: None,
+            })],
+            actions: vec![],
+            next_node_id: Some(2),
+        });
+
+        ctx.db.dialogue_choice().insert(DialogueChoice {
+            id: 2,
+            node_id: 1,
+            text: "Farewell.".into(),
+            conditions: vec![],
+            actions: vec![],
+            next_node_id: None,
+        });
+
+        ctx.db.dialogue_choice().insert(DialogueChoice {
+            id: 3,
+            node_id: 2,
+            text: "I'll go.".into(),
+            conditions: vec![],
+            actions: vec![
+                DialogueAction::StartQuest(1),
+                DialogueAction::GiveItem(ItemGrant {
+                    item_def_id: 4,
+                    quantity: 2,
+                }),
+            ],
+            next_node_id: None,
+        });
+
+        ctx.db.dialogue_choice().insert(DialogueChoice {
+            id: 4,
+            node_id: 2,
+            text: "Not now.".into(),
+            conditions: vec![],
+            actions: vec![],
+            next_node_id: None,
+        });
+
+        ctx.db.dialogue_choice().insert(DialogueChoice {
+            id: 5,
+            node_id: 3,
+            text: "Farewell.".into(),
+            conditions: vec![],
+            actions: vec![],
+            next_node_id: None,
+        });
+
+        ctx.db.dialogue_choice().insert(DialogueChoice {
+            id: 6,
+            node_id: 4,
+            text: "Who are you?".into(),
+            conditions: vec![],
+            actions: vec![],
+            next_node_id: Some(5),
+        });
+
+        ctx.db.dialogue_choice().insert(DialogueChoice {
+            id: 7,
+            node_id: 4,
+            text: "The trees whisper your name.".into(),
+            // Lumycus
+            conditions: vec![DialogueCondition::Race(4)],
+            actions: vec![],
+            next_node_id: Some(6),
+        });
+
+        ctx.db.dialogue_choice().insert(DialogueChoice {
+            id: 8,
+            node_id: 4,
+            text: "Show me where the mist begins.".into(),
+            conditions: vec![DialogueCondition::MinLevel(5)],
+            actions: vec![DialogueAction::Teleport(Vec3::new(20., 0., 20.))],
+            next_node_id: None,
+        });
+
+        ctx.db.dialogue_choice().insert(DialogueChoice {
+            id: 9,
+            node_id: 4,
+            text: "Leave her be.".into(),
+            conditions: vec![],
+            actions: vec![],
+            next_node_id: None,
+        });
+
+        ctx.db.dialogue_choice().insert(DialogueChoice {
+            id: 12,
+            node_id: 1,
+            text: "Could you bless me before I go?".into(),
+            conditions: vec![],
+            actions: vec![
+                DialogueAction::ApplyEffect(2),
+                DialogueAction::ApplyEffect(5),
+            ],
+            next_node_id: None,
+        });
+
+        for (id, node_id) in [(10, 5), (11, 6)] {
+            ctx.db.dialogue_choice().insert(DialogueChoice {
+                id,
+                node_id,
+                text: "...".into(),
+                conditions: vec![],
+                actions: vec![],
+                next_node_id: Some(4),
+            });
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{
+    dungeon::{DungeonDef, DungeonSpawn, dungeon_def, dungeon_spawn},
+    map::OVERWORLD_MAP_ID,
+    types::Vec3,
+};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.dungeon_def().iter().next().is_none() {
+        ctx.db.dungeon_def().insert(DungeonDef {
+            id: 1,
+            name: "The Sunken Vault".into(),
+            description:
+                "A flooded archive beneath the marsh, where the Veil first seeped into the world."
+                    .into(),
+            min_level: 5,
+            entrance_map_id: OVERWORLD_MAP_ID,
+            entrance: Vec3::new(60., 0., -40.),
+            arrival: Vec3::new(0., 0., 0.),
+            reset_secs: 2 * 60 * 60,
+            lockout_secs: 24 * 60 * 60,
+        });
+    }
+
+    if ctx.db.dungeon_spawn().iter().next().is_none() {
+        for (id, npc_def_id, x, z) in [
+            (1, 3, 4., 10.),
+            (2, 3, -4., 12.),
+            (3, 3, 0., 20.),
+            (4, 6, 0., 32.),
+        ] {
+            ctx.db.dungeon_spawn().insert(DungeonSpawn {
+                id,
+                dungeon_def_id: 1,
+                npc_def_id,
+                translation: Vec3::new(x, 0., z),
+            });
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::effect::{EffectDef, EffectKind, Stat, StatModifier, effect_def};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.effect_def().iter().next().is_none() {
+        ctx.db.effect_def().insert(EffectDef {
+            id: 1,
+            name: "Curse of Frailty".into(),
+            description: "Flesh and bone grow brittle, taking more damage from every blow.".into(),
+            kind: EffectKind::Modifier(StatModifier {
+                stat: Stat::DamageTaken,
+                percent: 10,
+            }),
+            duration_secs: 20,
+            tick_secs: 0,
+            radius: 0.0,
+            max_stacks: 3,
+        });
+
+        ctx.db.effect_def().insert(EffectDef {
+            id: 2,
+            name: "Blessing of Renewal".into(),
+            description: "Wounds slowly close on their own.".into(),
+            kind: EffectKind::Heal(5),
+            duration_secs: 10,
+            tick_secs: 2,
+            radius: 0.0,
+            max_stacks: 1,
+        });
+
+        ctx.db.effect_def().insert(EffectDef {
+            id: 3,
+            name: "Veil Chill".into(),
+            description: "The mist clings to your limbs, slowing every step.".into(),
+            kind: EffectKind::Modifier(StatModifier {
+                stat: Stat::MoveSpeed,
+                percent: -20,
+            }),
+            duration_secs: 3,
+            tick_secs: 0,
+            radius: 0.0,
+            max_stacks: 1,
+        });
+
+        ctx.db.effect_def().insert(EffectDef {
+            id: 4,
+            name: "Festering Wound".into(),
+            description:
+                "A wound that refuses to close, bleeding a little more each time it is struck."
+                    .into(),
+            kind: EffectKind::Damage(4),
+            duration_secs: 12,
+            tick_secs: 2,
+            radius: 0.0,
+            max_stacks: 5,
+        });
+
+        ctx.db.effect_def().insert(EffectDef {
+            id: 5,
+            name: "Swiftness".into(),
+            description: "Your feet feel light.".into(),
+            kind: EffectKind::Modifier(StatModifier {
+                stat: Stat::MoveSpeed,
+                percent: 30,
+            }),
+            duration_secs: 10,
+            tick_secs: 0,
+            radius: 0.0,
+            max_stacks: 1,
+        });
+
+        ctx.db.effect_def().insert(EffectDef {
+            id: 6,
+            name: "Cinder Cloak".into(),
+            description: "Embers swirl around you, searing every foe that comes close.".into(),
+            kind: EffectKind::Damage(4),
+            duration_secs: 10,
+            tick_secs: 1,
+            radius: 4.0,
+            max_stacks: 1,
+        });
+    }
+}
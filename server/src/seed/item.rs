@@ -0,0 +1,101 @@
+use crate::inventory::{ItemDef, ItemType, item_def, item_type};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.item_type().iter().next().is_none() {
+        for (id, name) in [
+            (1, "Reagent"),
+            (2, "Trade Good"),
+            (3, "Consumable"),
+            (4, "Mount"),
+        ] {
+            ctx.db.item_type().insert(ItemType {
+                id,
+                name: name.into(),
+            });
+        }
+    }
+
+    if ctx.db.item_def().iter().next().is_none() {
+        ctx.db.item_def().insert(ItemDef {
+            id: 1,
+            name: "Veil Shard".into(),
+            description: "A sliver of crystallized mist that hums faintly when held. Scholars and smugglers alike pay well for them.".into(),
+            item_type_id: 1,
+            level: 10,
+            max_stack: 100,
+            sell_price: 10,
+        });
+
+        ctx.db.item_def().insert(ItemDef {
+            id: 2,
+            name: "Iron Ore".into(),
+            description: "A rough chunk of ore pried from the mountain veins the Vrask have worked for generations.".into(),
+            item_type_id: 2,
+            level: 1,
+            max_stack: 50,
+            sell_price: 3,
+        });
+
+        ctx.db.item_def().insert(ItemDef {
+            id: 3,
+            name: "Glowcap".into(),
+            description: "A pale mushroom that blooms in darkened wilds, giving off a soft light long after it is picked.".into(),
+            item_type_id: 1,
+            level: 1,
+            max_stack: 50,
+            sell_price: 2,
+        });
+
+        ctx.db.item_def().insert(ItemDef {
+            id: 4,
+            name: "Traveler's Ration".into(),
+            description: "Hard bread, dried meat and a wedge of cheese wrapped in waxed cloth."
+                .into(),
+            item_type_id: 3,
+            level: 1,
+            max_stack: 20,
+            sell_price: 1,
+        });
+
+        ctx.db.item_def().insert(ItemDef {
+            id: 5,
+            name: "Iron Ingot".into(),
+            description: "A bar of smelted iron, ready for the anvil.".into(),
+            item_type_id: 2,
+            level: 5,
+            max_stack: 50,
+            sell_price: 8,
+        });
+
+        ctx.db.item_def().insert(ItemDef {
+            id: 6,
+            name: "Veilsteel Ingot".into(),
+            description: "Iron folded around a Veil Shard. It is cold to the touch and never quite stops shimmering.".into(),
+            item_type_id: 2,
+            level: 20,
+            max_stack: 20,
+            sell_price: 40,
+        });
+
+        ctx.db.item_def().insert(ItemDef {
+            id: 7,
+            name: "Glowcap Tonic".into(),
+            description: "A faintly luminous draught that keeps the mist's chill at bay.".into(),
+            item_type_id: 3,
+            level: 5,
+            max_stack: 20,
+            sell_price: 7,
+        });
+
+        ctx.db.item_def().insert(ItemDef {
+            id: 8,
+            name: "Marsh Strider Reins".into(),
+            description: "Worn leather reins for the long-legged striders the marsh folk ride through the shallows.".into(),
+            item_type_id: 4,
+            level: 5,
+            max_stack: 1,
+            sell_price: 50,
+        });
+    }
+}
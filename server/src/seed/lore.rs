@@ -0,0 +1,115 @@
+use crate::{
+    lore::{LoreCollection, LoreEntry, LoreKind, lore_collection, lore_entry},
+    types::Vec3,
+};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.lore_collection().iter().next().is_none() {
+        ctx.db.lore_collection().insert(LoreCollection {
+            id: 1,
+            name: "Aurora's Journals".into(),
+            description:
+                "Pages torn from the journal Aurora kept while searching for the artifact.".into(),
+            bonus_max_health: 0,
+            bonus_max_mana: 20,
+        });
+
+        ctx.db.lore_collection().insert(LoreCollection {
+            id: 2,
+            name: "Shards of the Aetherheart".into(),
+            description: "Fragments of the artifact, scattered when the Veil first tore loose."
+                .into(),
+            bonus_max_health: 10,
+            bonus_max_mana: 10,
+        });
+
+        ctx.db.lore_collection().insert(LoreCollection {
+            id: 3,
+            name: "Ruins of Aelynmar".into(),
+            description: "What remains of the places the Veil swallowed first.".into(),
+            bonus_max_health: 20,
+            bonus_max_mana: 0,
+        });
+    }
+
+    if ctx.db.lore_entry().iter().next().is_none() {
+        let entries = [
+            (
+                1,
+                1,
+                LoreKind::Journal,
+                "A Promising Dig",
+                "The old maps were right. Beneath the hill there is a chamber, and in the chamber there is a light that does not flicker.",
+                Vec3::new(-6., 0., -14.),
+            ),
+            (
+                2,
+                1,
+                LoreKind::Journal,
+                "The Light Speaks",
+                "It spoke today. Not in words, not yet. I think it has been waiting a very long time for someone to listen.",
+                Vec3::new(-40., 0., 25.),
+            ),
+            (
+                3,
+                1,
+                LoreKind::Journal,
+                "Unbound",
+                "I only meant to free it. I did not know the mist would follow, or that it would not stop.",
+                Vec3::new(45., 0., -30.),
+            ),
+            (
+                4,
+                2,
+                LoreKind::Shard,
+                "Shard of Longing",
+                "The shard is warm to the touch, and for a moment you feel homesick for a place you have never been.",
+                Vec3::new(70., 0., 55.),
+            ),
+            (
+                5,
+                2,
+                LoreKind::Shard,
+                "Shard of Silence",
+                "Every sound nearby seems to fall into the shard and vanish.",
+                Vec3::new(-75., 0., -60.),
+            ),
+            (
+                6,
+                2,
+                LoreKind::Shard,
+                "Shard of Memory",
+                "Faces flicker across its surface, none of them your own.",
+                Vec3::new(110., 0., 5.),
+            ),
+            (
+                7,
+                3,
+                LoreKind::Ruin,
+                "The Suspended Hall",
+                "Stone steps climb into the air and stop, still waiting for the rest of the hall to come back down.",
+                Vec3::new(-120., 0., 90.),
+            ),
+            (
+                8,
+                3,
+                LoreKind::Ruin,
+                "The Drowned Lanterns",
+                "A ring of lanterns still burns beneath the mist, tended by no one.",
+                Vec3::new(95., 0., -110.),
+            ),
+        ];
+        for (id, collection_id, kind, title, text, translation) in entries {
+            ctx.db.lore_entry().insert(LoreEntry {
+                id,
+                collection_id,
+                kind,
+                title: title.into(),
+                text: text.into(),
+                translation,
+                xp_reward: 25,
+            });
+        }
+    }
+}
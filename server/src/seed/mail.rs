@@ -0,0 +1,16 @@
+use crate::{
+    mail::{MailboxSpawn, mailbox_spawn},
+    types::Vec3,
+};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.mailbox_spawn().iter().next().is_none() {
+        for (id, x, z) in [(1, 2., 12.), (2, -10., -2.)] {
+            ctx.db.mailbox_spawn().insert(MailboxSpawn {
+                id,
+                translation: Vec3::new(x, 0., z),
+            });
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{
+    map::{Map, MapKind, OVERWORLD_MAP_ID, map},
+    types::Vec3,
+};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.map().iter().next().is_none() {
+        ctx.db.map().insert(Map {
+            id: OVERWORLD_MAP_ID,
+            name: "Aelynmar".into(),
+            kind: MapKind::World,
+            arrival: Vec3::new(0., 0., 0.),
+        });
+
+        ctx.db.map().insert(Map {
+            id: 2,
+            name: "The Shattered Reach".into(),
+            kind: MapKind::World,
+            arrival: Vec3::new(0., 0., 0.),
+        });
+    }
+}
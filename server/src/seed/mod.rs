@@ -2,13 +2,43 @@
 //! to seed that data on database start up.
 
 mod class;
+mod collision;
+mod dialogue;
+mod dungeon;
+mod effect;
+mod item;
+mod lore;
+mod mail;
+mod map;
+mod mount;
+mod npc;
+mod profession;
+mod quest;
 mod race;
+mod summon;
+mod travel;
+mod vendor;
 mod xp_progression;
 
 use spacetimedb::ReducerContext;
 
 pub fn seed_static_data(ctx: &ReducerContext) {
+    map::seed(ctx);
     race::seed(ctx);
     class::seed(ctx);
+    item::seed(ctx);
+    mount::seed(ctx);
+    npc::seed(ctx);
+    dungeon::seed(ctx);
+    travel::seed(ctx);
+    profession::seed(ctx);
+    quest::seed(ctx);
+    effect::seed(ctx);
+    summon::seed(ctx);
+    dialogue::seed(ctx);
+    lore::seed(ctx);
+    vendor::seed(ctx);
+    mail::seed(ctx);
     xp_progression::seed(ctx);
+    collision::seed(ctx);
 }
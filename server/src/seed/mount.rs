@@ -0,0 +1,24 @@
+use crate::mount::{MountDef, mount_def};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.mount_def().iter().next().is_none() {
+        ctx.db.mount_def().insert(MountDef {
+            id: 1,
+            name: "Marsh Strider".into(),
+            description: "A long-legged wading bird, tall enough to carry a rider through the deepest shallows.".into(),
+            speed_percent: 60,
+            item_def_id: Some(8),
+            class_id: None,
+        });
+
+        ctx.db.mount_def().insert(MountDef {
+            id: 2,
+            name: "Sunforged Charger".into(),
+            description: "A warhorse wreathed in pale light, called to the side of a Templar in need of haste.".into(),
+            speed_percent: 60,
+            item_def_id: None,
+            class_id: Some(2),
+        });
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    map::OVERWORLD_MAP_ID,
+    npc::{NpcDef, NpcSpawn, npc_def, npc_spawn},
+    types::Vec3,
+};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.npc_def().iter().next().is_none() {
+        ctx.db.npc_def().insert(NpcDef {
+            id: 1,
+            name: "Warden Elric".into(),
+            description: "A weathered sentry who has watched the Veil's edge for longer than he cares to admit.".into(),
+            level: 10,
+            max_health: 500,
+            hostile: false,
+            xp_reward: 0,
+        });
+
+        ctx.db.npc_def().insert(NpcDef {
+            id: 2,
+            name: "Old Maren".into(),
+            description: "An herbalist who knows every plant that grows in the Veil's shadow, and which of them are safe to eat.".into(),
+            level: 8,
+            max_health: 300,
+            hostile: false,
+            xp_reward: 0,
+        });
+
+        ctx.db.npc_def().insert(NpcDef {
+            id: 3,
+            name: "Mistling".into(),
+            description: "A small creature twisted by the Veil, more mist than flesh.".into(),
+            level: 1,
+            max_health: 40,
+            hostile: true,
+            xp_reward: 10,
+        });
+
+        ctx.db.npc_def().insert(NpcDef {
+            id: 4,
+            name: "Aurora".into(),
+            description: "The one who unearthed the artifact and set the Veil loose. Her voice is never wholly her own.".into(),
+            level: 50,
+            max_health: 5000,
+            hostile: false,
+            xp_reward: 0,
+        });
+
+        ctx.db.npc_def().insert(NpcDef {
+            id: 5,
+            name: "Peddler Tobin".into(),
+            description: "A cheerful trader who insists the mist is good for business.".into(),
+            level: 10,
+            max_health: 400,
+            hostile: false,
+            xp_reward: 0,
+        });
+
+        ctx.db.npc_def().insert(NpcDef {
+            id: 6,
+            name: "The Drowned Archivist".into(),
+            description:
+                "Keeper of the Sunken Vault, still cataloguing the tomes the water took from him."
+                    .into(),
+            level: 12,
+            max_health: 1500,
+            hostile: true,
+            xp_reward: 200,
+        });
+    }
+
+    if ctx.db.npc_spawn().iter().next().is_none() {
+        ctx.db.npc_spawn().insert(NpcSpawn {
+            id: 0,
+            map_id: OVERWORLD_MAP_ID,
+            npc_def_id: 1,
+            translation: Vec3::new(5., 0., 5.),
+            respawn_secs: 60,
+        });
+
+        ctx.db.npc_spawn().insert(NpcSpawn {
+            id: 0,
+            map_id: OVERWORLD_MAP_ID,
+            npc_def_id: 2,
+            translation: Vec3::new(-12., 0., 10.),
+            respawn_secs: 60,
+        });
+
+        for (x, z) in [(30., 30.), (34., 27.), (27., 35.), (36., 34.)] {
+            ctx.db.npc_spawn().insert(NpcSpawn {
+                id: 0,
+                map_id: OVERWORLD_MAP_ID,
+                npc_def_id: 3,
+                translation: Vec3::new(x, 0., z),
+                respawn_secs: 30,
+            });
+        }
+
+        ctx.db.npc_spawn().insert(NpcSpawn {
+            id: 0,
+            map_id: OVERWORLD_MAP_ID,
+            npc_def_id: 4,
+            translation: Vec3::new(0., 0., -8.),
+            respawn_secs: 60,
+        });
+
+        ctx.db.npc_spawn().insert(NpcSpawn {
+            id: 0,
+            map_id: OVERWORLD_MAP_ID,
+            npc_def_id: 5,
+            translation: Vec3::new(-8., 0., 14.),
+            respawn_secs: 60,
+        });
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    profession::{
+        Profession, Recipe, RecipeIngredient, ResourceNodeSpawn, profession, recipe,
+        recipe_ingredient, resource_node_spawn,
+    },
+    types::Vec3,
+};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.profession().iter().next().is_none() {
+        ctx.db.profession().insert(Profession {
+            id: 1,
+            name: "Mining".into(),
+            description: "Working the mountain veins for ore, a craft the Vrask have passed down for generations.".into(),
+        });
+
+        ctx.db.profession().insert(Profession {
+            id: 2,
+            name: "Herbalism".into(),
+            description: "Finding and picking the plants that grow in the wilds, and in the mist."
+                .into(),
+        });
+
+        ctx.db.profession().insert(Profession {
+            id: 3,
+            name: "Blacksmithing".into(),
+            description: "Smelting ore and hammering metal into something useful.".into(),
+        });
+
+        ctx.db.profession().insert(Profession {
+            id: 4,
+            name: "Alchemy".into(),
+            description: "Brewing tonics and draughts from whatever herbalists bring back.".into(),
+        });
+    }
+
+    if ctx.db.resource_node_spawn().iter().next().is_none() {
+        for (id, x, z) in [(1, -30., -20.), (2, -34., -26.), (3, -25., -28.)] {
+            ctx.db.resource_node_spawn().insert(ResourceNodeSpawn {
+                id,
+                translation: Vec3::new(x, 0., z),
+                profession_id: 1,
+                required_skill: 0,
+                item_def_id: 2,
+                quantity: 2,
+                gather_secs: 3,
+                respawn_secs: 60,
+            });
+        }
+
+        for (id, x, z) in [(4, 18., -22.), (5, 22., -18.), (6, 15., -15.)] {
+            ctx.db.resource_node_spawn().insert(ResourceNodeSpawn {
+                id,
+                translation: Vec3::new(x, 0., z),
+                profession_id: 2,
+                required_skill: 0,
+                item_def_id: 3,
+                quantity: 1,
+                gather_secs: 2,
+                respawn_secs: 45,
+            });
+        }
+    }
+
+    if ctx.db.recipe().iter().next().is_none() {
+        ctx.db.recipe().insert(Recipe {
+            id: 1,
+            name: "Smelt Iron".into(),
+            profession_id: 3,
+            required_skill: 0,
+            result_item_def_id: 5,
+            result_quantity: 1,
+        });
+
+        ctx.db.recipe().insert(Recipe {
+            id: 2,
+            name: "Veilsteel Ingot".into(),
+            profession_id: 3,
+            required_skill: 25,
+            result_item_def_id: 6,
+            result_quantity: 1,
+        });
+
+        ctx.db.recipe().insert(Recipe {
+            id: 3,
+            name: "Glowcap Tonic".into(),
+            profession_id: 4,
+            required_skill: 0,
+            result_item_def_id: 7,
+            result_quantity: 1,
+        });
+    }
+
+    if ctx.db.recipe_ingredient().iter().next().is_none() {
+        for (id, recipe_id, item_def_id, quantity) in
+            [(1, 1, 2, 2), (2, 2, 5, 2), (3, 2, 1, 1), (4, 3, 3, 3)]
+        {
+            ctx.db.recipe_ingredient().insert(RecipeIngredient {
+                id,
+                recipe_id,
+                item_def_id,
+                quantity,
+            });
+        }
+    }
+}
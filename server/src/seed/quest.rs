@@ -0,0 +1,79 @@
+use crate::{
+    quest::{ObjectiveKind, QuestDef, QuestObjective, ReachArea, quest_def, quest_objective},
+    types::Vec3,
+};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.quest_def().iter().next().is_none() {
+        ctx.db.quest_def().insert(QuestDef {
+            id: 1,
+            name: "The Edge of the Mist".into(),
+            description: "Warden Elric wants to know how far the Veil has crept since last night. Walk out to the mist's edge, then let Old Maren know it's safe to gather again.".into(),
+            min_level: 1,
+            prerequisite_quest_id: None,
+            giver_npc_def_id: 1,
+            turn_in_npc_def_id: 2,
+            xp_reward: 50,
+        });
+
+        ctx.db.quest_def().insert(QuestDef {
+            id: 2,
+            name: "Glowcaps for Maren".into(),
+            description: "Old Maren's stores are running low. Bring her a handful of Glowcaps from the darkened wilds.".into(),
+            min_level: 1,
+            prerequisite_quest_id: Some(1),
+            giver_npc_def_id: 2,
+            turn_in_npc_def_id: 2,
+            xp_reward: 80,
+        });
+
+        ctx.db.quest_def().insert(QuestDef {
+            id: 3,
+            name: "Thinning the Mist".into(),
+            description: "Mistlings have been wandering too close to the settlement. Warden Elric wants them driven back.".into(),
+            min_level: 2,
+            prerequisite_quest_id: Some(1),
+            giver_npc_def_id: 1,
+            turn_in_npc_def_id: 1,
+            xp_reward: 120,
+        });
+    }
+
+    if ctx.db.quest_objective().iter().next().is_none() {
+        ctx.db.quest_objective().insert(QuestObjective {
+            id: 1,
+            quest_def_id: 1,
+            description: "Reach the edge of the mist".into(),
+            kind: ObjectiveKind::ReachArea(ReachArea {
+                center: Vec3::new(30., 0., 30.),
+                radius: 10.,
+            }),
+            required: 1,
+        });
+
+        ctx.db.quest_objective().insert(QuestObjective {
+            id: 2,
+            quest_def_id: 1,
+            description: "Speak with Old Maren".into(),
+            kind: ObjectiveKind::TalkTo(2),
+            required: 1,
+        });
+
+        ctx.db.quest_objective().insert(QuestObjective {
+            id: 3,
+            quest_def_id: 2,
+            description: "Glowcaps collected".into(),
+            kind: ObjectiveKind::Collect(3),
+            required: 5,
+        });
+
+        ctx.db.quest_objective().insert(QuestObjective {
+            id: 4,
+            quest_def_id: 3,
+            description: "Mistlings slain".into(),
+            kind: ObjectiveKind::Kill(3),
+            required: 4,
+        });
+    }
+}
@@ -0,0 +1,36 @@
+use crate::summon::{SummonDef, SummonKind, summon_def};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.summon_def().iter().next().is_none() {
+        ctx.db.summon_def().insert(SummonDef {
+            id: 1,
+            name: "Voidling".into(),
+            description: "A sliver of the dark between the stars, bound to do its summoner's bidding.".into(),
+            kind: SummonKind::Pet,
+            class_id: 4,
+            max_health: 60,
+            attack_damage: 6,
+            follow_radius: 3.0,
+            lifetime_secs: None,
+            aura_effect_def_id: None,
+            aura_radius: 0.0,
+        });
+
+        ctx.db.summon_def().insert(SummonDef {
+            id: 2,
+            name: "Totem of Renewal".into(),
+            description:
+                "A carved spirit post that slowly mends the wounds of those standing near it."
+                    .into(),
+            kind: SummonKind::Totem,
+            class_id: 3,
+            max_health: 30,
+            attack_damage: 0,
+            follow_radius: 0.0,
+            lifetime_secs: Some(60),
+            aura_effect_def_id: Some(2),
+            aura_radius: 10.0,
+        });
+    }
+}
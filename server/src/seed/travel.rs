@@ -0,0 +1,24 @@
+use crate::{
+    map::OVERWORLD_MAP_ID,
+    travel::{Waypoint, waypoint},
+    types::Vec3,
+};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.waypoint().iter().next().is_none() {
+        for (id, name, map_id, x, z) in [
+            (1, "Wardens' Rest", OVERWORLD_MAP_ID, 0., 0.),
+            (2, "Marsh Landing", OVERWORLD_MAP_ID, 55., -30.),
+            (3, "Reach Outpost", 2, 0., 0.),
+        ] {
+            ctx.db.waypoint().insert(Waypoint {
+                id,
+                name: name.into(),
+                map_id,
+                translation: Vec3::new(x, 0., z),
+                discover_radius: 15.,
+            });
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::vendor::{Vendor, VendorItem, vendor, vendor_item};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.vendor().iter().next().is_none() {
+        ctx.db.vendor().insert(Vendor {
+            id: 1,
+            npc_def_id: 5,
+            restock_secs: 300,
+        });
+    }
+
+    if ctx.db.vendor_item().iter().next().is_none() {
+        for (id, item_def_id, price, max_stock) in [
+            (1, 4, 5, None),
+            (2, 3, 8, Some(10)),
+            (3, 2, 12, Some(5)),
+            (4, 8, 200, None),
+        ] {
+            ctx.db.vendor_item().insert(VendorItem {
+                id,
+                vendor_id: 1,
+                item_def_id,
+                price,
+                max_stock,
+                stock: max_stock.unwrap_or(0),
+            });
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Friend and ignore lists.
+//!
+//! Relationships are between players rather than characters, so they are keyed by
+//! `Identity` and carry over to every character of a player. A friend is online while
+//! one of their characters has a `character_pawn`.
+
+use crate::{
+    character::{character_def, character_pawn},
+    party::{PartyInvite, party_invite},
+};
+use spacetimedb::{
+    Filter, Identity, ReducerContext, Table, Timestamp, client_visibility_filter, reducer, table,
+};
+
+const MAX_FRIENDS: usize = 50;
+const MAX_IGNORED: usize = 50;
+
+#[client_visibility_filter]
+const FRIEND_SECURITY: Filter = Filter::Sql("SELECT * FROM friend WHERE owner = :sender");
+
+#[client_visibility_filter]
+const IGNORED_PLAYER_SECURITY: Filter =
+    Filter::Sql("SELECT * FROM ignored_player WHERE owner = :sender");
+
+#[client_visibility_filter]
+const FRIEND_NOTIFICATION_SECURITY: Filter =
+    Filter::Sql("SELECT * FROM friend_notification WHERE recipient = :sender");
+
+/// An entry in a player's friend list. Friendship is one-sided, like a contact list.
+#[table(name = friend, public)]
+pub struct Friend {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub owner: Identity,
+
+    #[index(btree)]
+    pub friend: Identity,
+
+    /// Name of the character the friend was last seen playing
+    pub name: String,
+}
+
+/// An entry in a player's ignore list.
+#[table(name = ignored_player, public)]
+pub struct IgnoredPlayer {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub owner: Identity,
+
+    #[index(btree)]
+    pub ignored: Identity,
+
+    /// Name of the character the player was playing when ignored
+    pub name: String,
+}
+
+/// Tells an in-game player that one of their friends came online or went offline.
+/// Cleared when the recipient leaves the world.
+#[table(name = friend_notification, public)]
+pub struct FriendNotification {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub recipient: Identity,
+
+    pub friend: Identity,
+    pub name: String,
+    pub online: bool,
+    pub created_at: Timestamp,
+}
+
+/// Whether `owner` has `other` on their ignore list.
+pub(crate) fn is_ignoring(ctx: &ReducerContext, owner: Identity, other: Identity) -> bool {
+    ctx.db
+        .ignored_player()
+        .owner()
+        .filter(owner)
+        .any(|row| row.ignored == other)
+}
+
+/// Notifies everyone who has `identity` as a friend and is in the world.
+fn notify_friends(ctx: &ReducerContext, identity: Identity, name: &str, online: bool) {
+    let rows: Vec<Friend> = ctx.db.friend().friend().filter(identity).collect();
+    for mut row in rows {
+        if online && row.name != name {
+            row.name = name.into();
+            row = ctx.db.friend().id().update(row);
+        }
+
+        if ctx.db.character_pawn().identity().find(row.owner).is_none()
+            || is_ignoring(ctx, identity, row.owner)
+        {
+            continue;
+        }
+
+        ctx.db.friend_notification().insert(FriendNotification {
+            id: 0,
+            recipient: row.owner,
+            friend: identity,
+            name: name.into(),
+            online,
+            created_at: ctx.timestamp,
+        });
+    }
+}
+
+pub(crate) fn enter_world(ctx: &ReducerContext, identity: Identity, character_id: u32) {
+    if let Some(character) = ctx.db.character_def().id().find(character_id) {
+        notify_friends(ctx, identity, &character.name, true);
+    }
+}
+
+pub(crate) fn leave_world(ctx: &ReducerContext, identity: Identity, character_id: u32) {
+    ctx.db.friend_notification().recipient().delete(identity);
+    if let Some(character) = ctx.db.character_def().id().find(character_id) {
+        notify_friends(ctx, identity, &character.name, false);
+    }
+}
+
+/// Finds the player controlling the character with the given name.
+fn player_by_character_name(
+    ctx: &ReducerContext,
+    reducer: &str,
+    character_name: &str,
+) -> Result<(Identity, String), String> {
+    let Some(character) = ctx
+        .db
+        .character_def()
+        .name()
+        .filter(character_name.trim())
+        .next()
+    else {
+        let err = format!("{reducer}: character not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if character.identity == ctx.sender {
+        let err = format!("{reducer}: cannot target yourself.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    Ok((character.identity, character.name))
+}
+
+/// Adds the player controlling the named character to the sender's friend list.
+#[reducer]
+pub fn add_friend(ctx: &ReducerContext, character_name: String) -> Result<(), String> {
+    let (identity, name) = player_by_character_name(ctx, "add_friend", &character_name)?;
+
+    let friends: Vec<Friend> = ctx.db.friend().owner().filter(ctx.sender).collect();
+    if friends.iter().any(|row| row.friend == identity) {
+        let err = format!("add_friend: already a friend.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if friends.len() >= MAX_FRIENDS {
+        let err = format!("add_friend: friend list is full.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    // Befriending someone takes them off the ignore list
+    if let Some(row) = ctx
+        .db
+        .ignored_player()
+        .owner()
+        .filter(ctx.sender)
+        .find(|row| row.ignored == identity)
+    {
+        ctx.db.ignored_player().delete(row);
+    }
+
+    ctx.db.friend().insert(Friend {
+        id: 0,
+        owner: ctx.sender,
+        friend: identity,
+        name,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn remove_friend(ctx: &ReducerContext, friend_id: u32) -> Result<(), String> {
+    let Some(row) = ctx
+        .db
+        .friend()
+        .id()
+        .find(friend_id)
+        .filter(|row| row.owner == ctx.sender)
+    else {
+        let err = format!("remove_friend: friend not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    ctx.db.friend().delete(row);
+
+    Ok(())
+}
+
+/// Adds the player controlling the named character to the sender's ignore list.
+/// Ignored players can't invite the sender to trades, parties or guilds and their chat
+/// messages aren't delivered to the sender.
+#[reducer]
+pub fn ignore_player(ctx: &ReducerContext, character_name: String) -> Result<(), String> {
+    let (identity, name) = player_by_character_name(ctx, "ignore_player", &character_name)?;
+
+    let ignored: Vec<IgnoredPlayer> = ctx.db.ignored_player().owner().filter(ctx.sender).collect();
+    if ignored.iter().any(|row| row.ignored == identity) {
+        let err = format!("ignore_player: already ignored.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if ignored.len() >= MAX_IGNORED {
+        let err = format!("ignore_player: ignore list is full.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if let Some(row) = ctx
+        .db
+        .friend()
+        .owner()
+        .filter(ctx.sender)
+        .find(|row| row.friend == identity)
+    {
+        ctx.db.friend().delete(row);
+    }
+    // Pending invites from the ignored player are void
+    let invites: Vec<PartyInvite> = ctx
+        .db
+        .party_invite()
+        .invitee()
+        .filter(ctx.sender)
+        .filter(|invite| invite.inviter == identity)
+        .collect();
+    for invite in invites {
+        ctx.db.party_invite().delete(invite);
+    }
+
+    ctx.db.ignored_player().insert(IgnoredPlayer {
+        id: 0,
+        owner: ctx.sender,
+        ignored: identity,
+        name,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn unignore_player(ctx: &ReducerContext, ignored_player_id: u32) -> Result<(), String> {
+    let Some(row) = ctx
+        .db
+        .ignored_player()
+        .id()
+        .find(ignored_player_id)
+        .filter(|row| row.owner == ctx.sender)
+    else {
+        let err = format!("unignore_player: player not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    ctx.db.ignored_player().delete(row);
+
+    Ok(())
+}
@@ -0,0 +1,281 @@
+//! Stealth and detection.
+//!
+//! A stealthed entity's transform is flagged `stealthed` and stops being sent to clients,
+//! except to the ones that detect it. Who detects what is recomputed on every tick into
+//! `stealth_detection`, a projection the transform visibility filters join against, so
+//! clients never learn where an undetected entity is. A character always detects itself.
+//!
+//! Observers detect a stealthed entity within a radius that grows with their level over
+//! the stealther's, as long as nothing blocks their line of sight. Attacking or taking
+//! damage breaks stealth.
+
+use crate::{
+    character::{character_def, character_pawn},
+    collision, entity, entity_location,
+    progression::character_level,
+    threat::npc_threat,
+    transform,
+    types::Vec3,
+};
+use spacetimedb::{
+    Filter, Identity, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp,
+    client_visibility_filter, reducer, table,
+};
+
+/// How often detection is recomputed.
+const TICK_MICRO_SECS: i64 = 250_000;
+/// The only class that can stealth.
+const STALKER_CLASS_ID: u32 = 5;
+/// The detection radius between observers and stealthers of the same level.
+const BASE_DETECTION_RADIUS: f32 = 9.0;
+/// How much each level the observer has over the stealther adds to the radius.
+const DETECTION_RADIUS_PER_LEVEL: f32 = 1.0;
+const MIN_DETECTION_RADIUS: f32 = 1.5;
+const MAX_DETECTION_RADIUS: f32 = 20.0;
+
+/// Transforms that aren't stealthed are visible to everyone on the same map, so
+/// instances don't leak to the open world.
+#[client_visibility_filter]
+const TRANSFORM_VISIBLE: Filter = Filter::Sql(
+    "SELECT transform.* FROM transform JOIN character_pawn ON transform.map_id = character_pawn.map_id WHERE character_pawn.identity = :sender AND transform.stealthed = false",
+);
+
+/// Players always see their own characters, in the world or not.
+#[client_visibility_filter]
+const TRANSFORM_OWNER: Filter = Filter::Sql(
+    "SELECT transform.* FROM transform JOIN character_def ON transform.id = character_def.transform_id WHERE character_def.identity = :sender",
+);
+
+/// Stealthed transforms are only visible to the clients detecting them.
+#[client_visibility_filter]
+const TRANSFORM_DETECTED: Filter = Filter::Sql(
+    "SELECT transform.* FROM transform JOIN stealth_detection ON transform.id = stealth_detection.transform_id WHERE stealth_detection.viewer = :sender",
+);
+
+#[client_visibility_filter]
+const STEALTH_SECURITY: Filter = Filter::Sql(
+    "SELECT stealth.* FROM stealth JOIN character_pawn ON stealth.entity_id = character_pawn.entity_id WHERE character_pawn.identity = :sender",
+);
+
+#[client_visibility_filter]
+const STEALTH_DETECTION_SECURITY: Filter =
+    Filter::Sql("SELECT * FROM stealth_detection WHERE viewer = :sender");
+
+/// An entity in stealth.
+#[table(name = stealth, public)]
+pub struct Stealth {
+    #[primary_key]
+    pub entity_id: u32,
+
+    /// The stealther's level when it entered stealth
+    pub level: u8,
+
+    pub started_at: Timestamp,
+}
+
+/// A client that currently detects a stealthed entity.
+#[table(name = stealth_detection, public)]
+pub struct StealthDetection {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub viewer: Identity,
+
+    #[index(btree)]
+    pub entity_id: u32,
+
+    pub transform_id: u32,
+}
+
+#[table(name = stealth_tick_timer, scheduled(stealth_tick))]
+struct StealthTickTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+pub fn init(ctx: &ReducerContext) {
+    ctx.db.stealth_tick_timer().scheduled_id().delete(1);
+    ctx.db.stealth_tick_timer().insert(StealthTickTimer {
+        scheduled_id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(TICK_MICRO_SECS)),
+    });
+}
+
+/// How close an observer needs to be to detect a stealther.
+pub(crate) fn detection_radius(observer_level: u8, stealth_level: u8) -> f32 {
+    let difference = observer_level as f32 - stealth_level as f32;
+    (BASE_DETECTION_RADIUS + difference * DETECTION_RADIUS_PER_LEVEL)
+        .clamp(MIN_DETECTION_RADIUS, MAX_DETECTION_RADIUS)
+}
+
+fn set_stealthed(ctx: &ReducerContext, entity_id: u32, stealthed: bool) -> Option<u32> {
+    let entity = ctx.db.entity().id().find(entity_id)?;
+    let mut transform = ctx.db.transform().id().find(entity.transform_id)?;
+    transform.stealthed = stealthed;
+    ctx.db.transform().id().update(transform);
+    Some(entity.transform_id)
+}
+
+/// Takes an entity out of stealth, e.g. when it attacks, takes damage or leaves the world.
+pub(crate) fn break_stealth(ctx: &ReducerContext, entity_id: u32) {
+    if ctx.db.stealth().entity_id().find(entity_id).is_none() {
+        return;
+    }
+    ctx.db.stealth().entity_id().delete(entity_id);
+    ctx.db.stealth_detection().entity_id().delete(entity_id);
+    set_stealthed(ctx, entity_id, false);
+}
+
+/// Puts the sender's character into stealth.
+#[reducer]
+pub fn enter_stealth(ctx: &ReducerContext) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("enter_stealth: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(character) = ctx.db.character_def().id().find(pawn.character_id) else {
+        let err = format!("enter_stealth: unable to find character.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if character.class_id != STALKER_CLASS_ID {
+        let err = format!("enter_stealth: only Stalkers can stealth.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if ctx.db.stealth().entity_id().find(pawn.entity_id).is_some() {
+        let err = format!("enter_stealth: already in stealth.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if ctx
+        .db
+        .npc_threat()
+        .target_entity_id()
+        .filter(pawn.entity_id)
+        .next()
+        .is_some()
+    {
+        let err = format!("enter_stealth: cannot stealth in combat.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    let Some(transform_id) = set_stealthed(ctx, pawn.entity_id, true) else {
+        let err = format!("enter_stealth: unable to find transform.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    ctx.db.stealth().insert(Stealth {
+        entity_id: pawn.entity_id,
+        level: character_level(ctx, pawn.character_id),
+        started_at: ctx.timestamp,
+    });
+    // Detected by itself straight away rather than on the next tick
+    ctx.db.stealth_detection().insert(StealthDetection {
+        id: 0,
+        viewer: ctx.sender,
+        entity_id: pawn.entity_id,
+        transform_id,
+    });
+
+    Ok(())
+}
+
+/// Takes the sender's character out of stealth.
+#[reducer]
+pub fn leave_stealth(ctx: &ReducerContext) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("leave_stealth: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    break_stealth(ctx, pawn.entity_id);
+
+    Ok(())
+}
+
+/// Recomputes which clients detect each stealthed entity, only touching the rows that
+/// changed so clients aren't sent the same detections over and over.
+#[reducer]
+fn stealth_tick(ctx: &ReducerContext, _timer: StealthTickTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`stealth_tick` may not be invoked by clients.".into());
+    }
+
+    // Every character in the world that could be looking
+    let observers: Vec<(Identity, u32, u8, u32, Vec3)> = ctx
+        .db
+        .character_pawn()
+        .iter()
+        .filter_map(|pawn| {
+            let (map_id, translation) = entity_location(ctx, pawn.entity_id)?;
+            let level = character_level(ctx, pawn.character_id);
+            Some((pawn.identity, pawn.entity_id, level, map_id, translation))
+        })
+        .collect();
+
+    let stealthed: Vec<Stealth> = ctx.db.stealth().iter().collect();
+    for stealth in stealthed {
+        let Some(entity) = ctx.db.entity().id().find(stealth.entity_id) else {
+            ctx.db.stealth().entity_id().delete(stealth.entity_id);
+            ctx.db
+                .stealth_detection()
+                .entity_id()
+                .delete(stealth.entity_id);
+            continue;
+        };
+        let Some((map_id, translation)) = entity_location(ctx, entity.id) else {
+            continue;
+        };
+
+        let viewers: Vec<Identity> = observers
+            .iter()
+            .filter(
+                |(_, observer_entity_id, observer_level, observer_map_id, observer_translation)| {
+                    if *observer_entity_id == entity.id {
+                        return true;
+                    }
+                    if *observer_map_id != map_id {
+                        return false;
+                    }
+                    let radius = detection_radius(*observer_level, stealth.level);
+                    translation.distance_squared_xz(*observer_translation) <= radius * radius
+                        && collision::line_of_sight(ctx, map_id, *observer_translation, translation)
+                },
+            )
+            .map(|(identity, ..)| *identity)
+            .collect();
+
+        let existing: Vec<StealthDetection> = ctx
+            .db
+            .stealth_detection()
+            .entity_id()
+            .filter(entity.id)
+            .collect();
+        for row in &existing {
+            if !viewers.contains(&row.viewer) {
+                ctx.db.stealth_detection().id().delete(row.id);
+            }
+        }
+        for viewer in viewers {
+            if existing.iter().any(|row| row.viewer == viewer) {
+                continue;
+            }
+            ctx.db.stealth_detection().insert(StealthDetection {
+                id: 0,
+                viewer,
+                entity_id: entity.id,
+                transform_id: entity.transform_id,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,501 @@
+//! Summoned pets and totems.
+//!
+//! Summons are regular entities with a transform and health, narrowed down by
+//! `owned_entity` to the entity that summoned them. Pets follow their owner around and
+//! can be told to attack, follow or stay. Totems stay where they were dropped, bless
+//! their owner's party while it stands nearby and crumble once their lifetime runs out.
+//! Summons are dismissed when their owner leaves the world.
+
+use crate::{
+    Entity, Health, Transform, area,
+    character::{character_def, character_pawn},
+    collision, combat,
+    effect::{self, active_effect},
+    entities_in_range, entity, entity_location, health,
+    movement::{self, MoveIntent, entity_movement},
+    npc::{self, npc},
+    party, threat, transform,
+    types::{Quat, Vec3},
+};
+use common::{area::Area, chunk};
+use spacetimedb::{
+    Filter, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp,
+    client_visibility_filter, reducer, table,
+};
+
+/// How often summons decide what to do.
+const TICK_MICRO_SECS: i64 = 250_000;
+/// How far a pet can wander from its owner before it is pulled back to them.
+const PET_LEASH_RANGE_SQUARED: f32 = 40.0 * 40.0;
+/// How close a pet needs to be to its target to attack it.
+const PET_ATTACK_RANGE_SQUARED: f32 = 2.5 * 2.5;
+/// How often a pet attacks.
+const PET_ATTACK_MICRO_SECS: i64 = 2_000_000;
+/// How far to either side of where a pet faces it can attack, in radians.
+const PET_ATTACK_HALF_ANGLE: f32 = std::f32::consts::FRAC_PI_4;
+/// How far away a target can be for a pet to be sent after it.
+const PET_COMMAND_RANGE_SQUARED: f32 = 30.0 * 30.0;
+
+/// The owner sees the health of their summons.
+#[client_visibility_filter]
+const HEALTH_SUMMON: Filter = Filter::Sql(
+    "SELECT health.* FROM health JOIN owned_entity ON health.id = owned_entity.health_id JOIN character_pawn ON owned_entity.owner_entity_id = character_pawn.entity_id WHERE character_pawn.identity = :sender",
+);
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum SummonKind {
+    Pet,
+    Totem,
+}
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum PetCommand {
+    Follow,
+    Stay,
+    /// Chase and attack an entity
+    Attack(u32),
+}
+
+/// The static definition of something a class can summon.
+#[table(name = summon_def, public)]
+pub struct SummonDef {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub description: String,
+    pub kind: SummonKind,
+
+    /// The class that can summon it
+    #[index(btree)]
+    pub class_id: u32,
+
+    pub max_health: u16,
+
+    /// Damage a pet deals with every attack
+    pub attack_damage: u16,
+
+    /// How far a pet lets its owner get before following
+    pub follow_radius: f32,
+
+    /// How long a totem lasts, `None` for summons that last until dismissed
+    pub lifetime_secs: Option<u32>,
+
+    /// The effect a totem keeps on its owner's party within `aura_radius`
+    pub aura_effect_def_id: Option<u32>,
+    pub aura_radius: f32,
+}
+
+/// A type-narrowing table for in-game entities that were summoned by another entity.
+#[table(name = owned_entity, public)]
+pub struct OwnedEntity {
+    #[primary_key]
+    pub entity_id: u32,
+
+    #[index(btree)]
+    pub owner_entity_id: u32,
+
+    pub summon_def_id: u32,
+    pub kind: SummonKind,
+    pub health_id: u32,
+
+    /// What a pet was last told to do, totems always stay
+    pub command: PetCommand,
+
+    pub next_attack_at: Timestamp,
+
+    /// When a totem crumbles, `None` for summons that last until dismissed
+    pub expires_at: Option<Timestamp>,
+}
+
+#[table(name = summon_tick_timer, scheduled(summon_tick))]
+struct SummonTickTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+#[table(name = summon_expiry_timer, scheduled(summon_expire))]
+struct SummonExpiryTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+
+    #[index(btree)]
+    entity_id: u32,
+}
+
+pub fn init(ctx: &ReducerContext) {
+    ctx.db.summon_tick_timer().scheduled_id().delete(1);
+    ctx.db.summon_tick_timer().insert(SummonTickTimer {
+        scheduled_id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(TICK_MICRO_SECS)),
+    });
+}
+
+/// Removes a summon from the world. Does nothing for entities that aren't summons.
+pub(crate) fn despawn(ctx: &ReducerContext, entity_id: u32) {
+    let Some(owned) = ctx.db.owned_entity().entity_id().find(entity_id) else {
+        return;
+    };
+
+    effect::clear(ctx, entity_id);
+    threat::forget_target(ctx, entity_id);
+    ctx.db.entity_movement().entity_id().delete(entity_id);
+    ctx.db.summon_expiry_timer().entity_id().delete(entity_id);
+    if let Some(entity) = ctx.db.entity().id().find(entity_id) {
+        ctx.db.transform().id().delete(entity.transform_id);
+        ctx.db.entity().delete(entity);
+    }
+    ctx.db.health().id().delete(owned.health_id);
+    ctx.db.owned_entity().delete(owned);
+}
+
+/// Dismisses everything an entity summoned, e.g. when it leaves the world.
+pub(crate) fn despawn_for_owner(ctx: &ReducerContext, owner_entity_id: u32) {
+    let entity_ids: Vec<u32> = ctx
+        .db
+        .owned_entity()
+        .owner_entity_id()
+        .filter(owner_entity_id)
+        .map(|owned| owned.entity_id)
+        .collect();
+    for entity_id in entity_ids {
+        despawn(ctx, entity_id);
+    }
+}
+
+/// The entity that summoned `entity_id`, if it is a summon.
+pub(crate) fn owner_of(ctx: &ReducerContext, entity_id: u32) -> Option<u32> {
+    ctx.db
+        .owned_entity()
+        .entity_id()
+        .find(entity_id)
+        .map(|owned| owned.owner_entity_id)
+}
+
+/// Summons a pet or drops a totem at the sender's character. A new pet replaces the
+/// current one, and a totem replaces the one of the same kind.
+#[reducer]
+pub fn summon(ctx: &ReducerContext, summon_def_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("summon: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(summon_def) = ctx.db.summon_def().id().find(summon_def_id) else {
+        let err = format!("summon: invalid summon.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let class_id = ctx
+        .db
+        .character_def()
+        .id()
+        .find(pawn.character_id)
+        .map(|character| character.class_id);
+    if class_id != Some(summon_def.class_id) {
+        let err = format!("summon: your class cannot summon {}.", summon_def.name);
+        log::warn!("{err}");
+        return Err(err);
+    }
+    let Some((map_id, translation)) = entity_location(ctx, pawn.entity_id) else {
+        let err = format!("summon: unable to find transform.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    let replaced: Vec<u32> = ctx
+        .db
+        .owned_entity()
+        .owner_entity_id()
+        .filter(pawn.entity_id)
+        .filter(|owned| match summon_def.kind {
+            SummonKind::Pet => owned.kind == SummonKind::Pet,
+            SummonKind::Totem => owned.summon_def_id == summon_def.id,
+        })
+        .map(|owned| owned.entity_id)
+        .collect();
+    for entity_id in replaced {
+        despawn(ctx, entity_id);
+    }
+
+    // Pets appear beside their owner, totems right at their feet
+    let translation = match summon_def.kind {
+        SummonKind::Pet => Vec3::new(
+            translation.x + summon_def.follow_radius,
+            translation.y,
+            translation.z,
+        ),
+        SummonKind::Totem => translation,
+    };
+    let transform = ctx.db.transform().insert(Transform {
+        id: 0,
+        translation,
+        rotation: Quat::default(),
+        scale: Vec3::default(),
+        map_id,
+        chunk_id: chunk::encode(translation.x, translation.z),
+        stealthed: false,
+    });
+    let entity = ctx.db.entity().insert(Entity {
+        id: 0,
+        transform_id: transform.id,
+    });
+    let health = ctx.db.health().insert(Health::new(summon_def.max_health));
+    let expires_at = summon_def
+        .lifetime_secs
+        .map(|secs| ctx.timestamp + TimeDuration::from_micros(secs as i64 * 1_000_000));
+    ctx.db.owned_entity().insert(OwnedEntity {
+        entity_id: entity.id,
+        owner_entity_id: pawn.entity_id,
+        summon_def_id: summon_def.id,
+        kind: summon_def.kind,
+        health_id: health.id,
+        command: match summon_def.kind {
+            SummonKind::Pet => PetCommand::Follow,
+            SummonKind::Totem => PetCommand::Stay,
+        },
+        next_attack_at: ctx.timestamp,
+        expires_at,
+    });
+    if let Some(expires_at) = expires_at {
+        ctx.db.summon_expiry_timer().insert(SummonExpiryTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Time(expires_at),
+            entity_id: entity.id,
+        });
+    }
+
+    Ok(())
+}
+
+/// Tells the sender's pet what to do.
+#[reducer]
+pub fn command_pet(ctx: &ReducerContext, command: PetCommand) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("command_pet: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(mut pet) = ctx
+        .db
+        .owned_entity()
+        .owner_entity_id()
+        .filter(pawn.entity_id)
+        .find(|owned| owned.kind == SummonKind::Pet)
+    else {
+        let err = format!("command_pet: you have no pet.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if let PetCommand::Attack(target_entity_id) = command {
+        let Some(target) = ctx.db.npc().entity_id().find(target_entity_id) else {
+            let err = format!("command_pet: target is not an NPC.");
+            log::warn!("{err}");
+            return Err(err);
+        };
+        if !npc::is_hostile(ctx, &target) {
+            let err = format!("command_pet: target cannot be attacked.");
+            log::warn!("{err}");
+            return Err(err);
+        }
+        if !entities_in_range(
+            ctx,
+            pawn.entity_id,
+            target.entity_id,
+            PET_COMMAND_RANGE_SQUARED,
+        ) {
+            let err = format!("command_pet: target is too far away.");
+            log::warn!("{err}");
+            return Err(err);
+        }
+        if !collision::entities_in_sight(ctx, pawn.entity_id, target.entity_id) {
+            let err = format!("command_pet: target is not in line of sight.");
+            log::warn!("{err}");
+            return Err(err);
+        }
+    }
+
+    // Whatever the pet was doing stops, the next tick starts on the new command
+    ctx.db.entity_movement().entity_id().delete(pet.entity_id);
+    pet.command = command;
+    ctx.db.owned_entity().entity_id().update(pet);
+
+    Ok(())
+}
+
+/// Dismisses one of the sender's summons.
+#[reducer]
+pub fn dismiss_summon(ctx: &ReducerContext, entity_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("dismiss_summon: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    if owner_of(ctx, entity_id) != Some(pawn.entity_id) {
+        let err = format!("dismiss_summon: not your summon.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    despawn(ctx, entity_id);
+
+    Ok(())
+}
+
+/// Moves a pet according to its command, attacking when its target is in reach.
+fn tick_pet(
+    ctx: &ReducerContext,
+    mut pet: OwnedEntity,
+    summon_def: &SummonDef,
+    (owner_map_id, owner): (u32, Vec3),
+) {
+    let Some((map_id, translation)) = entity_location(ctx, pet.entity_id) else {
+        return;
+    };
+    if map_id != owner_map_id || translation.distance_squared_xz(owner) > PET_LEASH_RANGE_SQUARED {
+        let _ = movement::transfer(ctx, pet.entity_id, owner_map_id, owner);
+        if matches!(pet.command, PetCommand::Attack(_)) {
+            pet.command = PetCommand::Follow;
+            ctx.db.owned_entity().entity_id().update(pet);
+        }
+        return;
+    }
+
+    match pet.command {
+        PetCommand::Follow => {
+            if translation.distance_squared_xz(owner) > summon_def.follow_radius.powi(2) {
+                movement::set_intent(ctx, pet.entity_id, MoveIntent::Entity(pet.owner_entity_id));
+            } else {
+                // Close enough, don't walk all the way into the owner
+                ctx.db.entity_movement().entity_id().delete(pet.entity_id);
+            }
+        }
+        PetCommand::Stay => {}
+        PetCommand::Attack(target_entity_id) => {
+            let target = ctx
+                .db
+                .npc()
+                .entity_id()
+                .find(target_entity_id)
+                .filter(|npc| !npc.evading && npc::is_hostile(ctx, npc))
+                .and_then(|npc| entity_location(ctx, npc.entity_id))
+                .filter(|(target_map_id, _)| *target_map_id == map_id)
+                .map(|(_, target)| target);
+            let Some(target) = target else {
+                // The target died or got away
+                pet.command = PetCommand::Follow;
+                ctx.db.owned_entity().entity_id().update(pet);
+                return;
+            };
+
+            if translation.distance_squared_xz(target) > PET_ATTACK_RANGE_SQUARED {
+                movement::set_intent(ctx, pet.entity_id, MoveIntent::Entity(target_entity_id));
+                return;
+            }
+            ctx.db.entity_movement().entity_id().delete(pet.entity_id);
+            // Pets turn to face a target that got around them before attacking it
+            if !combat::facing(ctx, pet.entity_id, target_entity_id, PET_ATTACK_HALF_ANGLE) {
+                if let Err(err) = movement::face(ctx, pet.entity_id, target) {
+                    log::warn!("Unable to turn pet {}: {err}", pet.entity_id);
+                }
+                return;
+            }
+            if pet.next_attack_at <= ctx.timestamp {
+                pet.next_attack_at =
+                    ctx.timestamp + TimeDuration::from_micros(PET_ATTACK_MICRO_SECS);
+                let (entity_id, damage) = (pet.entity_id, summon_def.attack_damage);
+                ctx.db.owned_entity().entity_id().update(pet);
+                combat::apply_damage(ctx, target_entity_id, Some(entity_id), damage);
+            }
+        }
+    }
+}
+
+/// Keeps a totem's aura on every member of its owner's party standing close enough.
+fn tick_totem(ctx: &ReducerContext, totem: &OwnedEntity, summon_def: &SummonDef) {
+    let Some(effect_def_id) = summon_def.aura_effect_def_id else {
+        return;
+    };
+    let Some((map_id, translation)) = entity_location(ctx, totem.entity_id) else {
+        return;
+    };
+    let Some(owner_character_id) = combat::entity_character_id(ctx, totem.owner_entity_id) else {
+        return;
+    };
+
+    let party = party::members_in_range(ctx, owner_character_id, f32::MAX);
+    let area = Area::Circle {
+        center: translation.xz(),
+        radius: summon_def.aura_radius,
+    };
+    for entity_id in area::entities_in_area(ctx, map_id, &area) {
+        let in_party = ctx
+            .db
+            .character_pawn()
+            .entity_id()
+            .filter(entity_id)
+            .next()
+            .is_some_and(|pawn| party.contains(&pawn.character_id));
+        if !in_party {
+            continue;
+        }
+        // Reapplied once it runs out rather than stacked up every tick
+        let has_aura = ctx
+            .db
+            .active_effect()
+            .entity_id()
+            .filter(entity_id)
+            .any(|effect| {
+                effect.effect_def_id == effect_def_id
+                    && effect.source_entity_id == Some(totem.entity_id)
+            });
+        if !has_aura {
+            let _ = effect::apply_effect(ctx, entity_id, effect_def_id, Some(totem.entity_id));
+        }
+    }
+}
+
+#[reducer]
+fn summon_tick(ctx: &ReducerContext, _timer: SummonTickTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`summon_tick` may not be invoked by clients.".into());
+    }
+
+    let summons: Vec<OwnedEntity> = ctx.db.owned_entity().iter().collect();
+    for owned in summons {
+        let Some(owner) = entity_location(ctx, owned.owner_entity_id) else {
+            // The owner is gone
+            despawn(ctx, owned.entity_id);
+            continue;
+        };
+        let Some(summon_def) = ctx.db.summon_def().id().find(owned.summon_def_id) else {
+            despawn(ctx, owned.entity_id);
+            continue;
+        };
+
+        match owned.kind {
+            SummonKind::Pet => tick_pet(ctx, owned, &summon_def, owner),
+            SummonKind::Totem => tick_totem(ctx, &owned, &summon_def),
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes a totem whose lifetime ran out.
+#[reducer]
+fn summon_expire(ctx: &ReducerContext, timer: SummonExpiryTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`summon_expire` may not be invoked by clients.".into());
+    }
+
+    despawn(ctx, timer.entity_id);
+
+    Ok(())
+}
@@ -0,0 +1,308 @@
+//! How NPCs pick who to fight.
+//!
+//! Every NPC in combat keeps a threat table of the entities it is fighting. Damage
+//! dealt to the NPC, healing done to its enemies and taunts all raise threat, and the
+//! NPC chases whoever has the most. An NPC pulled too far from its spawn point, or left
+//! without anyone to fight, evades: it forgets its threat table, walks back to its spawn
+//! point ignoring all damage, and resets to full health once it gets there.
+
+use crate::{
+    character::character_pawn,
+    collision, combat, effect, entities_in_range, entity_location, entity_translation, health,
+    mount,
+    movement::{self, MoveIntent},
+    npc::{self, Npc, npc, npc_spawn},
+    stealth,
+    types::Vec3,
+};
+use spacetimedb::{ReducerContext, ScheduleAt, Table, TimeDuration, reducer, table};
+
+/// How often NPCs reconsider their target.
+const TICK_MICRO_SECS: i64 = 500_000;
+/// How far an NPC follows its target away from its spawn point before giving up.
+const LEASH_RANGE_SQUARED: f32 = 40.0 * 40.0;
+/// How close to its spawn point an evading NPC needs to get to reset.
+const RESET_RANGE_SQUARED: f32 = 1.0;
+/// How far away a character can taunt an NPC from.
+const TAUNT_RANGE_SQUARED: f32 = 30.0 * 30.0;
+/// Healing an NPC's enemy generates this share of the amount healed as threat.
+const HEAL_THREAT_PERCENT: u32 = 50;
+/// A taunt puts the taunter this far ahead of the current top threat.
+const TAUNT_THREAT_PERCENT: u32 = 110;
+
+/// How much an NPC wants to fight a single entity.
+#[table(name = npc_threat, public)]
+pub struct NpcThreat {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub npc_entity_id: u32,
+
+    #[index(btree)]
+    pub target_entity_id: u32,
+
+    pub threat: u32,
+}
+
+#[table(name = threat_tick_timer, scheduled(threat_tick))]
+struct ThreatTickTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+pub fn init(ctx: &ReducerContext) {
+    ctx.db.threat_tick_timer().scheduled_id().delete(1);
+    ctx.db.threat_tick_timer().insert(ThreatTickTimer {
+        scheduled_id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(TICK_MICRO_SECS)),
+    });
+}
+
+fn spawn_translation(ctx: &ReducerContext, npc: &Npc) -> Option<Vec3> {
+    ctx.db
+        .npc_spawn()
+        .id()
+        .find(npc.spawn_id)
+        .map(|spawn| spawn.translation)
+}
+
+/// Adds threat towards `target_entity_id` on an NPC. Evading NPCs take no new enemies.
+/// Targets entering combat with an NPC are knocked off their mount.
+pub(crate) fn add_threat(
+    ctx: &ReducerContext,
+    npc_entity_id: u32,
+    target_entity_id: u32,
+    amount: u32,
+) {
+    if !ctx
+        .db
+        .npc()
+        .entity_id()
+        .find(npc_entity_id)
+        .is_some_and(|npc| !npc.evading)
+    {
+        return;
+    }
+
+    let existing = ctx
+        .db
+        .npc_threat()
+        .npc_entity_id()
+        .filter(npc_entity_id)
+        .find(|row| row.target_entity_id == target_entity_id);
+    match existing {
+        Some(mut row) => {
+            row.threat = row.threat.saturating_add(amount);
+            ctx.db.npc_threat().id().update(row);
+        }
+        None => {
+            mount::dismount_entity(ctx, target_entity_id);
+            ctx.db.npc_threat().insert(NpcThreat {
+                id: 0,
+                npc_entity_id,
+                target_entity_id,
+                threat: amount,
+            });
+        }
+    }
+}
+
+/// Healing an entity draws the attention of every NPC fighting it to the healer.
+pub(crate) fn add_heal_threat(
+    ctx: &ReducerContext,
+    healer_entity_id: u32,
+    target_entity_id: u32,
+    amount: u16,
+) {
+    let threat = amount as u32 * HEAL_THREAT_PERCENT / 100;
+    let npc_entity_ids: Vec<u32> = ctx
+        .db
+        .npc_threat()
+        .target_entity_id()
+        .filter(target_entity_id)
+        .map(|row| row.npc_entity_id)
+        .collect();
+    for npc_entity_id in npc_entity_ids {
+        add_threat(ctx, npc_entity_id, healer_entity_id, threat);
+    }
+}
+
+/// Clears an NPC's threat table, e.g. when it dies.
+pub(crate) fn clear_npc(ctx: &ReducerContext, npc_entity_id: u32) {
+    ctx.db.npc_threat().npc_entity_id().delete(npc_entity_id);
+}
+
+/// Removes an entity from every threat table, e.g. when it leaves the world.
+pub(crate) fn forget_target(ctx: &ReducerContext, target_entity_id: u32) {
+    ctx.db
+        .npc_threat()
+        .target_entity_id()
+        .delete(target_entity_id);
+}
+
+/// Makes an NPC give up its fight and walk back to its spawn point.
+fn evade(ctx: &ReducerContext, mut npc: Npc) {
+    clear_npc(ctx, npc.entity_id);
+    effect::clear(ctx, npc.entity_id);
+    if let Some(spawn) = spawn_translation(ctx, &npc) {
+        movement::set_intent(ctx, npc.entity_id, MoveIntent::Path(vec![spawn]));
+    }
+    npc.evading = true;
+    ctx.db.npc().entity_id().update(npc);
+}
+
+/// Forces a nearby NPC to attack the sender's character.
+#[reducer]
+pub fn taunt(ctx: &ReducerContext, npc_entity_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("taunt: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(npc) = ctx.db.npc().entity_id().find(npc_entity_id) else {
+        let err = format!("taunt: target is not an NPC.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    if !npc::is_hostile(ctx, &npc) {
+        let err = format!("taunt: NPC cannot be attacked.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if !entities_in_range(ctx, pawn.entity_id, npc.entity_id, TAUNT_RANGE_SQUARED) {
+        let err = format!("taunt: NPC is too far away.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if !collision::entities_in_sight(ctx, pawn.entity_id, npc.entity_id) {
+        let err = format!("taunt: NPC is not in line of sight.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if npc.evading {
+        let err = format!("taunt: NPC is evading.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    stealth::break_stealth(ctx, pawn.entity_id);
+
+    let top = ctx
+        .db
+        .npc_threat()
+        .npc_entity_id()
+        .filter(npc.entity_id)
+        .filter(|row| row.target_entity_id != pawn.entity_id)
+        .map(|row| row.threat)
+        .max()
+        .unwrap_or(0);
+    let own = ctx
+        .db
+        .npc_threat()
+        .npc_entity_id()
+        .filter(npc.entity_id)
+        .find(|row| row.target_entity_id == pawn.entity_id)
+        .map_or(0, |row| row.threat);
+    let wanted = (top as u64 * TAUNT_THREAT_PERCENT as u64 / 100).max(1) as u32;
+    add_threat(
+        ctx,
+        npc.entity_id,
+        pawn.entity_id,
+        wanted.saturating_sub(own),
+    );
+
+    Ok(())
+}
+
+/// Points every NPC in combat at its top threat, and makes NPCs that have been pulled
+/// too far or have nobody left to fight evade.
+#[reducer]
+fn threat_tick(ctx: &ReducerContext, _timer: ThreatTickTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`threat_tick` may not be invoked by clients.".into());
+    }
+
+    // Evading NPCs reset once they make it home
+    let evading: Vec<Npc> = ctx.db.npc().iter().filter(|npc| npc.evading).collect();
+    for mut npc in evading {
+        let home = match (
+            entity_translation(ctx, npc.entity_id),
+            spawn_translation(ctx, &npc),
+        ) {
+            (Some(a), Some(b)) => a.distance_squared_xz(b) <= RESET_RANGE_SQUARED,
+            _ => true,
+        };
+        if !home {
+            continue;
+        }
+        if let Some(mut health) = ctx.db.health().id().find(npc.health_id) {
+            health.update(health.max_health);
+            ctx.db.health().id().update(health);
+        }
+        npc.evading = false;
+        ctx.db.npc().entity_id().update(npc);
+    }
+
+    let mut npc_entity_ids: Vec<u32> = ctx
+        .db
+        .npc_threat()
+        .iter()
+        .map(|row| row.npc_entity_id)
+        .collect();
+    npc_entity_ids.sort();
+    npc_entity_ids.dedup();
+
+    for npc_entity_id in npc_entity_ids {
+        let Some(npc) = ctx.db.npc().entity_id().find(npc_entity_id) else {
+            clear_npc(ctx, npc_entity_id);
+            continue;
+        };
+        let Some((map_id, translation)) = entity_location(ctx, npc.entity_id) else {
+            continue;
+        };
+
+        let leashed = spawn_translation(ctx, &npc)
+            .is_some_and(|spawn| translation.distance_squared_xz(spawn) > LEASH_RANGE_SQUARED);
+        if leashed {
+            evade(ctx, npc);
+            continue;
+        }
+
+        // Forget enemies that died, left for another map or can no longer be found
+        let rows: Vec<NpcThreat> = ctx
+            .db
+            .npc_threat()
+            .npc_entity_id()
+            .filter(npc.entity_id)
+            .collect();
+        let mut top: Option<NpcThreat> = None;
+        for row in rows {
+            let alive = combat::entity_health_id(ctx, row.target_entity_id)
+                .and_then(|id| ctx.db.health().id().find(id))
+                .is_some_and(|health| health.health > 0);
+            let same_map = entity_location(ctx, row.target_entity_id)
+                .is_some_and(|(target_map_id, _)| target_map_id == map_id);
+            if !alive || !same_map {
+                ctx.db.npc_threat().id().delete(row.id);
+                continue;
+            }
+            if top.as_ref().is_none_or(|top| row.threat > top.threat) {
+                top = Some(row);
+            }
+        }
+
+        match top {
+            Some(top) => {
+                movement::set_intent(ctx, npc.entity_id, MoveIntent::Entity(top.target_entity_id));
+            }
+            None => evade(ctx, npc),
+        }
+    }
+
+    Ok(())
+}
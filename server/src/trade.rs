@@ -0,0 +1,497 @@
+//! Player-to-player trading.
+//!
+//! A trade goes through two confirmation phases: both players first lock their offers,
+//! then both accept. Changing an offer unlocks both sides again, so nobody can accept
+//! something other than what they last looked at. The swap itself happens inside the
+//! final `accept_trade` call, so it either commits entirely or not at all.
+
+use crate::{
+    character::character_pawn,
+    currency::{self, CurrencyReason},
+    entity_location,
+    inventory::{self, inventory_item},
+    social,
+};
+use spacetimedb::{
+    Filter, Identity, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration,
+    client_visibility_filter, reducer, table,
+};
+
+/// How close two characters need to be to trade with one another.
+const TRADE_RANGE_SQUARED: f32 = 10.0 * 10.0;
+/// How often open trades are checked for characters that have walked away from each other.
+const RANGE_CHECK_MICRO_SECS: i64 = 1_000_000;
+
+#[client_visibility_filter]
+const TRADE_SESSION_INITIATOR: Filter =
+    Filter::Sql("SELECT * FROM trade_session WHERE initiator = :sender");
+
+#[client_visibility_filter]
+const TRADE_SESSION_TARGET: Filter =
+    Filter::Sql("SELECT * FROM trade_session WHERE target = :sender");
+
+#[client_visibility_filter]
+const TRADE_PARTICIPANT_INITIATOR: Filter = Filter::Sql(
+    "SELECT trade_participant.* FROM trade_participant JOIN trade_session ON trade_participant.session_id = trade_session.id WHERE trade_session.initiator = :sender",
+);
+
+#[client_visibility_filter]
+const TRADE_PARTICIPANT_TARGET: Filter = Filter::Sql(
+    "SELECT trade_participant.* FROM trade_participant JOIN trade_session ON trade_participant.session_id = trade_session.id WHERE trade_session.target = :sender",
+);
+
+#[client_visibility_filter]
+const TRADE_OFFER_INITIATOR: Filter = Filter::Sql(
+    "SELECT trade_offer.* FROM trade_offer JOIN trade_session ON trade_offer.session_id = trade_session.id WHERE trade_session.initiator = :sender",
+);
+
+#[client_visibility_filter]
+const TRADE_OFFER_TARGET: Filter = Filter::Sql(
+    "SELECT trade_offer.* FROM trade_offer JOIN trade_session ON trade_offer.session_id = trade_session.id WHERE trade_session.target = :sender",
+);
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum TradeState {
+    /// Offers can still be changed.
+    Open,
+    /// The participant is happy with both offers as they are.
+    Locked,
+    /// The participant has confirmed the swap.
+    Accepted,
+}
+
+/// A trade between two in-game characters.
+#[table(name = trade_session, public)]
+pub struct TradeSession {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    /// The player who opened the trade
+    #[index(btree)]
+    pub initiator: Identity,
+
+    /// The player the trade was opened with
+    #[index(btree)]
+    pub target: Identity,
+}
+
+/// One side of a trade.
+/// Keyed by identity so that a player can only take part in a single trade at a time.
+#[table(name = trade_participant, public)]
+pub struct TradeParticipant {
+    #[primary_key]
+    pub identity: Identity,
+
+    #[index(btree)]
+    pub session_id: u32,
+
+    /// The character whose inventory and currency are being traded
+    pub character_id: u32,
+
+    /// The in-game entity of the character, used for range checks
+    pub entity_id: u32,
+
+    /// The amount of currency offered
+    pub currency: u64,
+
+    pub state: TradeState,
+}
+
+/// An inventory stack (or part of one) offered in a trade.
+#[table(name = trade_offer, public)]
+pub struct TradeOffer {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub session_id: u32,
+
+    /// The player offering the item
+    #[index(btree)]
+    pub identity: Identity,
+
+    /// A stack can only be offered once per trade
+    #[unique]
+    pub inventory_item_id: u32,
+
+    /// Copied from the inventory item so the other side can see what is offered
+    pub item_def_id: u32,
+
+    pub quantity: u16,
+}
+
+#[table(name = trade_range_timer, scheduled(trade_range_check))]
+struct TradeRangeTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+pub fn init(ctx: &ReducerContext) {
+    ctx.db.trade_range_timer().scheduled_id().delete(1);
+    ctx.db.trade_range_timer().insert(TradeRangeTimer {
+        scheduled_id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(RANGE_CHECK_MICRO_SECS)),
+    });
+}
+
+/// Cancels any trade `identity` is taking part in. Nothing is exchanged.
+pub(crate) fn cancel_for(ctx: &ReducerContext, identity: Identity) {
+    let Some(participant) = ctx.db.trade_participant().identity().find(identity) else {
+        return;
+    };
+    delete_session(ctx, participant.session_id);
+}
+
+fn delete_session(ctx: &ReducerContext, session_id: u32) {
+    ctx.db.trade_offer().session_id().delete(session_id);
+    ctx.db.trade_participant().session_id().delete(session_id);
+    ctx.db.trade_session().id().delete(session_id);
+}
+
+/// Finds the trade the sender is part of, returning both sides with the sender first.
+fn sender_trade(
+    ctx: &ReducerContext,
+    reducer: &str,
+) -> Result<(TradeParticipant, TradeParticipant), String> {
+    let Some(own) = ctx.db.trade_participant().identity().find(ctx.sender) else {
+        let err = format!("{reducer}: sender is not trading.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(other) = ctx
+        .db
+        .trade_participant()
+        .session_id()
+        .filter(own.session_id)
+        .find(|participant| participant.identity != ctx.sender)
+    else {
+        let err = format!("{reducer}: trade partner not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    Ok((own, other))
+}
+
+/// Returns true if both entities exist, are on the same map and are within trading range
+/// of each other.
+fn in_range(ctx: &ReducerContext, a_entity_id: u32, b_entity_id: u32) -> bool {
+    let (Some((a_map_id, a)), Some((b_map_id, b))) = (
+        entity_location(ctx, a_entity_id),
+        entity_location(ctx, b_entity_id),
+    ) else {
+        return false;
+    };
+    a_map_id == b_map_id && a.distance_squared_xz(b) < TRADE_RANGE_SQUARED
+}
+
+/// Any change to an offer puts both sides back to `Open`.
+fn reset_states(ctx: &ReducerContext, session_id: u32) {
+    for mut participant in ctx.db.trade_participant().session_id().filter(session_id) {
+        if participant.state != TradeState::Open {
+            participant.state = TradeState::Open;
+            ctx.db.trade_participant().identity().update(participant);
+        }
+    }
+}
+
+#[reducer]
+pub fn request_trade(ctx: &ReducerContext, target_entity_id: u32) -> Result<(), String> {
+    let Some(own_pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("request_trade: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    let Some(target_pawn) = ctx
+        .db
+        .character_pawn()
+        .entity_id()
+        .filter(target_entity_id)
+        .next()
+    else {
+        let err = format!("request_trade: target is not a player character.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if target_pawn.identity == ctx.sender {
+        let err = format!("request_trade: cannot trade with yourself.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if social::is_ignoring(ctx, target_pawn.identity, ctx.sender) {
+        let err = format!("request_trade: target is ignoring you.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if ctx
+        .db
+        .trade_participant()
+        .identity()
+        .find(ctx.sender)
+        .is_some()
+    {
+        let err = format!("request_trade: sender is already trading.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if ctx
+        .db
+        .trade_participant()
+        .identity()
+        .find(target_pawn.identity)
+        .is_some()
+    {
+        let err = format!("request_trade: target is already trading.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if !in_range(ctx, own_pawn.entity_id, target_pawn.entity_id) {
+        let err = format!("request_trade: target is too far away.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    let session = ctx.db.trade_session().insert(TradeSession {
+        id: 0,
+        initiator: ctx.sender,
+        target: target_pawn.identity,
+    });
+    for pawn in [own_pawn, target_pawn] {
+        ctx.db.trade_participant().insert(TradeParticipant {
+            identity: pawn.identity,
+            session_id: session.id,
+            character_id: pawn.character_id,
+            entity_id: pawn.entity_id,
+            currency: 0,
+            state: TradeState::Open,
+        });
+    }
+
+    Ok(())
+}
+
+/// Offers `quantity` items from one of the sender's inventory stacks.
+/// Offering the same stack again replaces the previous quantity.
+#[reducer]
+pub fn offer_trade_item(
+    ctx: &ReducerContext,
+    inventory_item_id: u32,
+    quantity: u16,
+) -> Result<(), String> {
+    let (own, _) = sender_trade(ctx, "offer_trade_item")?;
+
+    let Some(item) = ctx.db.inventory_item().id().find(inventory_item_id) else {
+        let err = format!("offer_trade_item: item not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if item.character_id != own.character_id {
+        let err = format!("offer_trade_item: item is not owned by the trading character.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if quantity == 0 || quantity > item.quantity {
+        let err = format!("offer_trade_item: invalid quantity.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    match ctx
+        .db
+        .trade_offer()
+        .inventory_item_id()
+        .find(inventory_item_id)
+    {
+        Some(mut offer) => {
+            offer.quantity = quantity;
+            ctx.db.trade_offer().id().update(offer);
+        }
+        None => {
+            ctx.db.trade_offer().insert(TradeOffer {
+                id: 0,
+                session_id: own.session_id,
+                identity: ctx.sender,
+                inventory_item_id,
+                item_def_id: item.item_def_id,
+                quantity,
+            });
+        }
+    }
+    reset_states(ctx, own.session_id);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn retract_trade_item(ctx: &ReducerContext, inventory_item_id: u32) -> Result<(), String> {
+    let (own, _) = sender_trade(ctx, "retract_trade_item")?;
+
+    let Some(offer) = ctx
+        .db
+        .trade_offer()
+        .inventory_item_id()
+        .find(inventory_item_id)
+        .filter(|offer| offer.identity == ctx.sender)
+    else {
+        let err = format!("retract_trade_item: item is not offered.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    ctx.db.trade_offer().delete(offer);
+    reset_states(ctx, own.session_id);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn offer_trade_currency(ctx: &ReducerContext, amount: u64) -> Result<(), String> {
+    let (mut own, _) = sender_trade(ctx, "offer_trade_currency")?;
+
+    if currency::balance(ctx, own.character_id) < amount {
+        let err = format!("offer_trade_currency: not enough currency.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    let session_id = own.session_id;
+    own.currency = amount;
+    ctx.db.trade_participant().identity().update(own);
+    reset_states(ctx, session_id);
+
+    Ok(())
+}
+
+/// First confirmation phase: the sender is happy with both offers as they are.
+#[reducer]
+pub fn lock_trade(ctx: &ReducerContext) -> Result<(), String> {
+    let (mut own, _) = sender_trade(ctx, "lock_trade")?;
+
+    if own.state != TradeState::Open {
+        let err = format!("lock_trade: trade is already locked.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    own.state = TradeState::Locked;
+    ctx.db.trade_participant().identity().update(own);
+
+    Ok(())
+}
+
+/// Second confirmation phase. Once both sides have accepted the swap is committed.
+#[reducer]
+pub fn accept_trade(ctx: &ReducerContext) -> Result<(), String> {
+    let (mut own, other) = sender_trade(ctx, "accept_trade")?;
+
+    if own.state == TradeState::Open || other.state == TradeState::Open {
+        let err = format!("accept_trade: both sides must lock the trade first.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if other.state != TradeState::Accepted {
+        own.state = TradeState::Accepted;
+        ctx.db.trade_participant().identity().update(own);
+        return Ok(());
+    }
+
+    commit(ctx, &own, &other)
+}
+
+#[reducer]
+pub fn cancel_trade(ctx: &ReducerContext) -> Result<(), String> {
+    let (own, _) = sender_trade(ctx, "cancel_trade")?;
+    delete_session(ctx, own.session_id);
+
+    Ok(())
+}
+
+/// Swaps both offers. Any error rolls back the whole reducer call,
+/// leaving the trade locked and both inventories untouched.
+fn commit(ctx: &ReducerContext, a: &TradeParticipant, b: &TradeParticipant) -> Result<(), String> {
+    if !in_range(ctx, a.entity_id, b.entity_id) {
+        let err = format!("accept_trade: trade partner is too far away.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    // Take everything out of both inventories before giving anything,
+    // so a full inventory can be freed up by what it is trading away.
+    let mut received: Vec<(u32, u32, u16)> = Vec::new();
+    for (giver, receiver) in [(a, b), (b, a)] {
+        currency::withdraw(
+            ctx,
+            giver.character_id,
+            giver.currency,
+            CurrencyReason::Trade(receiver.character_id),
+        )?;
+        for offer in ctx.db.trade_offer().identity().filter(giver.identity) {
+            let item_def_id = inventory::remove_item(
+                ctx,
+                giver.character_id,
+                offer.inventory_item_id,
+                offer.quantity,
+            )?;
+            received.push((receiver.character_id, item_def_id, offer.quantity));
+        }
+    }
+
+    currency::deposit(
+        ctx,
+        b.character_id,
+        a.currency,
+        CurrencyReason::Trade(a.character_id),
+    )?;
+    currency::deposit(
+        ctx,
+        a.character_id,
+        b.currency,
+        CurrencyReason::Trade(b.character_id),
+    )?;
+    for (character_id, item_def_id, quantity) in received {
+        inventory::add_item(ctx, character_id, item_def_id, quantity)?;
+    }
+
+    delete_session(ctx, a.session_id);
+
+    Ok(())
+}
+
+#[reducer]
+fn trade_range_check(ctx: &ReducerContext, _timer: TradeRangeTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`trade_range_check` may not be invoked by clients.".into());
+    }
+
+    for session in ctx.db.trade_session().iter() {
+        let entity_ids: Vec<u32> = ctx
+            .db
+            .trade_participant()
+            .session_id()
+            .filter(session.id)
+            .map(|participant| participant.entity_id)
+            .collect();
+
+        let still_in_range = match entity_ids[..] {
+            [a, b] => in_range(ctx, a, b),
+            _ => false,
+        };
+        if !still_in_range {
+            delete_session(ctx, session.id);
+        }
+    }
+
+    Ok(())
+}
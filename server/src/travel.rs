@@ -0,0 +1,408 @@
+//! Fast travel: waypoints, hearths and the teleport that takes characters to them.
+//!
+//! Waypoints are discovered by walking close to them. A character can teleport to any
+//! waypoint they have discovered, or to their hearth, which they bind at a waypoint.
+//! Teleporting takes a cast, interrupted by moving or taking damage, and shares a
+//! cooldown between all destinations.
+
+use crate::{
+    character::{character_def, character_pawn},
+    entity_location,
+    map::{self, OVERWORLD_MAP_ID, map},
+    movement::entity_movement,
+    threat::npc_threat,
+    types::Vec3,
+};
+use spacetimedb::{
+    Filter, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp,
+    client_visibility_filter, reducer, table,
+};
+
+/// How often characters are checked for waypoints they've walked up to.
+const DISCOVERY_MICRO_SECS: i64 = 1_000_000;
+/// How close a character needs to be to a waypoint to bind their hearth there.
+const BIND_RANGE_SQUARED: f32 = 5.0 * 5.0;
+const TELEPORT_CAST_SECS: i64 = 10;
+const TELEPORT_COOLDOWN_SECS: i64 = 5 * 60;
+
+#[client_visibility_filter]
+const WAYPOINT_DISCOVERED_SECURITY: Filter = Filter::Sql(
+    "SELECT waypoint_discovered.* FROM waypoint_discovered JOIN character_def ON waypoint_discovered.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+#[client_visibility_filter]
+const HEARTH_SECURITY: Filter = Filter::Sql(
+    "SELECT hearth.* FROM hearth JOIN character_def ON hearth.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+#[client_visibility_filter]
+const TELEPORT_CAST_SECURITY: Filter = Filter::Sql(
+    "SELECT teleport_cast.* FROM teleport_cast JOIN character_def ON teleport_cast.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+#[client_visibility_filter]
+const TELEPORT_COOLDOWN_SECURITY: Filter = Filter::Sql(
+    "SELECT teleport_cooldown.* FROM teleport_cooldown JOIN character_def ON teleport_cooldown.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+/// A place characters can teleport to once they've been there.
+#[table(name = waypoint, public)]
+pub struct Waypoint {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub map_id: u32,
+    pub translation: Vec3,
+
+    /// How close a character needs to come to discover the waypoint
+    pub discover_radius: f32,
+}
+
+/// A waypoint a character has discovered.
+#[table(name = waypoint_discovered, public)]
+pub struct WaypointDiscovered {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub character_id: u32,
+
+    pub waypoint_id: u32,
+}
+
+/// Where a character's hearth is bound. Characters without one return to the arrival
+/// point of the overworld.
+#[table(name = hearth, public)]
+pub struct Hearth {
+    #[primary_key]
+    pub character_id: u32,
+
+    pub map_id: u32,
+    pub translation: Vec3,
+}
+
+#[derive(SpacetimeType, Clone, Copy, PartialEq)]
+pub enum TeleportDestination {
+    Waypoint(u32),
+    Hearth,
+}
+
+/// A character's teleport in progress.
+#[table(name = teleport_cast, public)]
+pub struct TeleportCast {
+    #[primary_key]
+    pub character_id: u32,
+
+    /// The character's entity, so damage and movement can interrupt the cast
+    #[unique]
+    pub entity_id: u32,
+
+    pub destination: TeleportDestination,
+    pub started_at: Timestamp,
+    pub completes_at: Timestamp,
+}
+
+/// When a character can teleport again.
+#[table(name = teleport_cooldown, public)]
+pub struct TeleportCooldown {
+    #[primary_key]
+    pub character_id: u32,
+
+    pub ready_at: Timestamp,
+}
+
+#[table(name = teleport_timer, scheduled(complete_teleport))]
+struct TeleportTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+
+    #[index(btree)]
+    character_id: u32,
+}
+
+#[table(name = waypoint_discovery_timer, scheduled(waypoint_discovery))]
+struct WaypointDiscoveryTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+pub fn init(ctx: &ReducerContext) {
+    ctx.db.waypoint_discovery_timer().scheduled_id().delete(1);
+    ctx.db
+        .waypoint_discovery_timer()
+        .insert(WaypointDiscoveryTimer {
+            scheduled_id: 1,
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(DISCOVERY_MICRO_SECS)),
+        });
+}
+
+fn discovered(ctx: &ReducerContext, character_id: u32, waypoint_id: u32) -> bool {
+    ctx.db
+        .waypoint_discovered()
+        .character_id()
+        .filter(character_id)
+        .any(|discovered| discovered.waypoint_id == waypoint_id)
+}
+
+/// Where a teleport to `destination` puts a character.
+fn resolve(
+    ctx: &ReducerContext,
+    character_id: u32,
+    destination: TeleportDestination,
+) -> Result<(u32, Vec3), String> {
+    match destination {
+        TeleportDestination::Waypoint(waypoint_id) => {
+            let Some(waypoint) = ctx.db.waypoint().id().find(waypoint_id) else {
+                return Err(format!("waypoint {waypoint_id} not found."));
+            };
+            if !discovered(ctx, character_id, waypoint.id) {
+                return Err(format!("{} hasn't been discovered.", waypoint.name));
+            }
+            Ok((waypoint.map_id, waypoint.translation))
+        }
+        TeleportDestination::Hearth => match ctx.db.hearth().character_id().find(character_id) {
+            Some(hearth) => Ok((hearth.map_id, hearth.translation)),
+            None => ctx
+                .db
+                .map()
+                .id()
+                .find(OVERWORLD_MAP_ID)
+                .map(|map| (map.id, map.arrival))
+                .ok_or_else(|| "overworld map not found.".to_string()),
+        },
+    }
+}
+
+/// Stops a character's teleport in progress, if any.
+pub(crate) fn cancel_teleport_for(ctx: &ReducerContext, character_id: u32) {
+    ctx.db.teleport_cast().character_id().delete(character_id);
+    ctx.db.teleport_timer().character_id().delete(character_id);
+}
+
+/// Interrupts the teleport an entity is casting, if any.
+pub(crate) fn interrupt(ctx: &ReducerContext, entity_id: u32) {
+    if let Some(cast) = ctx.db.teleport_cast().entity_id().find(entity_id) {
+        cancel_teleport_for(ctx, cast.character_id);
+    }
+}
+
+/// Starts teleporting the sender's character to a discovered waypoint or their hearth.
+#[reducer]
+pub fn teleport(ctx: &ReducerContext, destination: TeleportDestination) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("teleport: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if ctx
+        .db
+        .teleport_cast()
+        .character_id()
+        .find(pawn.character_id)
+        .is_some()
+    {
+        let err = format!("teleport: already teleporting.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if ctx
+        .db
+        .teleport_cooldown()
+        .character_id()
+        .find(pawn.character_id)
+        .is_some_and(|cooldown| cooldown.ready_at > ctx.timestamp)
+    {
+        let err = format!("teleport: not ready yet.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if ctx
+        .db
+        .npc_threat()
+        .target_entity_id()
+        .filter(pawn.entity_id)
+        .next()
+        .is_some()
+    {
+        let err = format!("teleport: cannot teleport in combat.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    resolve(ctx, pawn.character_id, destination).map_err(|err| format!("teleport: {err}"))?;
+
+    // Teleporting takes standing still
+    ctx.db.entity_movement().entity_id().delete(pawn.entity_id);
+
+    let completes_at = ctx.timestamp + TimeDuration::from_micros(TELEPORT_CAST_SECS * 1_000_000);
+    ctx.db.teleport_cast().insert(TeleportCast {
+        character_id: pawn.character_id,
+        entity_id: pawn.entity_id,
+        destination,
+        started_at: ctx.timestamp,
+        completes_at,
+    });
+    ctx.db.teleport_timer().insert(TeleportTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(completes_at),
+        character_id: pawn.character_id,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn cancel_teleport(ctx: &ReducerContext) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("cancel_teleport: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    cancel_teleport_for(ctx, pawn.character_id);
+
+    Ok(())
+}
+
+/// Binds the sender's hearth at a nearby waypoint.
+#[reducer]
+pub fn set_hearth(ctx: &ReducerContext, waypoint_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("set_hearth: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(waypoint) = ctx.db.waypoint().id().find(waypoint_id) else {
+        let err = format!("set_hearth: waypoint {waypoint_id} not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let in_range = entity_location(ctx, pawn.entity_id).is_some_and(|(map_id, translation)| {
+        map_id == waypoint.map_id
+            && translation.distance_squared_xz(waypoint.translation) <= BIND_RANGE_SQUARED
+    });
+    if !in_range {
+        let err = format!("set_hearth: waypoint is too far away.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    let hearth = Hearth {
+        character_id: pawn.character_id,
+        map_id: waypoint.map_id,
+        translation: waypoint.translation,
+    };
+    if ctx
+        .db
+        .hearth()
+        .character_id()
+        .find(pawn.character_id)
+        .is_some()
+    {
+        ctx.db.hearth().character_id().update(hearth);
+    } else {
+        ctx.db.hearth().insert(hearth);
+    }
+
+    Ok(())
+}
+
+/// Finishes a teleport, moving the character and starting the cooldown.
+#[reducer]
+fn complete_teleport(ctx: &ReducerContext, timer: TeleportTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`complete_teleport` may not be invoked by clients.".into());
+    }
+
+    let Some(cast) = ctx
+        .db
+        .teleport_cast()
+        .character_id()
+        .find(timer.character_id)
+    else {
+        return Ok(());
+    };
+    ctx.db
+        .teleport_cast()
+        .character_id()
+        .delete(cast.character_id);
+
+    let Some(pawn) = ctx
+        .db
+        .character_def()
+        .id()
+        .find(cast.character_id)
+        .and_then(|character| ctx.db.character_pawn().identity().find(character.identity))
+        .filter(|pawn| pawn.character_id == cast.character_id)
+    else {
+        return Ok(());
+    };
+    // The destination can be gone by now, which fails the teleport rather than the timer
+    let (map_id, translation) = match resolve(ctx, cast.character_id, cast.destination) {
+        Ok(destination) => destination,
+        Err(err) => {
+            log::warn!("complete_teleport: {err}");
+            return Ok(());
+        }
+    };
+    if let Err(err) = map::transfer_entity(ctx, pawn.entity_id, map_id, translation) {
+        log::warn!("complete_teleport: {err}");
+        return Ok(());
+    }
+
+    let cooldown = TeleportCooldown {
+        character_id: cast.character_id,
+        ready_at: ctx.timestamp + TimeDuration::from_micros(TELEPORT_COOLDOWN_SECS * 1_000_000),
+    };
+    if ctx
+        .db
+        .teleport_cooldown()
+        .character_id()
+        .find(cast.character_id)
+        .is_some()
+    {
+        ctx.db.teleport_cooldown().character_id().update(cooldown);
+    } else {
+        ctx.db.teleport_cooldown().insert(cooldown);
+    }
+
+    Ok(())
+}
+
+/// Discovers the waypoints characters have walked up to.
+#[reducer]
+fn waypoint_discovery(ctx: &ReducerContext, _timer: WaypointDiscoveryTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`waypoint_discovery` may not be invoked by clients.".into());
+    }
+
+    let waypoints: Vec<Waypoint> = ctx.db.waypoint().iter().collect();
+    for pawn in ctx.db.character_pawn().iter() {
+        let Some((map_id, translation)) = entity_location(ctx, pawn.entity_id) else {
+            continue;
+        };
+        for waypoint in &waypoints {
+            if waypoint.map_id != map_id
+                || translation.distance_squared_xz(waypoint.translation)
+                    > waypoint.discover_radius * waypoint.discover_radius
+                || discovered(ctx, pawn.character_id, waypoint.id)
+            {
+                continue;
+            }
+            ctx.db.waypoint_discovered().insert(WaypointDiscovered {
+                id: 0,
+                character_id: pawn.character_id,
+                waypoint_id: waypoint.id,
+            });
+        }
+    }
+
+    Ok(())
+}
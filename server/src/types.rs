@@ -1,70 +1,4 @@
-use spacetimedb::{Identity, SpacetimeType};
+//! The math types stored in tables. They live in `common`, shared with the client and
+//! the spatial code, and are re-exported here for the rest of the module.
 
-#[derive(SpacetimeType)]
-pub struct Quat {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub w: f32,
-}
-
-impl Default for Quat {
-    fn default() -> Self {
-        Self {
-            x: 0.,
-            y: 0.,
-            z: 0.,
-            w: 0.,
-        }
-    }
-}
-
-impl Quat {
-    /// Converts `self` to `[x, y, z, w]`
-    #[inline]
-    #[must_use]
-    pub fn to_array(&self) -> [f32; 4] {
-        [self.x, self.y, self.z, self.w]
-    }
-}
-
-/// A 3-dimensional vector.
-#[derive(SpacetimeType, Clone, Copy, PartialEq)]
-pub struct Vec3 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-}
-
-impl Default for Vec3 {
-    fn default() -> Self {
-        Self::new(0., 0., 0.)
-    }
-}
-
-impl Vec3 {
-    /// Creates a new vector.
-    #[inline(always)]
-    #[must_use]
-    pub const fn new(x: f32, y: f32, z: f32) -> Self {
-        Self { x, y, z }
-    }
-
-    pub const fn to_2d_array(&self) -> [f32; 2] {
-        [self.x, self.z]
-    }
-
-    /// Converts `self` to `[x, y, z]`
-    #[inline]
-    #[must_use]
-    pub const fn to_array(&self) -> [f32; 3] {
-        [self.x, self.y, self.z]
-    }
-
-    /// Creates a new vector from an array.
-    #[inline]
-    #[must_use]
-    pub const fn from_array(a: [f32; 3]) -> Self {
-        Self::new(a[0], a[1], a[2])
-    }
-}
+pub use common::math::{Quat, Vec3};
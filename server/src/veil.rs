@@ -0,0 +1,373 @@
+//! The Veil, the sentient mist drifting across Aelynmar.
+//!
+//! The Veil is simulated as a circle that wanders on a scheduled reducer. Its extent is
+//! published as the set of chunks the circle covers so that the rest of the module,
+//! and clients rendering fog, only ever have to compare `chunk_id`s.
+//!
+//! While inside the Veil, characters slowly lose mana and are chilled, and NPCs are
+//! empowered. Veil shards, which only exist in the mist, condense inside it and fade
+//! when it moves on.
+
+use crate::{
+    Entity, Transform,
+    character::{character_def, character_pawn},
+    effect, entities_in_range, entity, inventory, mana,
+    map::OVERWORLD_MAP_ID,
+    npc::{self, npc},
+    transform,
+    types::{Quat, Vec3},
+};
+use common::chunk::{self, CHUNK_SIZE};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use spacetimedb::{
+    Filter, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp, client_visibility_filter,
+    reducer, table,
+};
+
+/// How often the Veil drifts.
+const DRIFT_MICRO_SECS: i64 = 10_000_000;
+/// How often the Veil's effects are applied to the entities inside of it.
+const EFFECT_MICRO_SECS: i64 = 2_000_000;
+
+const START_CENTER: Vec3 = Vec3::new(60., 0., 60.);
+const RADIUS: f32 = 50.0;
+/// How far the Veil moves every drift.
+const DRIFT_DISTANCE: f32 = 4.0;
+/// The largest change of heading between two drifts, in radians.
+const MAX_TURN: f32 = 0.5;
+/// The Veil never strays further than this from the origin, where the Aetherheart lies.
+const MAX_DISTANCE_FROM_ORIGIN: f32 = 400.0;
+
+/// The effect refreshed on characters for as long as they stay inside the Veil.
+const VEIL_CHILL_EFFECT_ID: u32 = 3;
+/// Mana lost by characters inside the Veil on every effect tick.
+const MANA_DRAIN: u16 = 2;
+/// Max health multiplier, in percent, of NPCs empowered by the Veil.
+pub(crate) const EMPOWERED_HEALTH_PERCENT: u32 = 150;
+
+/// Veil shards are the rare resource only found inside the Veil.
+const VEIL_SHARD_ITEM_ID: u32 = 1;
+const MAX_RESOURCES: usize = 8;
+/// Chance of a new resource condensing on each drift.
+const RESOURCE_SPAWN_CHANCE: f64 = 0.5;
+const GATHER_RANGE_SQUARED: f32 = 5.0 * 5.0;
+
+/// Only the player exposed to the Veil needs to know about it.
+#[client_visibility_filter]
+const VEIL_EXPOSURE_SECURITY: Filter = Filter::Sql(
+    "SELECT veil_exposure.* FROM veil_exposure JOIN character_pawn ON veil_exposure.entity_id = character_pawn.entity_id WHERE character_pawn.identity = :sender",
+);
+
+/// The current shape and drift of the Veil. There is only ever a single row.
+#[table(name = veil, public)]
+pub struct Veil {
+    #[primary_key]
+    pub id: u32,
+
+    pub center: Vec3,
+    pub radius: f32,
+
+    /// The direction the Veil is drifting towards, in radians around the y axis
+    pub heading: f32,
+}
+
+/// A chunk currently covered by the Veil.
+#[table(name = veil_chunk, public)]
+pub struct VeilChunk {
+    #[primary_key]
+    pub chunk_id: u32,
+}
+
+/// Marks a character entity that is inside the Veil and suffering its effects.
+#[table(name = veil_exposure, public)]
+pub struct VeilExposure {
+    #[primary_key]
+    pub entity_id: u32,
+
+    pub since: Timestamp,
+}
+
+/// A resource that condensed inside the Veil and can be gathered until it moves on.
+#[table(name = veil_resource, public)]
+pub struct VeilResource {
+    #[primary_key]
+    pub entity_id: u32,
+
+    #[index(btree)]
+    pub chunk_id: u32,
+
+    pub item_def_id: u32,
+}
+
+#[table(name = veil_drift_timer, scheduled(veil_drift))]
+struct VeilDriftTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+#[table(name = veil_effect_timer, scheduled(veil_effect_tick))]
+struct VeilEffectTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+pub fn init(ctx: &ReducerContext) {
+    if ctx.db.veil().id().find(0).is_none() {
+        let veil = ctx.db.veil().insert(Veil {
+            id: 0,
+            center: START_CENTER,
+            radius: RADIUS,
+            heading: 0.,
+        });
+        update_extent(ctx, &veil);
+    }
+
+    ctx.db.veil_drift_timer().scheduled_id().delete(1);
+    ctx.db.veil_drift_timer().insert(VeilDriftTimer {
+        scheduled_id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(DRIFT_MICRO_SECS)),
+    });
+    ctx.db.veil_effect_timer().scheduled_id().delete(1);
+    ctx.db.veil_effect_timer().insert(VeilEffectTimer {
+        scheduled_id: 1,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(EFFECT_MICRO_SECS)),
+    });
+}
+
+/// Whether the Veil currently covers the given chunk.
+pub(crate) fn contains(ctx: &ReducerContext, chunk_id: u32) -> bool {
+    ctx.db.veil_chunk().chunk_id().find(chunk_id).is_some()
+}
+
+/// The chunks whose center lies inside the Veil's circle.
+fn covered_chunks(veil: &Veil) -> Vec<u32> {
+    let reach = (veil.radius / CHUNK_SIZE).ceil() as i32 + 1;
+    let (center_x, center_z) = chunk::decode(chunk::encode(veil.center.x, veil.center.z));
+    let radius_squared = veil.radius * veil.radius;
+
+    let mut chunk_ids = Vec::new();
+    for chunk_x in center_x - reach..=center_x + reach {
+        for chunk_z in center_z - reach..=center_z + reach {
+            let x = (chunk_x as f32 + 0.5) * CHUNK_SIZE;
+            let z = (chunk_z as f32 + 0.5) * CHUNK_SIZE;
+            if veil.center.distance_squared_xz(Vec3::new(x, 0., z)) <= radius_squared {
+                chunk_ids.push(chunk::encode(x, z));
+            }
+        }
+    }
+    chunk_ids
+}
+
+/// Brings `veil_chunk` in line with the Veil's circle, only touching chunks that changed.
+fn update_extent(ctx: &ReducerContext, veil: &Veil) {
+    let covered = covered_chunks(veil);
+
+    let uncovered: Vec<u32> = ctx
+        .db
+        .veil_chunk()
+        .iter()
+        .map(|row| row.chunk_id)
+        .filter(|chunk_id| !covered.contains(chunk_id))
+        .collect();
+    for chunk_id in uncovered {
+        ctx.db.veil_chunk().chunk_id().delete(chunk_id);
+        // Resources fade away with the mist that made them
+        let faded: Vec<VeilResource> = ctx.db.veil_resource().chunk_id().filter(chunk_id).collect();
+        for resource in faded {
+            despawn_resource(ctx, resource);
+        }
+    }
+
+    for chunk_id in covered {
+        if !contains(ctx, chunk_id) {
+            ctx.db.veil_chunk().insert(VeilChunk { chunk_id });
+        }
+    }
+}
+
+fn despawn_resource(ctx: &ReducerContext, resource: VeilResource) {
+    if let Some(entity) = ctx.db.entity().id().find(resource.entity_id) {
+        ctx.db.transform().id().delete(entity.transform_id);
+        ctx.db.entity().delete(entity);
+    }
+    ctx.db.veil_resource().delete(resource);
+}
+
+/// Condenses a resource at a random spot inside the Veil.
+fn spawn_resource(ctx: &ReducerContext, veil: &Veil, rng: &mut ChaCha8Rng) {
+    let angle = rng.random_range(0.0..std::f32::consts::TAU);
+    let distance = veil.radius * rng.random_range(0.0f32..1.0).sqrt();
+    let translation = Vec3::new(
+        veil.center.x + angle.cos() * distance,
+        0.,
+        veil.center.z + angle.sin() * distance,
+    );
+    let chunk_id = chunk::encode(translation.x, translation.z);
+    if !contains(ctx, chunk_id) {
+        return;
+    }
+
+    let transform = ctx.db.transform().insert(Transform {
+        id: 0,
+        translation,
+        rotation: Quat::default(),
+        scale: Vec3::default(),
+        map_id: OVERWORLD_MAP_ID,
+        chunk_id,
+        stealthed: false,
+    });
+    let entity = ctx.db.entity().insert(Entity {
+        id: 0,
+        transform_id: transform.id,
+    });
+    ctx.db.veil_resource().insert(VeilResource {
+        entity_id: entity.id,
+        chunk_id,
+        item_def_id: VEIL_SHARD_ITEM_ID,
+    });
+}
+
+/// Returns the chunk of a spawned entity, if it is in the overworld the Veil drifts over.
+fn entity_chunk(ctx: &ReducerContext, entity_id: u32) -> Option<u32> {
+    let entity = ctx.db.entity().id().find(entity_id)?;
+    let transform = ctx.db.transform().id().find(entity.transform_id)?;
+    (transform.map_id == OVERWORLD_MAP_ID).then_some(transform.chunk_id)
+}
+
+#[reducer]
+fn veil_drift(ctx: &ReducerContext, _timer: VeilDriftTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`veil_drift` may not be invoked by clients.".into());
+    }
+
+    let Some(mut veil) = ctx.db.veil().id().find(0) else {
+        return Err("veil_drift: the Veil is missing.".into());
+    };
+    let mut rng = ChaCha8Rng::seed_from_u64(ctx.timestamp.to_micros_since_unix_epoch() as u64);
+
+    veil.heading += rng.random_range(-MAX_TURN..=MAX_TURN);
+    let mut next = Vec3::new(
+        veil.center.x + veil.heading.cos() * DRIFT_DISTANCE,
+        0.,
+        veil.center.z + veil.heading.sin() * DRIFT_DISTANCE,
+    );
+    // Turn back towards the Aetherheart when wandering too far
+    if next.distance_squared_xz(Vec3::ZERO) > MAX_DISTANCE_FROM_ORIGIN * MAX_DISTANCE_FROM_ORIGIN {
+        veil.heading = (-veil.center.z).atan2(-veil.center.x);
+        next = Vec3::new(
+            veil.center.x + veil.heading.cos() * DRIFT_DISTANCE,
+            0.,
+            veil.center.z + veil.heading.sin() * DRIFT_DISTANCE,
+        );
+    }
+    veil.center = next;
+    let veil = ctx.db.veil().id().update(veil);
+    update_extent(ctx, &veil);
+
+    if ctx.db.veil_resource().count() < MAX_RESOURCES as u64
+        && rng.random_bool(RESOURCE_SPAWN_CHANCE)
+    {
+        spawn_resource(ctx, &veil, &mut rng);
+    }
+
+    Ok(())
+}
+
+#[reducer]
+fn veil_effect_tick(ctx: &ReducerContext, _timer: VeilEffectTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`veil_effect_tick` may not be invoked by clients.".into());
+    }
+
+    for pawn in ctx.db.character_pawn().iter() {
+        let inside = entity_chunk(ctx, pawn.entity_id).is_some_and(|id| contains(ctx, id));
+        let exposed = ctx
+            .db
+            .veil_exposure()
+            .entity_id()
+            .find(pawn.entity_id)
+            .is_some();
+
+        if !inside {
+            if exposed {
+                ctx.db.veil_exposure().entity_id().delete(pawn.entity_id);
+            }
+            continue;
+        }
+        if !exposed {
+            ctx.db.veil_exposure().insert(VeilExposure {
+                entity_id: pawn.entity_id,
+                since: ctx.timestamp,
+            });
+        }
+        if let Err(err) = effect::apply_effect(ctx, pawn.entity_id, VEIL_CHILL_EFFECT_ID, None) {
+            log::warn!("veil_effect_tick: {err}");
+        }
+
+        let Some(mut mana) = ctx
+            .db
+            .character_def()
+            .id()
+            .find(pawn.character_id)
+            .and_then(|character| ctx.db.mana().id().find(character.mana_id))
+        else {
+            continue;
+        };
+        if mana.mana > 0 {
+            let drained = mana.mana.saturating_sub(MANA_DRAIN);
+            mana.update(drained);
+            ctx.db.mana().id().update(mana);
+        }
+    }
+
+    let npcs: Vec<_> = ctx.db.npc().iter().collect();
+    for npc in npcs {
+        let inside = entity_chunk(ctx, npc.entity_id).is_some_and(|id| contains(ctx, id));
+        if inside != npc.empowered {
+            npc::set_empowered(ctx, npc, inside);
+        }
+    }
+
+    Ok(())
+}
+
+/// Gathers a nearby resource that condensed inside the Veil.
+#[reducer]
+pub fn gather_veil_resource(ctx: &ReducerContext, entity_id: u32) -> Result<(), String> {
+    let Some(pawn) = ctx.db.character_pawn().identity().find(ctx.sender) else {
+        let err = format!("gather_veil_resource: unable to find character pawn for sender.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+    let Some(resource) = ctx.db.veil_resource().entity_id().find(entity_id) else {
+        let err = format!("gather_veil_resource: resource not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    let in_reach = entities_in_range(
+        ctx,
+        pawn.entity_id,
+        resource.entity_id,
+        GATHER_RANGE_SQUARED,
+    );
+    if !in_reach {
+        let err = format!("gather_veil_resource: resource is too far away.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+
+    if let Err(err) = inventory::add_item(ctx, pawn.character_id, resource.item_def_id, 1) {
+        let err = format!("gather_veil_resource: {err}");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    despawn_resource(ctx, resource);
+
+    Ok(())
+}
@@ -0,0 +1,300 @@
+//! NPC vendors selling and buying items.
+//!
+//! A `vendor` is attached to an NPC definition, so every spawn of that NPC trades from
+//! the same stock. Limited stock is refilled by a restock timer per vendor. Items sold
+//! to a vendor can be bought back for what the vendor paid, until they are pushed out
+//! by newer sales.
+
+use crate::{
+    currency::{self, CurrencyReason},
+    inventory::{self, inventory_item, item_def},
+    npc,
+};
+use spacetimedb::{
+    Filter, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp, client_visibility_filter,
+    reducer, table,
+};
+
+/// How many sold stacks a character can buy back.
+const MAX_BUYBACK: usize = 12;
+
+#[client_visibility_filter]
+const VENDOR_BUYBACK_SECURITY: Filter = Filter::Sql(
+    "SELECT vendor_buyback.* FROM vendor_buyback JOIN character_def ON vendor_buyback.character_id = character_def.id WHERE character_def.identity = :sender",
+);
+
+/// An NPC that trades items for currency.
+#[table(name = vendor, public)]
+pub struct Vendor {
+    #[primary_key]
+    pub id: u32,
+
+    #[unique]
+    pub npc_def_id: u32,
+
+    /// How often limited stock is refilled
+    pub restock_secs: u32,
+}
+
+/// An item on a vendor's stock list.
+#[table(name = vendor_item, public)]
+pub struct VendorItem {
+    #[primary_key]
+    pub id: u32,
+
+    #[index(btree)]
+    pub vendor_id: u32,
+
+    pub item_def_id: u32,
+
+    /// The price of a single item
+    pub price: u64,
+
+    /// How many the vendor holds after restocking, `None` meaning unlimited
+    pub max_stock: Option<u16>,
+    pub stock: u16,
+}
+
+/// An item stack a character sold to a vendor and can still buy back.
+#[table(name = vendor_buyback, public)]
+pub struct VendorBuyback {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+
+    #[index(btree)]
+    pub character_id: u32,
+
+    pub vendor_id: u32,
+    pub item_def_id: u32,
+    pub quantity: u16,
+
+    /// What the vendor paid for the whole stack
+    pub price: u64,
+
+    pub sold_at: Timestamp,
+}
+
+#[table(name = vendor_restock_timer, scheduled(vendor_restock))]
+struct VendorRestockTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+
+    vendor_id: u32,
+}
+
+/// Starts a restock timer for every vendor.
+pub fn init(ctx: &ReducerContext) {
+    for timer in ctx.db.vendor_restock_timer().iter() {
+        ctx.db
+            .vendor_restock_timer()
+            .scheduled_id()
+            .delete(timer.scheduled_id);
+    }
+
+    for vendor in ctx.db.vendor().iter() {
+        ctx.db.vendor_restock_timer().insert(VendorRestockTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from_micros(
+                vendor.restock_secs.max(1) as i64 * 1_000_000,
+            )),
+            vendor_id: vendor.id,
+        });
+    }
+}
+
+/// Finds the vendor behind a nearby NPC, returning it along with the sender's
+/// character id.
+fn vendor_in_reach(
+    ctx: &ReducerContext,
+    reducer: &str,
+    npc_entity_id: u32,
+) -> Result<(Vendor, u32), String> {
+    let (npc, character_id) = npc::npc_in_reach(ctx, reducer, npc_entity_id)?;
+    let Some(vendor) = ctx.db.vendor().npc_def_id().find(npc.npc_def_id) else {
+        let err = format!("{reducer}: NPC is not a vendor.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    Ok((vendor, character_id))
+}
+
+#[reducer]
+pub fn buy_from_vendor(
+    ctx: &ReducerContext,
+    npc_entity_id: u32,
+    vendor_item_id: u32,
+    quantity: u16,
+) -> Result<(), String> {
+    let (vendor, character_id) = vendor_in_reach(ctx, "buy_from_vendor", npc_entity_id)?;
+    let Some(mut vendor_item) = ctx
+        .db
+        .vendor_item()
+        .id()
+        .find(vendor_item_id)
+        .filter(|item| item.vendor_id == vendor.id)
+    else {
+        let err = format!("buy_from_vendor: vendor does not sell that item.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if quantity == 0 {
+        let err = format!("buy_from_vendor: quantity must be greater than zero.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    if vendor_item.max_stock.is_some() && vendor_item.stock < quantity {
+        let err = format!("buy_from_vendor: vendor does not have enough in stock.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    let Some(total) = vendor_item.price.checked_mul(quantity as u64) else {
+        let err = format!("buy_from_vendor: price is too high.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    currency::withdraw(
+        ctx,
+        character_id,
+        total,
+        CurrencyReason::VendorBuy(vendor.id),
+    )?;
+    inventory::add_item(ctx, character_id, vendor_item.item_def_id, quantity)?;
+
+    if vendor_item.max_stock.is_some() {
+        vendor_item.stock -= quantity;
+        ctx.db.vendor_item().id().update(vendor_item);
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn sell_to_vendor(
+    ctx: &ReducerContext,
+    npc_entity_id: u32,
+    inventory_item_id: u32,
+    quantity: u16,
+) -> Result<(), String> {
+    let (vendor, character_id) = vendor_in_reach(ctx, "sell_to_vendor", npc_entity_id)?;
+    let Some(item_def) = ctx
+        .db
+        .inventory_item()
+        .id()
+        .find(inventory_item_id)
+        .filter(|item| item.character_id == character_id)
+        .and_then(|item| ctx.db.item_def().id().find(item.item_def_id))
+    else {
+        let err = format!("sell_to_vendor: item not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    if item_def.sell_price == 0 {
+        let err = format!("sell_to_vendor: vendors won't buy that item.");
+        log::warn!("{err}");
+        return Err(err);
+    }
+    let Some(total) = item_def.sell_price.checked_mul(quantity as u64) else {
+        let err = format!("sell_to_vendor: price is too high.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    inventory::remove_item(ctx, character_id, inventory_item_id, quantity)?;
+    currency::deposit(
+        ctx,
+        character_id,
+        total,
+        CurrencyReason::VendorSell(vendor.id),
+    )?;
+
+    ctx.db.vendor_buyback().insert(VendorBuyback {
+        id: 0,
+        character_id,
+        vendor_id: vendor.id,
+        item_def_id: item_def.id,
+        quantity,
+        price: total,
+        sold_at: ctx.timestamp,
+    });
+
+    // Only the most recent sales can be bought back
+    let mut buybacks: Vec<VendorBuyback> = ctx
+        .db
+        .vendor_buyback()
+        .character_id()
+        .filter(character_id)
+        .collect();
+    if buybacks.len() > MAX_BUYBACK {
+        buybacks.sort_by_key(|buyback| buyback.sold_at);
+        for buyback in &buybacks[..buybacks.len() - MAX_BUYBACK] {
+            ctx.db.vendor_buyback().id().delete(buyback.id);
+        }
+    }
+
+    Ok(())
+}
+
+/// Buys back an item stack previously sold to the same vendor, for what it sold for.
+#[reducer]
+pub fn buyback_from_vendor(
+    ctx: &ReducerContext,
+    npc_entity_id: u32,
+    buyback_id: u32,
+) -> Result<(), String> {
+    let (vendor, character_id) = vendor_in_reach(ctx, "buyback_from_vendor", npc_entity_id)?;
+    let Some(buyback) = ctx
+        .db
+        .vendor_buyback()
+        .id()
+        .find(buyback_id)
+        .filter(|buyback| buyback.character_id == character_id && buyback.vendor_id == vendor.id)
+    else {
+        let err = format!("buyback_from_vendor: item not found.");
+        log::warn!("{err}");
+        return Err(err);
+    };
+
+    currency::withdraw(
+        ctx,
+        character_id,
+        buyback.price,
+        CurrencyReason::VendorBuyback(vendor.id),
+    )?;
+    inventory::add_item(ctx, character_id, buyback.item_def_id, buyback.quantity)?;
+    ctx.db.vendor_buyback().id().delete(buyback.id);
+
+    Ok(())
+}
+
+/// Refills a vendor's limited stock.
+#[reducer]
+fn vendor_restock(ctx: &ReducerContext, timer: VendorRestockTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("`vendor_restock` may not be invoked by clients.".into());
+    }
+
+    let stock: Vec<VendorItem> = ctx
+        .db
+        .vendor_item()
+        .vendor_id()
+        .filter(timer.vendor_id)
+        .collect();
+    for mut vendor_item in stock {
+        let Some(max_stock) = vendor_item.max_stock else {
+            continue;
+        };
+        if vendor_item.stock < max_stock {
+            vendor_item.stock = max_stock;
+            ctx.db.vendor_item().id().update(vendor_item);
+        }
+    }
+
+    Ok(())
+}